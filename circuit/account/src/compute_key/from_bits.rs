@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> FromBits for ComputeKey<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new compute key from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let size_in_bits = A::BaseField::size_in_bits();
+        if bits_le.len() != size_in_bits * 2 {
+            A::halt(format!("Expected {} bits for a compute key, found {}", size_in_bits * 2, bits_le.len()))
+        }
+        // Recover `pk_sig` and `pr_sig`.
+        let (pk_sig, pr_sig) = bits_le.split_at(size_in_bits);
+        Self::from_groups(Group::from_bits_le(pk_sig), Group::from_bits_le(pr_sig))
+    }
+
+    /// Initializes a new compute key from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let size_in_bits = A::BaseField::size_in_bits();
+        if bits_be.len() != size_in_bits * 2 {
+            A::halt(format!("Expected {} bits for a compute key, found {}", size_in_bits * 2, bits_be.len()))
+        }
+        // Recover `pk_sig` and `pr_sig`.
+        let (pk_sig, pr_sig) = bits_be.split_at(size_in_bits);
+        Self::from_groups(Group::from_bits_be(pk_sig), Group::from_bits_be(pr_sig))
+    }
+}
+
+impl<A: Aleo> ComputeKey<A> {
+    /// Initializes a compute key from `pk_sig` and `pr_sig`, deriving `sk_prf` := HashToScalar(pk_sig || pr_sig).
+    fn from_groups(pk_sig: Group<A>, pr_sig: Group<A>) -> Self {
+        // Compute `sk_prf` := HashToScalar(G^sk_sig || G^r_sig).
+        let sk_prf = A::hash_to_scalar_psd4(&[pk_sig.to_x_coordinate(), pr_sig.to_x_coordinate()]);
+        // Output the compute key.
+        Self { pk_sig, pr_sig, sk_prf }
+    }
+}
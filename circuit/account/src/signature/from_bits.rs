@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new signature from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let scalar_size_in_bits = A::ScalarField::size_in_bits();
+        let size_in_bits = scalar_size_in_bits * 2 + A::BaseField::size_in_bits() * 2;
+        if bits_le.len() != size_in_bits {
+            A::halt(format!("Expected {size_in_bits} bits for a signature, found {}", bits_le.len()))
+        }
+        // Recover the challenge, response, and compute key.
+        let (challenge, remaining) = bits_le.split_at(scalar_size_in_bits);
+        let (response, compute_key) = remaining.split_at(scalar_size_in_bits);
+        Self {
+            challenge: Scalar::from_bits_le(challenge),
+            response: Scalar::from_bits_le(response),
+            compute_key: ComputeKey::from_bits_le(compute_key),
+        }
+    }
+
+    /// Initializes a new signature from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let scalar_size_in_bits = A::ScalarField::size_in_bits();
+        let size_in_bits = scalar_size_in_bits * 2 + A::BaseField::size_in_bits() * 2;
+        if bits_be.len() != size_in_bits {
+            A::halt(format!("Expected {size_in_bits} bits for a signature, found {}", bits_be.len()))
+        }
+        // Recover the challenge, response, and compute key.
+        let (challenge, remaining) = bits_be.split_at(scalar_size_in_bits);
+        let (response, compute_key) = remaining.split_at(scalar_size_in_bits);
+        Self {
+            challenge: Scalar::from_bits_be(challenge),
+            response: Scalar::from_bits_be(response),
+            compute_key: ComputeKey::from_bits_be(compute_key),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_from_bits(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+
+            // Generate a signature.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let expected = console::Signature::sign(&private_key, &message, rng)?;
+            let signature = Signature::<Circuit>::new(mode, expected);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                // Check the little-endian bits.
+                let candidate = Signature::from_bits_le(&signature.to_bits_le());
+                assert_eq!(expected, candidate.eject_value());
+                assert!(candidate.is_equal(&signature).eject_value());
+
+                // Check the big-endian bits.
+                let candidate = Signature::from_bits_be(&signature.to_bits_be());
+                assert_eq!(expected, candidate.eject_value());
+                assert!(candidate.is_equal(&signature).eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bits_constant() -> Result<()> {
+        check_from_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_bits_public() -> Result<()> {
+        check_from_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_bits_private() -> Result<()> {
+        check_from_bits(Mode::Private)
+    }
+}
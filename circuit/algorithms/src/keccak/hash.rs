@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Returns the Keccak hash of the given input as bits.
+    pub fn hash(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Pad the input to a multiple of the rate.
+        let padded_input = Self::pad(input);
+
+        // Initialize the state.
+        let mut state = vec![Boolean::constant(false); PERMUTATION_WIDTH];
+        // Absorb the padded input into the state, one block at a time.
+        for block in padded_input.chunks(Self::rate()) {
+            for (state_bit, input_bit) in state.iter_mut().zip(block) {
+                *state_bit ^= input_bit;
+            }
+            state = self.permutation_f(&state);
+        }
+
+        // Squeeze the output from the state.
+        // Note: As the output size is less than the rate, the output fits in the first block.
+        state.truncate(VARIANT);
+        state
+    }
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Returns the rate of the sponge, in bits.
+    const fn rate() -> usize {
+        PERMUTATION_WIDTH - 2 * VARIANT
+    }
+
+    /// Returns the given input, padded to a multiple of the rate.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        let mut padded_input = input.to_vec();
+        // Append the domain separation bits, based on the padding rule.
+        match TYPE {
+            0 => (),
+            1 => padded_input.extend([Boolean::constant(false), Boolean::constant(true)]),
+            _ => E::halt(format!("Invalid Keccak type: {TYPE}")),
+        }
+        // Append the `pad10*1` padding.
+        padded_input.push(Boolean::constant(true));
+        while padded_input.len() % Self::rate() != Self::rate() - 1 {
+            padded_input.push(Boolean::constant(false));
+        }
+        padded_input.push(Boolean::constant(true));
+        padded_input
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permutation_f(&self, state: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Returns the index of bit `z` in the lane at `(x, y)`.
+        let index = |x: usize, y: usize, z: usize| LANE_WIDTH * (x + 5 * y) + z;
+
+        let mut state = state.to_vec();
+        for round_constant in &self.round_constants {
+            // Theta: XOR each lane with the parities of two neighboring columns.
+            let parities = (0..5)
+                .map(|x| {
+                    (0..LANE_WIDTH)
+                        .map(|z| {
+                            let mut parity = state[index(x, 0, z)].clone();
+                            (1..5).for_each(|y| parity ^= &state[index(x, y, z)]);
+                            parity
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            for x in 0..5 {
+                for z in 0..LANE_WIDTH {
+                    let parity = &parities[(x + 4) % 5][z] ^ &parities[(x + 1) % 5][(z + LANE_WIDTH - 1) % LANE_WIDTH];
+                    (0..5).for_each(|y| state[index(x, y, z)] ^= &parity);
+                }
+            }
+
+            // Rho and pi: rotate each lane, and permute the positions of the lanes.
+            let mut lanes = vec![Boolean::constant(false); PERMUTATION_WIDTH];
+            for x in 0..5 {
+                for y in 0..5 {
+                    let offset = self.rotation_offsets[x + 5 * y];
+                    for z in 0..LANE_WIDTH {
+                        lanes[index(y, (2 * x + 3 * y) % 5, (z + offset) % LANE_WIDTH)] = state[index(x, y, z)].clone();
+                    }
+                }
+            }
+
+            // Chi: combine each lane with the next two lanes in its row.
+            for x in 0..5 {
+                for y in 0..5 {
+                    for z in 0..LANE_WIDTH {
+                        state[index(x, y, z)] = &lanes[index(x, y, z)]
+                            ^ &(!&lanes[index((x + 1) % 5, y, z)] & &lanes[index((x + 2) % 5, y, z)]);
+                    }
+                }
+            }
+
+            // Iota: XOR the first lane with the round constant.
+            for (state_bit, constant_bit) in state[..LANE_WIDTH].iter_mut().zip_eq(round_constant) {
+                *state_bit ^= constant_bit;
+            }
+        }
+        state
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Hash as H;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 3;
+
+    fn check_hash<const TYPE: u8>(
+        mode: Mode,
+        num_bits: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) {
+        // Initialize the Keccak hash.
+        let native = console::Keccak::<TYPE, 256>::new();
+        let circuit = Keccak::<Circuit, TYPE, 256>::constant(native);
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Keccak {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_keccak256_constant() {
+        let mut rng = TestRng::default();
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Constant, 1, 0, 0, 0, 0, &mut rng);
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Constant, 1088, 0, 0, 0, 0, &mut rng);
+    }
+
+    #[test]
+    fn test_keccak256_public() {
+        let mut rng = TestRng::default();
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Public, 1, 0, 0, 138170, 138170, &mut rng);
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Public, 1088, 0, 0, 306176, 306176, &mut rng);
+    }
+
+    #[test]
+    fn test_keccak256_private() {
+        let mut rng = TestRng::default();
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Private, 1, 0, 0, 138170, 138170, &mut rng);
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Private, 1088, 0, 0, 306176, 306176, &mut rng);
+    }
+
+    #[test]
+    fn test_sha3_256_constant() {
+        let mut rng = TestRng::default();
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Constant, 1, 0, 0, 0, 0, &mut rng);
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Constant, 1088, 0, 0, 0, 0, &mut rng);
+    }
+
+    #[test]
+    fn test_sha3_256_public() {
+        let mut rng = TestRng::default();
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Public, 1, 0, 0, 138303, 138303, &mut rng);
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Public, 1088, 0, 0, 306176, 306176, &mut rng);
+    }
+
+    #[test]
+    fn test_sha3_256_private() {
+        let mut rng = TestRng::default();
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Private, 1, 0, 0, 138303, 138303, &mut rng);
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Private, 1088, 0, 0, 306176, 306176, &mut rng);
+    }
+
+    #[test]
+    fn test_sha3_256_test_vector() {
+        // Initialize the SHA3-256 hash.
+        let circuit = Sha3_256::<Circuit>::constant(console::Sha3_256::new());
+        // Prepare the input, as the little-endian bits of "abc".
+        let input = b"abc".iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+        // Compute the hash, and convert it into bytes.
+        let candidate = circuit.hash(&circuit_input).eject_value();
+        let candidate = candidate
+            .chunks(8)
+            .map(|byte| byte.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8)))
+            .collect::<Vec<u8>>();
+        // Check the test vector from FIPS 202.
+        let expected = "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532";
+        assert_eq!(expected, candidate.iter().map(|byte| format!("{byte:02x}")).collect::<String>());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
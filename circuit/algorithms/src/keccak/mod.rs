@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use snarkvm_circuit_types::prelude::*;
+
+/// Keccak256 is the hash function used in Ethereum, with a 256-bit output.
+pub type Keccak256<E> = Keccak<E, { KeccakType::Keccak as u8 }, 256>;
+/// SHA3-256 is the standardized variant of Keccak in FIPS 202, with a 256-bit output.
+pub type Sha3_256<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 256>;
+
+/// The padding rule of the Keccak sponge.
+pub enum KeccakType {
+    /// The original Keccak padding rule, as used in Ethereum.
+    Keccak,
+    /// The SHA-3 padding rule, as standardized in FIPS 202.
+    Sha3,
+}
+
+/// The width of the Keccak-f[1600] permutation, in bits.
+const PERMUTATION_WIDTH: usize = 1600;
+/// The number of bits in a lane of the Keccak-f[1600] permutation.
+const LANE_WIDTH: usize = 64;
+
+/// Keccak is a cryptographic hash function based on the sponge construction.
+/// The hash function outputs `VARIANT` bits, and processes inputs in blocks of `1600 - 2 * VARIANT` bits.
+///
+/// The state is represented as 1600 booleans, where bit `z` of the lane at `(x, y)` is at index `64 * (x + 5 * y) + z`.
+/// As such, the rotations and permutations of the lanes do not incur any constraints.
+pub struct Keccak<E: Environment, const TYPE: u8, const VARIANT: usize> {
+    /// The round constants of the Keccak-f[1600] permutation, as little-endian bits.
+    round_constants: Vec<Vec<Boolean<E>>>,
+    /// The rotation offsets of the Keccak-f[1600] permutation, for the lane at index `x + 5 * y`.
+    rotation_offsets: Vec<usize>,
+}
+
+#[cfg(console)]
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Inject for Keccak<E, TYPE, VARIANT> {
+    type Primitive = console::Keccak<TYPE, VARIANT>;
+
+    /// Initializes a new instance of Keccak with the given Keccak variant.
+    fn new(_mode: Mode, _keccak: Self::Primitive) -> Self {
+        // Ensure the output size is supported.
+        assert!([224, 256, 384, 512].contains(&VARIANT), "Invalid Keccak output size: {VARIANT}");
+
+        // Initialize the round constants.
+        let round_constants = Self::Primitive::round_constants()
+            .iter()
+            .map(|round_constant| (0..LANE_WIDTH).map(|i| Boolean::constant((round_constant >> i) & 1 == 1)).collect())
+            .collect();
+
+        // Initialize the rotation offsets.
+        let rotation_offsets = Self::Primitive::rotation_offsets().iter().map(|offset| *offset as usize).collect();
+
+        Self { round_constants, rotation_offsets }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_setup_constant() {
+        Circuit::scope("Keccak::setup", || {
+            // Perform the setup operation.
+            let _circuit = Keccak256::<Circuit>::constant(console::Keccak256::new());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+}
@@ -60,6 +60,18 @@ pub trait Aleo: Environment {
     /// Returns a BHP commitment with an input hasher of 1024-bits.
     fn commit_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self>;
 
+    /// Returns a BHP commitment with an input hasher of 256-bits on the affine curve.
+    fn commit_to_group_bhp256(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns a BHP commitment with an input hasher of 512-bits on the affine curve.
+    fn commit_to_group_bhp512(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns a BHP commitment with an input hasher of 768-bits on the affine curve.
+    fn commit_to_group_bhp768(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits on the affine curve.
+    fn commit_to_group_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self>;
+
     /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
     fn commit_ped64(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self>;
 
@@ -84,6 +96,12 @@ pub trait Aleo: Environment {
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[Boolean<Self>]) -> Field<Self>;
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Field<Self>;
 
@@ -102,6 +120,24 @@ pub trait Aleo: Environment {
     /// Returns the extended Poseidon hash with an input rate of 8.
     fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
+    /// Returns the BHP hash with an input hasher of 256-bits on the affine curve.
+    fn hash_to_group_bhp256(input: &[Boolean<Self>]) -> Group<Self>;
+
+    /// Returns the BHP hash with an input hasher of 512-bits on the affine curve.
+    fn hash_to_group_bhp512(input: &[Boolean<Self>]) -> Group<Self>;
+
+    /// Returns the BHP hash with an input hasher of 768-bits on the affine curve.
+    fn hash_to_group_bhp768(input: &[Boolean<Self>]) -> Group<Self>;
+
+    /// Returns the BHP hash with an input hasher of 1024-bits on the affine curve.
+    fn hash_to_group_bhp1024(input: &[Boolean<Self>]) -> Group<Self>;
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input on the affine curve.
+    fn hash_to_group_ped64(input: &[Boolean<Self>]) -> Group<Self>;
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input on the affine curve.
+    fn hash_to_group_ped128(input: &[Boolean<Self>]) -> Group<Self>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
     fn hash_to_group_psd2(input: &[Field<Self>]) -> Group<Self>;
 
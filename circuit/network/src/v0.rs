@@ -22,11 +22,14 @@ use snarkvm_circuit_algorithms::{
     HashMany,
     HashToGroup,
     HashToScalar,
+    HashUncompressed,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha3_256,
     BHP1024,
     BHP256,
     BHP512,
@@ -76,6 +79,11 @@ thread_local! {
     /// The Pedersen hash function, which can take an input of up to 128 bits.
     static PEDERSEN_128: Pedersen128<AleoV0> = Pedersen128::<AleoV0>::constant(console::PEDERSEN_128.clone());
 
+    /// The Keccak hash function, with a 256-bit output.
+    static KECCAK_256: Keccak256<AleoV0> = Keccak256::<AleoV0>::constant(*console::KECCAK_256);
+    /// The SHA-3 hash function, with a 256-bit output.
+    static SHA3_256: Sha3_256<AleoV0> = Sha3_256::<AleoV0>::constant(*console::SHA3_256);
+
     /// The Poseidon hash function, using a rate of 2.
     static POSEIDON_2: Poseidon2<AleoV0> = Poseidon2::<AleoV0>::constant(console::POSEIDON_2.clone());
     /// The Poseidon hash function, using a rate of 4.
@@ -149,6 +157,26 @@ impl Aleo for AleoV0 {
         BHP_1024.with(|bhp| bhp.commit(input, randomizer))
     }
 
+    /// Returns a BHP commitment with an input hasher of 256-bits on the affine curve.
+    fn commit_to_group_bhp256(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        BHP_256.with(|bhp| bhp.commit_uncompressed(input, randomizer))
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits on the affine curve.
+    fn commit_to_group_bhp512(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        BHP_512.with(|bhp| bhp.commit_uncompressed(input, randomizer))
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits on the affine curve.
+    fn commit_to_group_bhp768(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        BHP_768.with(|bhp| bhp.commit_uncompressed(input, randomizer))
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits on the affine curve.
+    fn commit_to_group_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        BHP_1024.with(|bhp| bhp.commit_uncompressed(input, randomizer))
+    }
+
     /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
     fn commit_ped64(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
         PEDERSEN_64.with(|pedersen| pedersen.commit_uncompressed(input, randomizer))
@@ -189,6 +217,16 @@ impl Aleo for AleoV0 {
         PEDERSEN_128.with(|pedersen| pedersen.hash(input))
     }
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        KECCAK_256.with(|keccak| keccak.hash(input))
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA3_256.with(|sha3| sha3.hash(input))
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Field<Self> {
         POSEIDON_2.with(|poseidon| poseidon.hash(input))
@@ -219,6 +257,36 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash_many(input, num_outputs))
     }
 
+    /// Returns the BHP hash with an input hasher of 256-bits on the affine curve.
+    fn hash_to_group_bhp256(input: &[Boolean<Self>]) -> Group<Self> {
+        BHP_256.with(|bhp| bhp.hash_uncompressed(input))
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits on the affine curve.
+    fn hash_to_group_bhp512(input: &[Boolean<Self>]) -> Group<Self> {
+        BHP_512.with(|bhp| bhp.hash_uncompressed(input))
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits on the affine curve.
+    fn hash_to_group_bhp768(input: &[Boolean<Self>]) -> Group<Self> {
+        BHP_768.with(|bhp| bhp.hash_uncompressed(input))
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits on the affine curve.
+    fn hash_to_group_bhp1024(input: &[Boolean<Self>]) -> Group<Self> {
+        BHP_1024.with(|bhp| bhp.hash_uncompressed(input))
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input on the affine curve.
+    fn hash_to_group_ped64(input: &[Boolean<Self>]) -> Group<Self> {
+        PEDERSEN_64.with(|pedersen| pedersen.hash_uncompressed(input))
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input on the affine curve.
+    fn hash_to_group_ped128(input: &[Boolean<Self>]) -> Group<Self> {
+        PEDERSEN_128.with(|pedersen| pedersen.hash_uncompressed(input))
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
     fn hash_to_group_psd2(input: &[Field<Self>]) -> Group<Self> {
         POSEIDON_2.with(|poseidon| poseidon.hash_to_group(input))
@@ -0,0 +1,323 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+#[cfg(console)]
+use snarkvm_circuit_types::integers::Integer;
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Casts the literal into the given literal type.
+    ///
+    /// The cast enforces that the value of the literal is preserved, and the circuit is unsatisfied otherwise.
+    /// Group and address literals are cast by their x-coordinate, and negative integers only fit in integer types.
+    pub fn cast(&self, to_type: console::LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, false)
+    }
+
+    /// Casts the literal into the given literal type, with lossy truncation.
+    ///
+    /// The little-endian bits of the literal are sign-extended or truncated to fit in the given type,
+    /// following the semantics of an `as` cast in Rust. Field and scalar types are treated as unsigned.
+    /// As a group element cannot be recovered from a truncated x-coordinate, lossy casts into
+    /// group and address types enforce that the value is a valid x-coordinate.
+    pub fn cast_lossy(&self, to_type: console::LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, true)
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Casts the literal into the given literal type, with lossy truncation if `is_lossy` is `true`.
+    fn cast_internal(&self, to_type: console::LiteralType, is_lossy: bool) -> Result<Self> {
+        use console::LiteralType;
+
+        // If the literal is already of the given type, return the literal.
+        if self.to_type() == to_type {
+            return Ok(self.clone());
+        }
+
+        match (self, to_type) {
+            // Ensure strings and signatures are not cast into or from any other type.
+            (Self::String(..), _)
+            | (_, LiteralType::String)
+            | (Self::Signature(..), _)
+            | (_, LiteralType::Signature) => {
+                bail!("Cannot cast a '{}' literal into a '{to_type}' literal", self.to_type())
+            }
+            // Casting between an address and a group element preserves the underlying group element.
+            (Self::Address(address), LiteralType::Group) => Ok(Self::Group(address.to_group())),
+            (Self::Group(group), LiteralType::Address) => Ok(Self::Address(Address::from_group(group.clone()))),
+            // Otherwise, cast the little-endian bits of the literal.
+            _ => {
+                // Retrieve the little-endian bits of the literal, and whether they are signed.
+                let (bits_le, is_signed) = self.to_cast_bits_le()?;
+                // Cast the bits into the given literal type.
+                let output = match to_type {
+                    LiteralType::Address => Self::Address(Address::from_group(to_group(self.cast_to_field(is_lossy)?))),
+                    LiteralType::Boolean => {
+                        Self::Boolean(cast_bits_le(&bits_le, is_signed, 1, false, is_lossy)[0].clone())
+                    }
+                    LiteralType::Field => Self::Field(self.cast_to_field(is_lossy)?),
+                    LiteralType::Group => Self::Group(to_group(self.cast_to_field(is_lossy)?)),
+                    LiteralType::I8 => Self::I8(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::I16 => Self::I16(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::I32 => Self::I32(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::I64 => Self::I64(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::I128 => Self::I128(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::U8 => Self::U8(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::U16 => Self::U16(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::U32 => Self::U32(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::U64 => Self::U64(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::U128 => Self::U128(to_integer(&bits_le, is_signed, is_lossy)),
+                    LiteralType::Scalar => Self::Scalar(to_scalar(&bits_le, is_signed, is_lossy)),
+                    LiteralType::String => bail!("Cannot cast a '{}' literal into a string", self.to_type()),
+                    LiteralType::Signature => bail!("Cannot cast a '{}' literal into a signature", self.to_type()),
+                };
+                Ok(output)
+            }
+        }
+    }
+
+    /// Casts the literal into a base field element, with lossy truncation if `is_lossy` is `true`.
+    /// Group and address literals are cast into their x-coordinate.
+    fn cast_to_field(&self, is_lossy: bool) -> Result<Field<A>> {
+        match self {
+            Self::Address(address) => Ok(address.to_group().to_x_coordinate()),
+            Self::Boolean(boolean) => Ok(Field::from_boolean(boolean)),
+            Self::Field(field) => Ok(field.clone()),
+            Self::Group(group) => Ok(group.to_x_coordinate()),
+            Self::Scalar(scalar) => Ok(scalar.to_field()),
+            _ => {
+                // Retrieve the little-endian bits of the literal, and whether they are signed.
+                let (bits_le, is_signed) = self.to_cast_bits_le()?;
+                // Cast the bits into a base field element.
+                Ok(to_field(&bits_le, is_signed, is_lossy))
+            }
+        }
+    }
+
+    /// Returns the little-endian bits of the literal for casting, and `true` if the bits are signed.
+    /// Group and address literals are represented by the bits of their x-coordinate.
+    fn to_cast_bits_le(&self) -> Result<(Vec<Boolean<A>>, bool)> {
+        match self {
+            Self::Address(address) => Ok((address.to_group().to_x_coordinate().to_bits_le(), false)),
+            Self::Boolean(boolean) => Ok((vec![boolean.clone()], false)),
+            Self::Field(field) => Ok((field.to_bits_le(), false)),
+            Self::Group(group) => Ok((group.to_x_coordinate().to_bits_le(), false)),
+            Self::I8(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I16(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I32(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I64(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I128(integer) => Ok((integer.to_bits_le(), true)),
+            Self::U8(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U16(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U32(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U64(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U128(integer) => Ok((integer.to_bits_le(), false)),
+            Self::Scalar(scalar) => Ok((scalar.to_bits_le(), false)),
+            Self::String(..) => bail!("Cannot cast a string literal"),
+            Self::Signature(..) => bail!("Cannot cast a signature literal"),
+        }
+    }
+}
+
+/// Returns the given little-endian bits, sign-extended (if signed) or zero-extended to `num_bits` bits.
+#[cfg(console)]
+fn extend_bits_le<A: Aleo>(bits_le: &[Boolean<A>], is_signed: bool, num_bits: usize) -> Vec<Boolean<A>> {
+    // Retrieve the most significant bit, which is the sign bit for signed integers.
+    let msb = match is_signed {
+        true => bits_le.last().cloned().unwrap_or_else(|| Boolean::constant(false)),
+        false => Boolean::constant(false),
+    };
+    // Extend the bits up to the given number of bits.
+    let mut bits_le = bits_le.to_vec();
+    bits_le.resize(bits_le.len().max(num_bits), msb);
+    bits_le
+}
+
+/// Casts the given little-endian bits into `num_bits` bits, which are signed if `to_signed` is `true`.
+/// If `is_lossy` is `false`, this method enforces that the value is preserved.
+#[cfg(console)]
+fn cast_bits_le<A: Aleo>(
+    bits_le: &[Boolean<A>],
+    is_signed: bool,
+    num_bits: usize,
+    to_signed: bool,
+    is_lossy: bool,
+) -> Vec<Boolean<A>> {
+    // Extend the bits by one bit more than the larger of the two sizes, so that both values are comparable.
+    let extended = extend_bits_le(bits_le, is_signed, bits_le.len().max(num_bits) + 1);
+    // Truncate the bits to the given number of bits.
+    let output = extended[..num_bits].to_vec();
+    // If this is a checked cast, enforce that extending the output recovers the original value.
+    if !is_lossy {
+        // Retrieve the bit that the output is extended with.
+        let msb = match to_signed {
+            true => output[num_bits - 1].clone(),
+            false => Boolean::constant(false),
+        };
+        // Enforce the remaining bits all match the extension of the output.
+        for bit in &extended[num_bits..] {
+            A::assert_eq(bit, &msb);
+        }
+    }
+    output
+}
+
+/// Casts the given little-endian bits into an integer.
+#[cfg(console)]
+fn to_integer<A: Aleo, I: IntegerType>(bits_le: &[Boolean<A>], is_signed: bool, is_lossy: bool) -> Integer<A, I> {
+    Integer::from_bits_le(&cast_bits_le(bits_le, is_signed, I::BITS as usize, I::is_signed(), is_lossy))
+}
+
+/// Casts the given little-endian bits into a base field element.
+#[cfg(console)]
+fn to_field<A: Aleo>(bits_le: &[Boolean<A>], is_signed: bool, is_lossy: bool) -> Field<A> {
+    // If this is a checked cast, enforce the value is not negative.
+    if !is_lossy && is_signed {
+        if let Some(sign) = bits_le.last() {
+            A::assert(!sign);
+        }
+    }
+    // Note: A signed value is reinterpreted as unsigned, as field elements do not have a sign.
+    Field::from_bits_le(bits_le)
+}
+
+/// Returns the group element with the given x-coordinate.
+/// The resulting point is enforced to be on the curve and in the subgroup, and to have the given x-coordinate.
+#[cfg(console)]
+fn to_group<E: Environment>(x_coordinate: Field<E>) -> Group<E> {
+    // Recover the group element from the x-coordinate, or the zero element if the x-coordinate is invalid.
+    let group: Group<E> = witness!(|x_coordinate| {
+        console::Group::from_x_coordinate(x_coordinate).unwrap_or_else(|_| console::Group::zero())
+    });
+    // Enforce the group element has the given x-coordinate.
+    E::assert_eq(group.to_x_coordinate(), x_coordinate);
+    group
+}
+
+/// Casts the given little-endian bits into a scalar field element.
+#[cfg(console)]
+fn to_scalar<A: Aleo>(bits_le: &[Boolean<A>], is_signed: bool, is_lossy: bool) -> Scalar<A> {
+    match is_lossy {
+        // Truncate the bits to the scalar data size, which is guaranteed to be less than the scalar modulus.
+        true => Scalar::from_bits_le(&bits_le[..bits_le.len().min(console::Scalar::<A::Network>::size_in_data_bits())]),
+        false => {
+            // Enforce the value is not negative.
+            if is_signed {
+                if let Some(sign) = bits_le.last() {
+                    A::assert(!sign);
+                }
+            }
+            // Enforce the value is less than the scalar modulus.
+            Scalar::from_bits_le(bits_le)
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::{LiteralType, TestRng, Uniform};
+
+    const ITERATIONS: u32 = 10;
+
+    const LITERAL_TYPES: [LiteralType; 15] = [
+        LiteralType::Address,
+        LiteralType::Boolean,
+        LiteralType::Field,
+        LiteralType::Group,
+        LiteralType::I8,
+        LiteralType::I16,
+        LiteralType::I32,
+        LiteralType::I64,
+        LiteralType::I128,
+        LiteralType::U8,
+        LiteralType::U16,
+        LiteralType::U32,
+        LiteralType::U64,
+        LiteralType::U128,
+        LiteralType::Scalar,
+    ];
+
+    /// Checks that casting the given literal in the circuit matches the console cast.
+    fn check_cast(mode: Mode, expected: &console::Literal<<Circuit as Environment>::Network>, to_type: LiteralType) {
+        for is_lossy in [false, true] {
+            let literal = Literal::<Circuit>::new(mode, expected.clone());
+            let (candidate, expected) = match is_lossy {
+                false => (literal.cast(to_type).unwrap(), expected.cast(to_type)),
+                true => (literal.cast_lossy(to_type).unwrap(), expected.cast_lossy(to_type)),
+            };
+            match expected {
+                // Ensure the circuit matches the console output, and is satisfied.
+                Ok(expected) => {
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(
+                        Circuit::is_satisfied(),
+                        "Cast of {literal} into {to_type} (lossy: {is_lossy}) should succeed"
+                    );
+                }
+                // Ensure the circuit is not satisfied.
+                Err(_) => {
+                    assert!(
+                        !Circuit::is_satisfied(),
+                        "Cast of {literal} into {to_type} (lossy: {is_lossy}) should fail"
+                    )
+                }
+            }
+            Circuit::reset();
+        }
+    }
+
+    fn run_test(mode: Mode) {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            for from_type in LITERAL_TYPES {
+                for to_type in LITERAL_TYPES {
+                    // Check the cast of a random literal.
+                    check_cast(mode, &console::Literal::sample(from_type, rng), to_type);
+                    // Check the cast of a small literal, which fits in every type.
+                    let small = console::Literal::U8(console::U8::new(Uniform::rand(rng)));
+                    if let Ok(small) = small.cast(from_type) {
+                        check_cast(mode, &small, to_type);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_cast_private() {
+        run_test(Mode::Private);
+    }
+
+    #[test]
+    fn test_cast_string_fails() {
+        let string = Literal::<Circuit>::new(Mode::Private, console::Literal::String(console::StringType::new("hi")));
+        assert!(string.cast(LiteralType::Field).is_err());
+        assert!(string.cast_lossy(LiteralType::U8).is_err());
+        let field = Literal::<Circuit>::new(Mode::Private, console::Literal::Field(console::Field::one()));
+        assert!(field.cast(LiteralType::String).is_err());
+    }
+}
@@ -37,7 +37,8 @@ impl<A: Aleo> Literal<A> {
             13 => Literal::U128(U128::from_bits_le(literal)),
             14 => Literal::Scalar(Scalar::from_bits_le(literal)),
             15 => Literal::String(StringType::from_bits_le(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
+            16 => Literal::Signature(Box::new(Signature::from_bits_le(literal))),
+            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
         }
     }
 
@@ -61,7 +62,8 @@ impl<A: Aleo> Literal<A> {
             13 => Literal::U128(U128::from_bits_be(literal)),
             14 => Literal::Scalar(Scalar::from_bits_be(literal)),
             15 => Literal::String(StringType::from_bits_be(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
+            16 => Literal::Signature(Box::new(Signature::from_bits_be(literal))),
+            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
         }
     }
 }
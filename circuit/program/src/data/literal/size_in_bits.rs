@@ -36,6 +36,8 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => console::U128::<A::Network>::size_in_bits() as u16,
             Self::Scalar(..) => console::Scalar::<A::Network>::size_in_bits() as u16,
             Self::String(string) => string.to_bits_le().len() as u16,
+            // A signature consists of two scalars and the two group elements of the compute key.
+            Self::Signature(..) => (A::ScalarField::size_in_bits() * 2 + A::BaseField::size_in_bits() * 2) as u16,
         }))
     }
 }
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Literal<A> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Boolean<A>, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Address(a), Self::Address(b)) => Self::Address(Address::ternary(condition, a, b)),
+            (Self::Boolean(a), Self::Boolean(b)) => Self::Boolean(Boolean::ternary(condition, a, b)),
+            (Self::Field(a), Self::Field(b)) => Self::Field(Field::ternary(condition, a, b)),
+            (Self::Group(a), Self::Group(b)) => Self::Group(Group::ternary(condition, a, b)),
+            (Self::I8(a), Self::I8(b)) => Self::I8(I8::ternary(condition, a, b)),
+            (Self::I16(a), Self::I16(b)) => Self::I16(I16::ternary(condition, a, b)),
+            (Self::I32(a), Self::I32(b)) => Self::I32(I32::ternary(condition, a, b)),
+            (Self::I64(a), Self::I64(b)) => Self::I64(I64::ternary(condition, a, b)),
+            (Self::I128(a), Self::I128(b)) => Self::I128(I128::ternary(condition, a, b)),
+            (Self::U8(a), Self::U8(b)) => Self::U8(U8::ternary(condition, a, b)),
+            (Self::U16(a), Self::U16(b)) => Self::U16(U16::ternary(condition, a, b)),
+            (Self::U32(a), Self::U32(b)) => Self::U32(U32::ternary(condition, a, b)),
+            (Self::U64(a), Self::U64(b)) => Self::U64(U64::ternary(condition, a, b)),
+            (Self::U128(a), Self::U128(b)) => Self::U128(U128::ternary(condition, a, b)),
+            (Self::Scalar(a), Self::Scalar(b)) => Self::Scalar(Scalar::ternary(condition, a, b)),
+            (Self::String(..), Self::String(..)) => A::halt("Ternary selection of a string literal is not supported"),
+            (Self::Signature(..), Self::Signature(..)) => {
+                A::halt("Ternary selection of a signature literal is not supported")
+            }
+            _ => A::halt("Ternary selection requires literals of the same type"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn check_ternary(first: &str, second: &str) {
+        let first = Literal::<Circuit>::new(Mode::Private, console::Literal::from_str(first).unwrap());
+        let second = Literal::<Circuit>::new(Mode::Private, console::Literal::from_str(second).unwrap());
+
+        for flag in [true, false] {
+            let condition = Boolean::new(Mode::Private, flag);
+            let expected = if flag { first.eject_value() } else { second.eject_value() };
+
+            let candidate = Literal::ternary(&condition, &first, &second);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_ternary() {
+        check_ternary(
+            "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+            "aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add",
+        );
+        check_ternary("true", "false");
+        check_ternary("1field", "2field");
+        check_ternary("-5i8", "7i8");
+        check_ternary("100u128", "3u128");
+        check_ternary("1scalar", "2scalar");
+    }
+}
@@ -18,53 +18,47 @@ use super::*;
 
 impl<A: Aleo> Plaintext<A> {
     /// Returns the plaintext member from the given path.
-    pub fn find(&self, path: &[Identifier<A>]) -> Result<Plaintext<A>> {
+    pub fn find(&self, path: &[Access<A>]) -> Result<Plaintext<A>> {
         // Ensure the path is not empty.
         if path.is_empty() {
             A::halt("Attempted to find member with an empty path.")
         }
 
-        match self {
-            // Halts if the value is not a struct.
-            Self::Literal(..) => A::halt("Literal is not a struct"),
-            // Retrieve the value of the member (from the value).
-            Self::Struct(members, ..) => {
-                // Initialize the members starting from the top-level.
-                let mut submembers = members;
+        // Initialize the plaintext starting from the top-level.
+        let mut plaintext = self;
 
-                // Initialize the output.
-                let mut output = None;
-
-                // Iterate through the path to retrieve the value.
-                for (i, identifier) in path.iter().enumerate() {
-                    // If this is not the last item in the path, ensure the value is a struct.
-                    if i != path.len() - 1 {
-                        match submembers.get(identifier) {
-                            // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
-                            // Retrieve the member and update `submembers` for the next iteration.
-                            Some(Self::Struct(members, ..)) => submembers = members,
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in struct"),
-                        }
+        // Iterate through the path to retrieve the value.
+        for access in path.iter() {
+            // Update the plaintext at each step.
+            plaintext = match (plaintext, access) {
+                // Retrieve the member from the struct.
+                (Self::Struct(members, ..), Access::Member(identifier)) => match members.get(identifier) {
+                    // Retrieve the member and update `plaintext` for the next iteration.
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in struct"),
+                },
+                // Retrieve the element from the array.
+                (Self::Array(elements, ..), Access::Index(index)) => {
+                    // Ensure the index is a constant.
+                    if !index.is_constant() {
+                        bail!("Index '{index}' must be a constant")
                     }
-                    // Otherwise, return the final member.
-                    else {
-                        match submembers.get(identifier) {
-                            // Return the plaintext member.
-                            Some(plaintext) => output = Some(plaintext.clone()),
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in struct"),
-                        }
+                    match elements.get(*index.eject_value() as usize) {
+                        // Retrieve the element and update `plaintext` for the next iteration.
+                        Some(element) => element,
+                        // Halts if the index is out of bounds.
+                        None => bail!("Index '{index}' is out of bounds"),
                     }
                 }
-
-                // Return the output.
-                match output {
-                    Some(output) => Ok(output),
-                    None => A::halt("Failed to locate member in struct from path"),
-                }
-            }
+                // Halts if the access does not match the plaintext.
+                (Self::Literal(..), _) => bail!("'{access}' cannot be applied to a literal"),
+                (Self::Struct(..), Access::Index(..)) => bail!("'{access}' cannot be applied to a struct"),
+                (Self::Array(..), Access::Member(..)) => bail!("'{access}' cannot be applied to an array"),
+            };
         }
+
+        // Return the output.
+        Ok(plaintext.clone())
     }
 }
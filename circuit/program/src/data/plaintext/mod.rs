@@ -24,12 +24,13 @@ mod from_bits;
 mod from_fields;
 mod num_randomizers;
 mod size_in_fields;
+mod ternary;
 mod to_bits;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal, Visibility};
+use crate::{Access, Ciphertext, Identifier, Literal, Visibility};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U16, U8};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U16, U32, U8};
 
 #[derive(Clone)]
 pub enum Plaintext<A: Aleo> {
@@ -37,6 +38,8 @@ pub enum Plaintext<A: Aleo> {
     Literal(Literal<A>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext struct.
     Struct(IndexMap<Identifier<A>, Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext array.
+    Array(Vec<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -48,6 +51,7 @@ impl<A: Aleo> Inject for Plaintext<A> {
         match plaintext {
             Self::Primitive::Literal(literal, _) => Self::Literal(Literal::new(mode, literal), Default::default()),
             Self::Primitive::Struct(struct_, _) => Self::Struct(Inject::new(mode, struct_), Default::default()),
+            Self::Primitive::Array(array, _) => Self::Array(Inject::new(mode, array), Default::default()),
         }
     }
 }
@@ -65,6 +69,7 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .map(|(identifier, value)| (identifier, value).eject_mode())
                 .collect::<Vec<_>>()
                 .eject_mode(),
+            Self::Array(array, _) => array.iter().map(Eject::eject_mode).collect::<Vec<_>>().eject_mode(),
         }
     }
 
@@ -75,6 +80,9 @@ impl<A: Aleo> Eject for Plaintext<A> {
             Self::Struct(struct_, _) => {
                 console::Plaintext::Struct(struct_.iter().map(|pair| pair.eject_value()).collect(), Default::default())
             }
+            Self::Array(array, _) => {
+                console::Plaintext::Array(array.iter().map(Eject::eject_value).collect(), Default::default())
+            }
         }
     }
 }
@@ -226,6 +234,18 @@ mod tests {
             value.to_bits_le().eject(),
             Plaintext::<Circuit>::from_bits_le(&value.to_bits_le()).to_bits_le().eject()
         );
+
+        let value = Plaintext::<Circuit>::Array(
+            vec![
+                Plaintext::<Circuit>::Literal(Literal::Boolean(Boolean::new(Mode::Private, true)), OnceCell::new()),
+                Plaintext::<Circuit>::Literal(Literal::Boolean(Boolean::new(Mode::Private, false)), OnceCell::new()),
+            ],
+            OnceCell::new(),
+        );
+        assert_eq!(
+            value.to_bits_le().eject(),
+            Plaintext::<Circuit>::from_bits_le(&value.to_bits_le()).to_bits_le().eject()
+        );
         Ok(())
     }
 }
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Plaintext<A> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    /// Note: The selection is performed member-wise, and requires `first` and `second` to share the same layout.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Literal(a, _), Self::Literal(b, _)) => {
+                Self::Literal(Literal::ternary(condition, a, b), Default::default())
+            }
+            (Self::Struct(a, _), Self::Struct(b, _)) => {
+                // Ensure the structs have the same number of members.
+                if a.len() != b.len() {
+                    A::halt("Ternary selection requires structs with the same number of members")
+                }
+                // Recursively select each member.
+                let members = a
+                    .iter()
+                    .zip_eq(b.iter())
+                    .map(|((name_a, plaintext_a), (name_b, plaintext_b))| {
+                        // Ensure the member names match.
+                        if name_a != name_b {
+                            A::halt("Ternary selection requires structs with the same member names")
+                        }
+                        (name_a.clone(), Plaintext::ternary(condition, plaintext_a, plaintext_b))
+                    })
+                    .collect();
+                Self::Struct(members, Default::default())
+            }
+            (Self::Array(a, _), Self::Array(b, _)) => {
+                // Ensure the arrays have the same length.
+                if a.len() != b.len() {
+                    A::halt("Ternary selection requires arrays of the same length")
+                }
+                // Recursively select each element.
+                let elements = a
+                    .iter()
+                    .zip_eq(b.iter())
+                    .map(|(plaintext_a, plaintext_b)| Plaintext::ternary(condition, plaintext_a, plaintext_b))
+                    .collect();
+                Self::Array(elements, Default::default())
+            }
+            _ => A::halt("Ternary selection requires plaintexts of the same type"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn sample_plaintext(string: &str) -> Plaintext<Circuit> {
+        Plaintext::new(Mode::Private, console::Plaintext::from_str(string).unwrap())
+    }
+
+    #[test]
+    fn test_ternary() {
+        let first = sample_plaintext("{ a: 1u8, b: { c: true, d: 3field }, e: [1u32, 2u32] }");
+        let second = sample_plaintext("{ a: 2u8, b: { c: false, d: 4field }, e: [3u32, 4u32] }");
+
+        for flag in [true, false] {
+            let condition = Boolean::new(Mode::Private, flag);
+            let expected = if flag { first.eject_value() } else { second.eject_value() };
+
+            let candidate = Plaintext::ternary(&condition, &first, &second);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ternary_fails_on_mismatched_members() {
+        let first = sample_plaintext("{ a: 1u8, b: 2u8 }");
+        let second = sample_plaintext("{ a: 1u8, c: 2u8 }");
+        let _ = Plaintext::ternary(&Boolean::new(Mode::Private, true), &first, &second);
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Entry<A, Plaintext<A>> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    /// Note: The selection is performed member-wise, and requires `first` and `second` to share the same visibility.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Constant(a), Self::Constant(b)) => Self::Constant(Plaintext::ternary(condition, a, b)),
+            (Self::Public(a), Self::Public(b)) => Self::Public(Plaintext::ternary(condition, a, b)),
+            (Self::Private(a), Self::Private(b)) => Self::Private(Plaintext::ternary(condition, a, b)),
+            _ => A::halt("Ternary selection requires entries of the same visibility"),
+        }
+    }
+}
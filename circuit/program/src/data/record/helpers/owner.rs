@@ -116,6 +116,20 @@ impl<A: Aleo, Private: Visibility<A>> Equal<Self> for Owner<A, Private> {
     }
 }
 
+impl<A: Aleo> Ternary for Owner<A, Plaintext<A>> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Public(a), Self::Public(b)) => Self::Public(Address::ternary(condition, a, b)),
+            (Self::Private(a), Self::Private(b)) => Self::Private(Plaintext::ternary(condition, a, b)),
+            _ => A::halt("Ternary selection requires owners of the same visibility"),
+        }
+    }
+}
+
 impl<A: Aleo> Owner<A, Plaintext<A>> {
     /// Encrypts `self` under the given randomizer.
     pub fn encrypt(&self, randomizer: &[Field<A>]) -> Owner<A, Ciphertext<A>> {
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Record<A, Plaintext<A>> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    /// Note: The selection is performed member-wise, and requires `first` and `second` to share the same layout.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Ensure the records have the same number of entries.
+        if first.data.len() != second.data.len() {
+            A::halt("Ternary selection requires records with the same number of entries")
+        }
+
+        // Select the owner.
+        let owner = Owner::ternary(condition, &first.owner, &second.owner);
+        // Recursively select each entry.
+        let data = first
+            .data
+            .iter()
+            .zip_eq(second.data.iter())
+            .map(|((name_a, entry_a), (name_b, entry_b))| {
+                // Ensure the entry names match.
+                if name_a != name_b {
+                    A::halt("Ternary selection requires records with the same entry names")
+                }
+                (name_a.clone(), Entry::ternary(condition, entry_a, entry_b))
+            })
+            .collect();
+        // Select the nonce.
+        let nonce = Group::ternary(condition, &first.nonce, &second.nonce);
+
+        Self { owner, data, nonce }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn sample_record(string: &str) -> Record<Circuit, Plaintext<Circuit>> {
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(string)
+        .unwrap();
+        Record::new(Mode::Private, record)
+    }
+
+    #[test]
+    fn test_ternary() {
+        let first = sample_record(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    a: true.private,
+    b: 123456789field.public,
+    c: { d: 1u8.private, e: 0group.private },
+    _nonce: 0group.public
+}",
+        );
+        let second = sample_record(
+            r"{
+    owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private,
+    a: false.private,
+    b: 987654321field.public,
+    c: { d: 2u8.private, e: 0group.private },
+    _nonce: 0group.public
+}",
+        );
+
+        for flag in [true, false] {
+            let condition = Boolean::new(Mode::Private, flag);
+            let expected = if flag { first.eject_value() } else { second.eject_value() };
+
+            let candidate = Record::ternary(&condition, &first, &second);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+        }
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBits for ComputeKey<N> {
+    /// Initializes a new compute key from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        ensure!(bits_le.len() == Self::size_in_bits(), "Invalid compute key size in bits");
+        // Recover `pk_sig` and `pr_sig`.
+        let (pk_sig, pr_sig) = bits_le.split_at(Group::<N>::size_in_bits());
+        // Derive the compute key.
+        Self::try_from((Group::from_bits_le(pk_sig)?, Group::from_bits_le(pr_sig)?))
+    }
+
+    /// Initializes a new compute key from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        ensure!(bits_be.len() == Self::size_in_bits(), "Invalid compute key size in bits");
+        // Recover `pk_sig` and `pr_sig`.
+        let (pk_sig, pr_sig) = bits_be.split_at(Group::<N>::size_in_bits());
+        // Derive the compute key.
+        Self::try_from((Group::from_bits_be(pk_sig)?, Group::from_bits_be(pr_sig)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new compute key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let expected = ComputeKey::try_from(private_key)?;
+
+            // Check the little-endian bits.
+            let bits_le = expected.to_bits_le();
+            assert_eq!(ComputeKey::<CurrentNetwork>::size_in_bits(), bits_le.len());
+            assert_eq!(expected, ComputeKey::from_bits_le(&bits_le)?);
+            assert!(ComputeKey::<CurrentNetwork>::from_bits_le(&bits_le[1..]).is_err());
+
+            // Check the big-endian bits.
+            let bits_be = expected.to_bits_be();
+            assert_eq!(ComputeKey::<CurrentNetwork>::size_in_bits(), bits_be.len());
+            assert_eq!(expected, ComputeKey::from_bits_be(&bits_be)?);
+            assert!(ComputeKey::<CurrentNetwork>::from_bits_be(&bits_be[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the Keccak hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the output size is supported.
+        ensure!([224, 256, 384, 512].contains(&VARIANT), "Invalid Keccak output size: {VARIANT}");
+
+        // Pad the input to a multiple of the rate.
+        let padded_input = Self::pad(input)?;
+
+        // Initialize the state.
+        let mut state = [0u64; 25];
+        // Absorb the padded input into the state, one block at a time.
+        for block in padded_input.chunks(Self::rate()) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(64)) {
+                *lane ^= bits.iter().rev().fold(0u64, |lane, bit| (lane << 1) | (*bit as u64));
+            }
+            Self::permutation_f(&mut state);
+        }
+
+        // Squeeze the output from the state.
+        // Note: As the output size is less than the rate, the output fits in the first block.
+        Ok(state.iter().flat_map(|lane| (0..64).map(move |i| (lane >> i) & 1 == 1)).take(VARIANT).collect())
+    }
+}
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// Returns the given input, padded to a multiple of the rate.
+    fn pad(input: &[bool]) -> Result<Vec<bool>> {
+        let mut padded_input = input.to_vec();
+        // Append the domain separation bits, based on the padding rule.
+        match TYPE {
+            0 => (),
+            1 => padded_input.extend([false, true]),
+            _ => bail!("Invalid Keccak type: {TYPE}"),
+        }
+        // Append the `pad10*1` padding.
+        padded_input.push(true);
+        while padded_input.len() % Self::rate() != Self::rate() - 1 {
+            padded_input.push(false);
+        }
+        padded_input.push(true);
+        Ok(padded_input)
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permutation_f(state: &mut [u64; 25]) {
+        for round_constant in ROUND_CONSTANTS {
+            // Theta: XOR each lane with the parities of two neighboring columns.
+            let parities: [u64; 5] =
+                core::array::from_fn(|x| state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20]);
+            for x in 0..5 {
+                let parity = parities[(x + 4) % 5] ^ parities[(x + 1) % 5].rotate_left(1);
+                for y in 0..5 {
+                    state[x + 5 * y] ^= parity;
+                }
+            }
+
+            // Rho and pi: rotate each lane, and permute the positions of the lanes.
+            let mut lanes = [0u64; 25];
+            for x in 0..5 {
+                for y in 0..5 {
+                    lanes[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(ROTATION_OFFSETS[x + 5 * y]);
+                }
+            }
+
+            // Chi: combine each lane with the next two lanes in its row.
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = lanes[x + 5 * y] ^ (!lanes[(x + 1) % 5 + 5 * y] & lanes[(x + 2) % 5 + 5 * y]);
+                }
+            }
+
+            // Iota: XOR the first lane with the round constant.
+            state[0] ^= round_constant;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The 448-bit message from the FIPS 180 and FIPS 202 examples.
+    const MESSAGE_448: &str = "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+    /// Returns the little-endian bits of the given bytes.
+    fn to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the given little-endian bits as a hex string.
+    fn to_hex(bits: &[bool]) -> String {
+        let bytes = bits
+            .chunks(8)
+            .map(|byte| byte.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8)))
+            .collect::<Vec<u8>>();
+        hex::encode(bytes)
+    }
+
+    fn check_hash<const TYPE: u8>(input: &[bool], expected: &str) {
+        // Compute the hash.
+        let candidate = Keccak::<TYPE, 256>::new().hash(input).unwrap();
+        assert_eq!(256, candidate.len());
+        assert_eq!(expected, to_hex(&candidate));
+    }
+
+    #[test]
+    fn test_keccak256() {
+        check_hash::<{ KeccakType::Keccak as u8 }>(
+            &to_bits_le(b""),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        );
+        check_hash::<{ KeccakType::Keccak as u8 }>(
+            &to_bits_le(b"abc"),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        );
+        check_hash::<{ KeccakType::Keccak as u8 }>(
+            &to_bits_le(MESSAGE_448.as_bytes()),
+            "45d3b367a6904e6e8d502ee04999a7c27647f91fa845d456525fd352ae3d7371",
+        );
+    }
+
+    #[test]
+    fn test_sha3_256() {
+        check_hash::<{ KeccakType::Sha3 as u8 }>(
+            &to_bits_le(b""),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+        );
+        check_hash::<{ KeccakType::Sha3 as u8 }>(
+            &to_bits_le(b"abc"),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+        );
+        check_hash::<{ KeccakType::Sha3 as u8 }>(
+            &to_bits_le(MESSAGE_448.as_bytes()),
+            "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376",
+        );
+        // The 1600-bit message from the FIPS 202 examples, which spans two blocks.
+        check_hash::<{ KeccakType::Sha3 as u8 }>(
+            &to_bits_le(&[0xa3; 200]),
+            "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787",
+        );
+    }
+
+    #[test]
+    fn test_sha3_256_bits() {
+        // The 5-bit message `11001` from the FIPS 202 examples.
+        check_hash::<{ KeccakType::Sha3 as u8 }>(
+            &[true, true, false, false, true],
+            "7b0047cf5a456882363cbf0fb05322cf65f4b7059a46365e830132e3b5d957af",
+        );
+        // The 30-bit message `110010100001101011011110100110` from the FIPS 202 examples.
+        check_hash::<{ KeccakType::Sha3 as u8 }>(
+            &to_bits_le(&[0x53, 0x58, 0x7b, 0x19])[..30],
+            "c8242fef409e5ae9d1f1c857ae4dc624b92b19809f62aa8c07411c54a078b1d0",
+        );
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+
+/// Keccak256 is the hash function used in Ethereum, with a 256-bit output.
+pub type Keccak256 = Keccak<{ KeccakType::Keccak as u8 }, 256>;
+/// SHA3-256 is the standardized variant of Keccak in FIPS 202, with a 256-bit output.
+pub type Sha3_256 = Keccak<{ KeccakType::Sha3 as u8 }, 256>;
+
+/// The padding rule of the Keccak sponge.
+pub enum KeccakType {
+    /// The original Keccak padding rule, as used in Ethereum.
+    Keccak,
+    /// The SHA-3 padding rule, as standardized in FIPS 202.
+    Sha3,
+}
+
+/// The number of rounds in the Keccak-f[1600] permutation.
+const NUM_ROUNDS: usize = 24;
+/// The width of the Keccak-f[1600] permutation, in bits.
+const PERMUTATION_WIDTH: usize = 1600;
+
+/// The round constants of the Keccak-f[1600] permutation.
+const ROUND_CONSTANTS: [u64; NUM_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the Keccak-f[1600] permutation, for the lane at index `x + 5 * y`.
+const ROTATION_OFFSETS: [u32; 25] = [
+    0, 1, 62, 28, 27, // y = 0
+    36, 44, 6, 55, 20, // y = 1
+    3, 10, 43, 25, 39, // y = 2
+    41, 45, 15, 21, 8, // y = 3
+    18, 2, 61, 56, 14, // y = 4
+];
+
+/// Keccak is a cryptographic hash function based on the sponge construction.
+/// The hash function outputs `VARIANT` bits, and processes inputs in blocks of `1600 - 2 * VARIANT` bits.
+///
+/// ## Design
+/// The input is padded with the padding rule of the given `TYPE`, and absorbed block-by-block
+/// into the state using the Keccak-f[1600] permutation. The output is the first `VARIANT` bits of the state.
+///
+/// All bits are in little-endian order, such that a byte string is hashed as its bits in
+/// least-significant-bit-first order, and the output bits are the output bytes in the same order.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keccak<const TYPE: u8, const VARIANT: usize>;
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// Initializes a new instance of Keccak.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the rate of the sponge, in bits.
+    pub const fn rate() -> usize {
+        PERMUTATION_WIDTH - 2 * VARIANT
+    }
+
+    /// Returns the round constants of the Keccak-f[1600] permutation.
+    pub const fn round_constants() -> &'static [u64; NUM_ROUNDS] {
+        &ROUND_CONSTANTS
+    }
+
+    /// Returns the rotation offsets of the Keccak-f[1600] permutation, for the lane at index `x + 5 * y`.
+    pub const fn rotation_offsets() -> &'static [u32; 25] {
+        &ROTATION_OFFSETS
+    }
+}
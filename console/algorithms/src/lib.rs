@@ -30,6 +30,9 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod keccak;
+pub use keccak::{Keccak, Keccak256, KeccakType, Sha3_256};
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
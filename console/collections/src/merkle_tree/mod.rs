@@ -199,6 +199,17 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn append(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
         let timer = timer!("MerkleTree::append");
 
+        // If the new leaves fit in the current tree size, update the tree in place.
+        let updated_number_of_leaves = self.number_of_leaves + new_leaves.len();
+        if self.is_same_size(updated_number_of_leaves) {
+            // Compute the new leaf hashes.
+            let leaf_hashes = self.leaf_hasher.hash_leaves(new_leaves)?;
+            // Update the tree with the new leaf hashes.
+            self.update_in_place(self.number_of_leaves, &leaf_hashes, updated_number_of_leaves)?;
+            finish!(timer);
+            return Ok(());
+        }
+
         // Compute the updated Merkle tree with the new leaves.
         let updated_tree = self.prepare_append(new_leaves)?;
         // Update the tree at the very end, so the original tree is not altered in case of failure.
@@ -491,6 +502,16 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn remove_last_n(&mut self, n: usize) -> Result<()> {
         let timer = timer!("MerkleTree::remove_last_n");
 
+        // If the remaining leaves fill the current tree size, update the tree in place.
+        if let Some(updated_number_of_leaves) = self.number_of_leaves.checked_sub(n) {
+            if n > 0 && self.is_same_size(updated_number_of_leaves) {
+                // Update the tree with the last 'n' leaf hashes replaced by empty hashes.
+                self.update_in_place(updated_number_of_leaves, &[], updated_number_of_leaves)?;
+                finish!(timer);
+                return Ok(());
+            }
+        }
+
         // Compute the updated Merkle tree with the last 'n' leaves removed.
         let updated_tree = self.prepare_remove_last_n(n)?;
         // Update the tree at the very end, so the original tree is not altered in case of failure.
@@ -586,6 +607,96 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         self.number_of_leaves
     }
 
+    /// Returns `true` if the Merkle tree with the given number of leaves has the same size as the current tree.
+    #[inline]
+    fn is_same_size(&self, number_of_leaves: usize) -> bool {
+        match (self.number_of_leaves.checked_next_power_of_two(), number_of_leaves.checked_next_power_of_two()) {
+            (Some(current), Some(updated)) => current == updated,
+            _ => false,
+        }
+    }
+
+    /// Updates the Merkle tree in place, by replacing the leaf hashes from the given leaf index onwards
+    /// with the given leaf hashes, followed by empty hashes in place of any removed leaves,
+    /// and recomputing only the hashes above the replaced leaf hashes.
+    ///
+    /// Note: The updated number of leaves must have the same tree size as the current number of leaves.
+    #[inline]
+    fn update_in_place(
+        &mut self,
+        leaf_index: usize,
+        leaf_hashes: &[LH::Hash],
+        updated_number_of_leaves: usize,
+    ) -> Result<()> {
+        // Ensure the tree size is unchanged.
+        ensure!(self.is_same_size(updated_number_of_leaves), "The Merkle tree size must not change in place");
+        // Ensure the leaf hashes end at the updated number of leaves.
+        ensure!(
+            leaf_index + leaf_hashes.len() == updated_number_of_leaves,
+            "The leaf hashes must end at the updated number of leaves in the Merkle tree"
+        );
+
+        // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
+        let start = match self.number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves - 1,
+            None => bail!("Integer overflow when computing the Merkle tree start index"),
+        };
+
+        // Prepare the replaced leaf hashes, padded with empty hashes in place of any removed leaves.
+        let mut replaced_hashes = leaf_hashes.to_vec();
+        replaced_hashes.resize(self.number_of_leaves.max(updated_number_of_leaves) - leaf_index, self.empty_hash);
+        // If no leaf hashes are replaced, the tree is unchanged.
+        if replaced_hashes.is_empty() {
+            return Ok(());
+        }
+
+        // Compute the replaced hashes for each level, iterating from the leaf level to the root level.
+        // Note: The hashes are only stored at the very end, so the tree is not altered in case of failure.
+        let mut levels = vec![(start + leaf_index, replaced_hashes)];
+        while let Some((first, hashes)) = levels.last() {
+            // Compute the range of the parents of the replaced hashes in the current level.
+            let (parent_first, parent_last) = match (parent(*first), parent(first + hashes.len() - 1)) {
+                (Some(parent_first), Some(parent_last)) => (parent_first, parent_last),
+                _ => break,
+            };
+            // Retrieve the hash at the given index, from the replaced hashes if it was replaced.
+            let hash_at = |index: usize| match index.checked_sub(*first).and_then(|i| hashes.get(i)) {
+                Some(hash) => *hash,
+                None => self.tree[index],
+            };
+            // Compute the hashes for the parents in the current level.
+            let parent_hashes = cfg_into_iter!(parent_first..=parent_last)
+                .map(|i| self.path_hasher.hash_children(&hash_at(left_child(i)), &hash_at(right_child(i))))
+                .collect::<Result<Vec<_>>>()?;
+            levels.push((parent_first, parent_hashes));
+        }
+
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(self.tree.len())?;
+        // Compute the padding depth.
+        let padding_depth = DEPTH - tree_depth;
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        // Note: The last level is guaranteed to be the root level, as the parents are computed up to the root.
+        let mut root_hash = match levels.last() {
+            Some((_, hashes)) if !hashes.is_empty() => hashes[0],
+            _ => bail!("Failed to compute the root level of the Merkle tree"),
+        };
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hash)?;
+        }
+
+        // Store the replaced hashes for each level.
+        for (first, hashes) in levels {
+            self.tree[first..first + hashes.len()].copy_from_slice(&hashes);
+        }
+        // Update the root hash and the number of leaves.
+        self.root = root_hash;
+        self.number_of_leaves = updated_number_of_leaves;
+        Ok(())
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
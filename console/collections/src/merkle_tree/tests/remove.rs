@@ -41,6 +41,12 @@ fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash
     let mut new_merkle_tree = merkle_tree.prepare_append(additional_leaves)?;
     assert_eq!(leaves.len() + additional_leaves.len(), new_merkle_tree.number_of_leaves);
 
+    // Ensure that appending the additional leaves in place matches the new Merkle tree.
+    let mut appended_merkle_tree = merkle_tree.clone();
+    appended_merkle_tree.append(additional_leaves)?;
+    assert_eq!(appended_merkle_tree.root(), new_merkle_tree.root());
+    assert_eq!(appended_merkle_tree.tree(), new_merkle_tree.tree());
+
     // Remove the additional leaves from the new Merkle tree.
     if !additional_leaves.is_empty() {
         new_merkle_tree.remove_last_n(additional_leaves.len())?;
@@ -98,6 +98,15 @@ pub trait Network:
     /// The starting supply of Aleo credits.
     const STARTING_SUPPLY: u64 = 1_500_000_000_000_000; // 1.5B credits
 
+    /// The cost in microcredits per byte of a deployment.
+    const DEPLOYMENT_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
+    /// The cost in microcredits per constraint of a function in a deployment.
+    const SYNTHESIS_FEE_MULTIPLIER: u64 = 25; // 25 microcredits per constraint
+    /// The cost in microcredits per byte of an execution.
+    const EXECUTION_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
+    /// The cost in microcredits per command in the finalize scopes of an execution.
+    const FINALIZE_FEE_MULTIPLIER: u64 = 10_000; // 10 millicredits per command
+
     /// The anchor time per block in seconds, which must be greater than the round time per block.
     const ANCHOR_TIME: u16 = 25;
     /// The coinbase puzzle degree.
@@ -121,6 +130,11 @@ pub trait Network:
     /// The maximum number of entries in a struct.
     const MAX_STRUCT_ENTRIES: usize = Self::MAX_DATA_ENTRIES;
 
+    /// The minimum number of elements in an array.
+    const MIN_ARRAY_ELEMENTS: usize = 1; // This ensures the array is not empty.
+    /// The maximum number of elements in an array.
+    const MAX_ARRAY_ELEMENTS: usize = Self::MAX_DATA_ENTRIES;
+
     /// The minimum number of entries in a record.
     const MIN_RECORD_ENTRIES: usize = 1; // This accounts for 'record.owner'.
     /// The maximum number of entries in a record.
@@ -203,6 +217,18 @@ pub trait Network:
     /// Returns a BHP commitment with an input hasher of 1024-bits.
     fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>>;
 
+    /// Returns a BHP commitment with an input hasher of 256-bits on the affine curve.
+    fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>>;
+
+    /// Returns a BHP commitment with an input hasher of 512-bits on the affine curve.
+    fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>>;
+
+    /// Returns a BHP commitment with an input hasher of 768-bits on the affine curve.
+    fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>>;
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits on the affine curve.
+    fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>>;
+
     /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
     fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>>;
 
@@ -227,6 +253,12 @@ pub trait Network:
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>>;
 
@@ -245,6 +277,24 @@ pub trait Network:
     /// Returns the extended Poseidon hash with an input rate of 8.
     fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
+    /// Returns the BHP hash with an input hasher of 256-bits on the affine curve.
+    fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>>;
+
+    /// Returns the BHP hash with an input hasher of 512-bits on the affine curve.
+    fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>>;
+
+    /// Returns the BHP hash with an input hasher of 768-bits on the affine curve.
+    fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>>;
+
+    /// Returns the BHP hash with an input hasher of 1024-bits on the affine curve.
+    fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>>;
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input on the affine curve.
+    fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>>;
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input on the affine curve.
+    fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
     fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>>;
 
@@ -17,11 +17,13 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha3_256,
     BHP1024,
     BHP256,
     BHP512,
@@ -62,6 +64,11 @@ lazy_static! {
     /// The Pedersen hash function, which can take an input of up to 128 bits.
     pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Pedersen128::<Testnet3>::setup("AleoPedersen128");
 
+    /// The Keccak hash function, with a 256-bit output.
+    pub static ref KECCAK_256: Keccak256 = Keccak256::new();
+    /// The SHA-3 hash function, with a 256-bit output.
+    pub static ref SHA3_256: Sha3_256 = Sha3_256::new();
+
     /// The Poseidon hash function, using a rate of 2.
     pub static ref POSEIDON_2: Poseidon2<Testnet3> = Poseidon2::<Testnet3>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
     /// The Poseidon hash function, using a rate of 4.
@@ -253,6 +260,26 @@ impl Network for Testnet3 {
         BHP_1024.commit(input, randomizer)
     }
 
+    /// Returns a BHP commitment with an input hasher of 256-bits on the affine curve.
+    fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        BHP_256.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits on the affine curve.
+    fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        BHP_512.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits on the affine curve.
+    fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        BHP_768.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits on the affine curve.
+    fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        BHP_1024.commit_uncompressed(input, randomizer)
+    }
+
     /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
     fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
         PEDERSEN_64.commit_uncompressed(input, randomizer)
@@ -293,6 +320,16 @@ impl Network for Testnet3 {
         PEDERSEN_128.hash(input)
     }
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+        KECCAK_256.hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+        SHA3_256.hash(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
         POSEIDON_2.hash(input)
@@ -323,6 +360,36 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_many(input, num_outputs)
     }
 
+    /// Returns the BHP hash with an input hasher of 256-bits on the affine curve.
+    fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
+        BHP_256.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits on the affine curve.
+    fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
+        BHP_512.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits on the affine curve.
+    fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
+        BHP_768.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits on the affine curve.
+    fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
+        BHP_1024.hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input on the affine curve.
+    fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
+        PEDERSEN_64.hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input on the affine curve.
+    fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
+        PEDERSEN_128.hash_uncompressed(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
     fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
         POSEIDON_2.hash_to_group(input)
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for Access<N> {
+    /// Parses a string into an access.
+    /// The access is of the form `.{identifier}` or `[{index}]`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        alt((
+            map(pair(tag("."), Identifier::parse), |(_, identifier)| Self::Member(identifier)),
+            map(pair(tag("["), terminated(U32::parse, tag("]"))), |(_, index)| Self::Index(index)),
+        ))(string)
+    }
+}
+
+impl<N: Network> FromStr for Access<N> {
+    type Err = Error;
+
+    /// Parses a string into an access.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Access<N> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Access<N> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            // Prints the member access, i.e. .owner
+            Self::Member(identifier) => write!(f, ".{identifier}"),
+            // Prints the index access, i.e. [3u32]
+            Self::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(Access::parse(".owner"), Ok(("", Access::<CurrentNetwork>::Member(Identifier::from_str("owner")?))));
+        assert_eq!(Access::parse("[3u32]"), Ok(("", Access::<CurrentNetwork>::Index(U32::new(3)))));
+        assert_eq!(Access::parse("[3u32].a"), Ok((".a", Access::<CurrentNetwork>::Index(U32::new(3)))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        assert!(Access::<CurrentNetwork>::parse("").is_err());
+        assert!(Access::<CurrentNetwork>::parse(".").is_err());
+        assert!(Access::<CurrentNetwork>::parse("owner").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[3]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[3u8]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[-1i32]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[r0]").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(Access::<CurrentNetwork>::Member(Identifier::from_str("owner")?).to_string(), ".owner");
+        assert_eq!(Access::<CurrentNetwork>::Index(U32::new(3)).to_string(), "[3u32]");
+        Ok(())
+    }
+}
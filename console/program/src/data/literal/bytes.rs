@@ -37,7 +37,8 @@ impl<N: Network> FromBytes for Literal<N> {
             13 => Self::U128(U128::read_le(&mut reader)?),
             14 => Self::Scalar(Scalar::read_le(&mut reader)?),
             15 => Self::String(StringType::read_le(&mut reader)?),
-            16.. => return Err(error(format!("Failed to decode literal variant {index}"))),
+            16 => Self::Signature(Box::new(Signature::read_le(&mut reader)?)),
+            17.. => return Err(error(format!("Failed to decode literal variant {index}"))),
         };
         Ok(literal)
     }
@@ -112,6 +113,10 @@ impl<N: Network> ToBytes for Literal<N> {
                 (15 as Size).write_le(&mut writer)?;
                 primitive.write_le(&mut writer)
             }
+            Self::Signature(primitive) => {
+                (16 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
         }
     }
 }
@@ -173,6 +178,8 @@ mod tests {
             check_bytes(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)))?;
             // String
             check_bytes(Literal::<CurrentNetwork>::String(StringType::rand(rng)))?;
+            // Signature
+            check_bytes(Literal::sample(LiteralType::Signature, rng))?;
         }
         Ok(())
     }
@@ -0,0 +1,381 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_types::{environment::traits::integers::IntegerType, integers::Integer};
+
+impl<N: Network> Literal<N> {
+    /// Casts the literal into the given literal type.
+    ///
+    /// The cast preserves the value of the literal, and fails if the value does not fit in the given type.
+    /// Group and address literals are cast by their x-coordinate, and negative integers only fit in integer types.
+    pub fn cast(&self, to_type: LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, false)
+    }
+
+    /// Casts the literal into the given literal type, with lossy truncation.
+    ///
+    /// The little-endian bits of the literal are sign-extended or truncated to fit in the given type,
+    /// following the semantics of an `as` cast in Rust. Field and scalar types are treated as unsigned.
+    /// As a group element cannot be recovered from a truncated x-coordinate, lossy casts into
+    /// group and address types fail when the value is not a valid x-coordinate.
+    pub fn cast_lossy(&self, to_type: LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, true)
+    }
+}
+
+impl<N: Network> Literal<N> {
+    /// Casts the literal into the given literal type, with lossy truncation if `is_lossy` is `true`.
+    fn cast_internal(&self, to_type: LiteralType, is_lossy: bool) -> Result<Self> {
+        // If the literal is already of the given type, return the literal.
+        if self.to_type() == to_type {
+            return Ok(self.clone());
+        }
+
+        match (self, to_type) {
+            // Ensure strings and signatures are not cast into or from any other type.
+            (Self::String(..), _)
+            | (_, LiteralType::String)
+            | (Self::Signature(..), _)
+            | (_, LiteralType::Signature) => {
+                bail!("Cannot cast a '{}' literal into a '{to_type}' literal", self.to_type())
+            }
+            // Casting between an address and a group element preserves the underlying group element.
+            (Self::Address(address), LiteralType::Group) => Ok(Self::Group(**address)),
+            (Self::Group(group), LiteralType::Address) => Ok(Self::Address(Address::new(*group))),
+            // Otherwise, cast the little-endian bits of the literal.
+            _ => {
+                // Retrieve the little-endian bits of the literal, and whether they are signed.
+                let (bits_le, is_signed) = self.to_cast_bits_le()?;
+                // Cast the bits into the given literal type.
+                let output = match to_type {
+                    LiteralType::Address => {
+                        Self::Address(Address::new(Group::from_x_coordinate(self.cast_to_field(is_lossy)?)?))
+                    }
+                    LiteralType::Boolean => {
+                        Self::Boolean(Boolean::new(cast_bits_le(&bits_le, is_signed, 1, false, is_lossy)?[0]))
+                    }
+                    LiteralType::Field => Self::Field(self.cast_to_field(is_lossy)?),
+                    LiteralType::Group => Self::Group(Group::from_x_coordinate(self.cast_to_field(is_lossy)?)?),
+                    LiteralType::I8 => Self::I8(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::I16 => Self::I16(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::I32 => Self::I32(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::I64 => Self::I64(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::I128 => Self::I128(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::U8 => Self::U8(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::U16 => Self::U16(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::U32 => Self::U32(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::U64 => Self::U64(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::U128 => Self::U128(to_integer(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::Scalar => Self::Scalar(to_scalar(&bits_le, is_signed, is_lossy)?),
+                    LiteralType::String => bail!("Cannot cast a '{}' literal into a string", self.to_type()),
+                    LiteralType::Signature => bail!("Cannot cast a '{}' literal into a signature", self.to_type()),
+                };
+                Ok(output)
+            }
+        }
+    }
+
+    /// Casts the literal into a base field element, with lossy truncation if `is_lossy` is `true`.
+    /// Group and address literals are cast into their x-coordinate.
+    fn cast_to_field(&self, is_lossy: bool) -> Result<Field<N>> {
+        match self {
+            Self::Address(address) => Ok(address.to_x_coordinate()),
+            Self::Boolean(boolean) => match **boolean {
+                true => Ok(Field::one()),
+                false => Ok(Field::zero()),
+            },
+            Self::Field(field) => Ok(*field),
+            Self::Group(group) => Ok(group.to_x_coordinate()),
+            Self::Scalar(scalar) => scalar.to_field(),
+            _ => {
+                // Retrieve the little-endian bits of the literal, and whether they are signed.
+                let (bits_le, is_signed) = self.to_cast_bits_le()?;
+                // Cast the bits into a base field element.
+                to_field(&bits_le, is_signed, is_lossy)
+            }
+        }
+    }
+
+    /// Returns the little-endian bits of the literal for casting, and `true` if the bits are signed.
+    /// Group and address literals are represented by the bits of their x-coordinate.
+    fn to_cast_bits_le(&self) -> Result<(Vec<bool>, bool)> {
+        match self {
+            Self::Address(address) => Ok((address.to_x_coordinate().to_bits_le(), false)),
+            Self::Boolean(boolean) => Ok((vec![**boolean], false)),
+            Self::Field(field) => Ok((field.to_bits_le(), false)),
+            Self::Group(group) => Ok((group.to_x_coordinate().to_bits_le(), false)),
+            Self::I8(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I16(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I32(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I64(integer) => Ok((integer.to_bits_le(), true)),
+            Self::I128(integer) => Ok((integer.to_bits_le(), true)),
+            Self::U8(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U16(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U32(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U64(integer) => Ok((integer.to_bits_le(), false)),
+            Self::U128(integer) => Ok((integer.to_bits_le(), false)),
+            Self::Scalar(scalar) => Ok((scalar.to_bits_le(), false)),
+            Self::String(..) => bail!("Cannot cast a string literal"),
+            Self::Signature(..) => bail!("Cannot cast a signature literal"),
+        }
+    }
+}
+
+/// Returns the given little-endian bits, sign-extended (if signed) or zero-extended to `num_bits` bits.
+fn extend_bits_le(bits_le: &[bool], is_signed: bool, num_bits: usize) -> Vec<bool> {
+    // Retrieve the most significant bit, which is the sign bit for signed integers.
+    let msb = is_signed && bits_le.last().copied().unwrap_or(false);
+    // Extend the bits up to the given number of bits.
+    let mut bits_le = bits_le.to_vec();
+    bits_le.resize(bits_le.len().max(num_bits), msb);
+    bits_le
+}
+
+/// Casts the given little-endian bits into `num_bits` bits, which are signed if `to_signed` is `true`.
+/// If `is_lossy` is `false`, this method ensures that the value is preserved.
+fn cast_bits_le(
+    bits_le: &[bool],
+    is_signed: bool,
+    num_bits: usize,
+    to_signed: bool,
+    is_lossy: bool,
+) -> Result<Vec<bool>> {
+    // Extend the bits by one bit more than the larger of the two sizes, so that both values are comparable.
+    let extended = extend_bits_le(bits_le, is_signed, bits_le.len().max(num_bits) + 1);
+    // Truncate the bits to the given number of bits.
+    let output = extended[..num_bits].to_vec();
+    // If this is a checked cast, ensure that extending the output recovers the original value.
+    if !is_lossy {
+        // Retrieve the bit that the output is extended with.
+        let msb = to_signed && output[num_bits - 1];
+        // Ensure the remaining bits all match the extension of the output.
+        ensure!(extended[num_bits..].iter().all(|bit| *bit == msb), "The value does not fit in the cast type");
+    }
+    Ok(output)
+}
+
+/// Casts the given little-endian bits into an integer.
+fn to_integer<N: Network, I: IntegerType>(bits_le: &[bool], is_signed: bool, is_lossy: bool) -> Result<Integer<N, I>> {
+    Integer::from_bits_le(&cast_bits_le(bits_le, is_signed, I::BITS as usize, I::is_signed(), is_lossy)?)
+}
+
+/// Casts the given little-endian bits into a base field element.
+fn to_field<N: Network>(bits_le: &[bool], is_signed: bool, is_lossy: bool) -> Result<Field<N>> {
+    // If this is a checked cast, ensure the value is not negative.
+    if !is_lossy && is_signed {
+        ensure!(!bits_le.last().copied().unwrap_or(false), "Cannot cast a negative value into a field element");
+    }
+    // Note: A signed value is reinterpreted as unsigned, as field elements do not have a sign.
+    Field::from_bits_le(bits_le)
+}
+
+/// Casts the given little-endian bits into a scalar field element.
+fn to_scalar<N: Network>(bits_le: &[bool], is_signed: bool, is_lossy: bool) -> Result<Scalar<N>> {
+    match is_lossy {
+        // Truncate the bits to the scalar data size, which is guaranteed to be less than the scalar modulus.
+        true => Scalar::from_bits_le(&bits_le[..bits_le.len().min(Scalar::<N>::size_in_data_bits())]),
+        false => {
+            // Ensure the value is not negative.
+            if is_signed {
+                ensure!(!bits_le.last().copied().unwrap_or(false), "Cannot cast a negative value into a scalar");
+            }
+            // Ensure the value is less than the scalar modulus.
+            Scalar::from_bits_le(bits_le)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 100;
+
+    /// Checks the integer casts from `$from` into each integer type against the native `try_from` and `as` casts.
+    macro_rules! check_integer_casts {
+        ($rng:expr, $from:ident, $from_variant:ident, [$( ($to:ident, $to_variant:ident) ),+]) => {{
+            for _ in 0..ITERATIONS {
+                let value: $from = Uniform::rand($rng);
+                let literal = Literal::<CurrentNetwork>::$from_variant(Integer::new(value));
+                $(
+                    // Check the checked cast.
+                    let candidate = literal.cast(LiteralType::$to_variant);
+                    match $to::try_from(value) {
+                        Ok(expected) => assert_eq!(candidate.unwrap(), Literal::$to_variant(Integer::new(expected))),
+                        Err(_) => assert!(candidate.is_err()),
+                    }
+                    // Check the lossy cast.
+                    let candidate = literal.cast_lossy(LiteralType::$to_variant).unwrap();
+                    assert_eq!(candidate, Literal::$to_variant(Integer::new(value as $to)));
+                )+
+            }
+        }};
+    }
+
+    /// Checks the integer casts from `$from` into every integer type.
+    macro_rules! check_integer_casts_from {
+        ($rng:expr, $( ($from:ident, $from_variant:ident) ),+) => {{
+            $(
+                check_integer_casts!($rng, $from, $from_variant, [
+                    (i8, I8), (i16, I16), (i32, I32), (i64, I64), (i128, I128),
+                    (u8, U8), (u16, U16), (u32, U32), (u64, U64), (u128, U128)
+                ]);
+            )+
+        }};
+    }
+
+    #[test]
+    fn test_cast_integers() {
+        let rng = &mut TestRng::default();
+
+        check_integer_casts_from!(
+            rng,
+            (i8, I8),
+            (i16, I16),
+            (i32, I32),
+            (i64, I64),
+            (i128, I128),
+            (u8, U8),
+            (u16, U16),
+            (u32, U32),
+            (u64, U64),
+            (u128, U128)
+        );
+    }
+
+    #[test]
+    fn test_cast_boolean() -> Result<()> {
+        let one = Literal::<CurrentNetwork>::U8(U8::new(1));
+        let two = Literal::<CurrentNetwork>::U8(U8::new(2));
+        let three = Literal::<CurrentNetwork>::U8(U8::new(3));
+
+        assert_eq!(one.cast(LiteralType::Boolean)?, Literal::Boolean(Boolean::new(true)));
+        assert!(two.cast(LiteralType::Boolean).is_err());
+        assert_eq!(two.cast_lossy(LiteralType::Boolean)?, Literal::Boolean(Boolean::new(false)));
+        assert_eq!(three.cast_lossy(LiteralType::Boolean)?, Literal::Boolean(Boolean::new(true)));
+
+        let true_ = Literal::<CurrentNetwork>::Boolean(Boolean::new(true));
+        assert_eq!(true_.cast(LiteralType::I8)?, Literal::I8(I8::new(1)));
+        assert_eq!(true_.cast(LiteralType::Field)?, Literal::Field(Field::one()));
+        assert_eq!(true_.cast(LiteralType::Scalar)?, Literal::Scalar(Scalar::one()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_field() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure an integer casts into a field element losslessly.
+            let integer = U64::<CurrentNetwork>::new(Uniform::rand(rng));
+            let field = Literal::U64(integer).cast(LiteralType::Field)?;
+            assert_eq!(field, Literal::Field(integer.to_field()?));
+            // Ensure the field element casts back into the same integer.
+            assert_eq!(field.cast(LiteralType::U64)?, Literal::U64(integer));
+            assert_eq!(field.cast_lossy(LiteralType::U64)?, Literal::U64(integer));
+
+            // Ensure a random field element does not fit in an integer, and is truncated by a lossy cast.
+            let field: Field<CurrentNetwork> = Uniform::rand(rng);
+            let literal = Literal::Field(field);
+            assert!(literal.cast(LiteralType::U128).is_err());
+            let expected = u128::from_bits_le(&field.to_bits_le()[..128])?;
+            assert_eq!(literal.cast_lossy(LiteralType::U128)?, Literal::U128(U128::new(expected)));
+        }
+
+        // Ensure a negative integer does not cast into a field element, and is reinterpreted by a lossy cast.
+        let negative = Literal::<CurrentNetwork>::I8(I8::new(-1));
+        assert!(negative.cast(LiteralType::Field).is_err());
+        assert_eq!(negative.cast_lossy(LiteralType::Field)?, Literal::Field(Field::from_u8(255)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_scalar() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure a scalar casts into a field element and back.
+            let scalar: Scalar<CurrentNetwork> = Uniform::rand(rng);
+            let field = Literal::Scalar(scalar).cast(LiteralType::Field)?;
+            assert_eq!(field, Literal::Field(scalar.to_field()?));
+            assert_eq!(field.cast(LiteralType::Scalar)?, Literal::Scalar(scalar));
+
+            // Ensure a lossy cast of a field element always succeeds, and preserves the lower bits.
+            let field: Field<CurrentNetwork> = Uniform::rand(rng);
+            let candidate = Literal::Field(field).cast_lossy(LiteralType::Scalar)?;
+            let size_in_data_bits = Scalar::<CurrentNetwork>::size_in_data_bits();
+            let expected = Scalar::from_bits_le(&field.to_bits_le()[..size_in_data_bits])?;
+            assert_eq!(candidate, Literal::Scalar(expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_group_and_address() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let group: Group<CurrentNetwork> = Uniform::rand(rng);
+            let literal = Literal::Group(group);
+
+            // Ensure a group element casts into its x-coordinate, and back.
+            let field = literal.cast(LiteralType::Field)?;
+            assert_eq!(field, Literal::Field(group.to_x_coordinate()));
+            assert_eq!(
+                field.cast(LiteralType::Group)?,
+                Literal::Group(Group::from_x_coordinate(group.to_x_coordinate())?)
+            );
+            assert_eq!(field.cast_lossy(LiteralType::Group)?, field.cast(LiteralType::Group)?);
+
+            // Ensure a group element casts into an address, and back.
+            let address = literal.cast(LiteralType::Address)?;
+            assert_eq!(address, Literal::Address(Address::new(group)));
+            assert_eq!(address.cast(LiteralType::Group)?, literal);
+        }
+
+        // Ensure an invalid x-coordinate does not cast into a group element.
+        let invalid = Literal::<CurrentNetwork>::Field(Field::one());
+        assert!(invalid.cast(LiteralType::Group).is_err());
+        assert!(invalid.cast_lossy(LiteralType::Group).is_err());
+        assert!(invalid.cast(LiteralType::Address).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_string() -> Result<()> {
+        let string = Literal::<CurrentNetwork>::String(StringType::new("hello"));
+        assert_eq!(string.cast(LiteralType::String)?, string);
+        assert!(string.cast(LiteralType::Field).is_err());
+        assert!(string.cast_lossy(LiteralType::U8).is_err());
+        assert!(Literal::<CurrentNetwork>::U8(U8::new(1)).cast(LiteralType::String).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_signature() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let signature = Literal::<CurrentNetwork>::sample(LiteralType::Signature, rng);
+        assert_eq!(signature.cast(LiteralType::Signature)?, signature);
+        assert!(signature.cast(LiteralType::Field).is_err());
+        assert!(signature.cast_lossy(LiteralType::U8).is_err());
+        assert!(Literal::<CurrentNetwork>::Field(Field::one()).cast_lossy(LiteralType::Signature).is_err());
+        Ok(())
+    }
+}
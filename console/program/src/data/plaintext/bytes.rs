@@ -44,7 +44,20 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the struct.
                 Self::Struct(members, Default::default())
             }
-            2.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            2 => {
+                let num_elements = u32::read_le(&mut reader)?;
+                if num_elements as usize > N::MAX_ARRAY_ELEMENTS {
+                    return Err(error(format!("Plaintext array exceeds {} elements", N::MAX_ARRAY_ELEMENTS)));
+                }
+                let mut elements = Vec::with_capacity(num_elements as usize);
+                for _ in 0..num_elements {
+                    let num_bytes = u16::read_le(&mut reader)?;
+                    let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                    elements.push(Plaintext::read_le(&mut bytes.as_slice())?);
+                }
+                Self::Array(elements, Default::default())
+            }
+            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -82,6 +95,22 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Array(elements, ..) => {
+                2u8.write_le(&mut writer)?;
+
+                u32::try_from(elements.len())
+                    .or_halt_with::<N>("Plaintext array length exceeds u32::MAX.")
+                    .write_le(&mut writer)?;
+
+                for element in elements {
+                    let bytes = element.to_bytes_le().map_err(|e| error(e.to_string()))?;
+                    u16::try_from(bytes.len())
+                        .or_halt_with::<N>("Plaintext element exceeds u16::MAX bytes.")
+                        .write_le(&mut writer)?;
+                    bytes.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -194,6 +223,11 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
         assert!(Plaintext::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        let expected = Plaintext::<CurrentNetwork>::from_str("[ { a: [1u8, 2u8] }, { a: [3u8, 4u8] } ]")?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
+        assert!(Plaintext::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
 }
@@ -18,51 +18,39 @@ use super::*;
 
 impl<N: Network> Plaintext<N> {
     /// Returns the plaintext member from the given path.
-    pub fn find(&self, path: &[Identifier<N>]) -> Result<Plaintext<N>> {
+    pub fn find(&self, path: &[Access<N>]) -> Result<Plaintext<N>> {
         // Ensure the path is not empty.
         ensure!(!path.is_empty(), "Attempted to find member with an empty path.");
 
-        match self {
-            // Halts if the value is not a struct.
-            Self::Literal(..) => bail!("'{self}' is not a struct"),
-            // Retrieve the value of the member (from the value).
-            Self::Struct(members, ..) => {
-                // Initialize the members starting from the top-level.
-                let mut submembers = members;
-
-                // Initialize the output.
-                let mut output = None;
-
-                // Iterate through the path to retrieve the value.
-                for (i, identifier) in path.iter().enumerate() {
-                    // If this is not the last item in the path, ensure the value is a struct.
-                    if i != path.len() - 1 {
-                        match submembers.get(identifier) {
-                            // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
-                            // Retrieve the member and update `submembers` for the next iteration.
-                            Some(Self::Struct(members, ..)) => submembers = members,
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
-                    // Otherwise, return the final member.
-                    else {
-                        match submembers.get(identifier) {
-                            // Return the plaintext member.
-                            Some(plaintext) => output = Some(plaintext.clone()),
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
-                }
-
-                // Return the output.
-                match output {
-                    Some(output) => Ok(output),
-                    None => bail!("Failed to locate member from path in '{self}': {:?}", path),
-                }
-            }
+        // Initialize the plaintext starting from the top-level.
+        let mut plaintext = self;
+
+        // Iterate through the path to retrieve the value.
+        for access in path.iter() {
+            // Update the plaintext at each step.
+            plaintext = match (plaintext, access) {
+                // Retrieve the member from the struct.
+                (Self::Struct(members, ..), Access::Member(identifier)) => match members.get(identifier) {
+                    // Retrieve the member and update `plaintext` for the next iteration.
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in '{self}'"),
+                },
+                // Retrieve the element from the array.
+                (Self::Array(elements, ..), Access::Index(index)) => match elements.get(**index as usize) {
+                    // Retrieve the element and update `plaintext` for the next iteration.
+                    Some(element) => element,
+                    // Halts if the index is out of bounds.
+                    None => bail!("Index '{index}' is out of bounds in '{self}'"),
+                },
+                // Halts if the access does not match the plaintext.
+                (Self::Literal(..), _) => bail!("'{access}' cannot be applied to a literal"),
+                (Self::Struct(..), Access::Index(..)) => bail!("'{access}' cannot be applied to a struct"),
+                (Self::Array(..), Access::Member(..)) => bail!("'{access}' cannot be applied to an array"),
+            };
         }
+
+        // Return the output.
+        Ok(plaintext.clone())
     }
 }
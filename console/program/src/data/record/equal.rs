@@ -29,9 +29,12 @@ impl<N: Network, Private: Visibility<Boolean = Boolean<N>>> Equal<Self> for Reco
     type Output = Boolean<N>;
 
     /// Returns `true` if `self` and `other` are equal.
-    ///
-    /// Note: This method does **not** check the `nonce` equality.
     fn is_equal(&self, other: &Self) -> Self::Output {
+        // Ensure the `data` lengths match.
+        if self.data.len() != other.data.len() {
+            return Boolean::new(false);
+        }
+
         // Recursively check each entry for equality.
         let mut equal = Boolean::new(true);
         for ((name_a, entry_a), (name_b, entry_b)) in self.data.iter().zip_eq(other.data.iter()) {
@@ -43,9 +46,12 @@ impl<N: Network, Private: Visibility<Boolean = Boolean<N>>> Equal<Self> for Reco
     }
 
     /// Returns `true` if `self` and `other` are *not* equal.
-    ///
-    /// Note: This method does **not** check the `nonce` equality.
     fn is_not_equal(&self, other: &Self) -> Self::Output {
+        // Ensure the `data` lengths match.
+        if self.data.len() != other.data.len() {
+            return Boolean::new(true);
+        }
+
         // Recursively check each entry for inequality.
         let mut not_equal = Boolean::new(false);
         for ((name_a, entry_a), (name_b, entry_b)) in self.data.iter().zip_eq(other.data.iter()) {
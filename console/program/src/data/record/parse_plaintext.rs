@@ -157,10 +157,10 @@ impl<N: Network> Record<N, Plaintext<N>> {
                 Entry::Constant(Plaintext::Literal(..))
                 | Entry::Public(Plaintext::Literal(..))
                 | Entry::Private(Plaintext::Literal(..)) => write!(f, "{entry}")?,
-                // If the entry is a struct, print the entry with indentation.
-                Entry::Constant(Plaintext::Struct(..))
-                | Entry::Public(Plaintext::Struct(..))
-                | Entry::Private(Plaintext::Struct(..)) => entry.fmt_internal(f, depth + 1)?,
+                // If the entry is a struct or an array, print the entry with indentation.
+                Entry::Constant(Plaintext::Struct(..) | Plaintext::Array(..))
+                | Entry::Public(Plaintext::Struct(..) | Plaintext::Array(..))
+                | Entry::Private(Plaintext::Struct(..) | Plaintext::Array(..)) => entry.fmt_internal(f, depth + 1)?,
             }
             // Print the comma.
             write!(f, ",")?;
@@ -24,14 +24,18 @@ impl<N: Network> FromBytes for Register<N> {
         match variant {
             0 => Ok(Self::Locator(locator)),
             1 => {
-                // Read the number of identifiers.
-                let num_identifiers = u16::read_le(&mut reader)?;
-                // Read the identifiers.
-                let mut identifiers = Vec::with_capacity(num_identifiers as usize);
-                for _ in 0..num_identifiers {
-                    identifiers.push(Identifier::read_le(&mut reader)?);
+                // Read the number of accesses.
+                let num_accesses = u16::read_le(&mut reader)?;
+                // Ensure the number of accesses is within the limit.
+                if num_accesses as usize > N::MAX_DATA_DEPTH {
+                    return Err(error("Failed to deserialize register: too many accesses"));
                 }
-                Ok(Self::Member(locator, identifiers))
+                // Read the accesses.
+                let mut accesses = Vec::with_capacity(num_accesses as usize);
+                for _ in 0..num_accesses {
+                    accesses.push(Access::read_le(&mut reader)?);
+                }
+                Ok(Self::Access(locator, accesses))
             }
             2.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
         }
@@ -46,18 +50,18 @@ impl<N: Network> ToBytes for Register<N> {
                 u8::write_le(&0u8, &mut writer)?;
                 variable_length_integer(locator).write_le(&mut writer)
             }
-            Self::Member(locator, identifiers) => {
-                // Ensure the number of identifiers is within the limit.
-                if identifiers.len() > N::MAX_DATA_DEPTH {
-                    return Err(error("Failed to serialize register: too many identifiers"));
+            Self::Access(locator, accesses) => {
+                // Ensure the number of accesses is within the limit.
+                if accesses.len() > N::MAX_DATA_DEPTH {
+                    return Err(error("Failed to serialize register: too many accesses"));
                 }
 
                 u8::write_le(&1u8, &mut writer)?;
                 variable_length_integer(locator).write_le(&mut writer)?;
-                u16::try_from(identifiers.len())
+                u16::try_from(accesses.len())
                     .or_halt_with::<N>("Register path length exceeds u16::MAX")
                     .write_le(&mut writer)?;
-                identifiers.write_le(&mut writer)
+                accesses.write_le(&mut writer)
             }
         }
     }
@@ -18,7 +18,7 @@ use super::*;
 
 impl<N: Network> Parser for Register<N> {
     /// Parses a string into a register.
-    /// The register is of the form `r{locator}` or `r{locator}.{identifier}`.
+    /// The register is of the form `r{locator}`, `r{locator}.{identifier}`, or `r{locator}[{index}]`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the register character from the string.
@@ -26,20 +26,19 @@ impl<N: Network> Parser for Register<N> {
         // Parse the locator from the string.
         let (string, locator) =
             map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)?;
-        // Parse the identifier from the string, if it is a register member.
-        let (string, identifiers): (&str, Vec<Identifier<N>>) =
-            map_res(many0(pair(tag("."), Identifier::parse)), |identifiers| {
-                // Ensure the number of identifiers is within the limit.
-                if identifiers.len() <= N::MAX_DATA_DEPTH {
-                    Ok(identifiers.iter().cloned().map(|(_, identifier)| identifier).collect())
-                } else {
-                    Err(error(format!("Register \'r{locator}\' has too many identifiers ({})", identifiers.len())))
-                }
-            })(string)?;
+        // Parse the accesses from the string, if it is a register access.
+        let (string, accesses): (&str, Vec<Access<N>>) = map_res(many0(Access::parse), |accesses: Vec<Access<N>>| {
+            // Ensure the number of accesses is within the limit.
+            if accesses.len() <= N::MAX_DATA_DEPTH {
+                Ok(accesses)
+            } else {
+                Err(error(format!("Register \'r{locator}\' has too many accesses ({})", accesses.len())))
+            }
+        })(string)?;
         // Return the register.
-        Ok((string, match identifiers.len() {
+        Ok((string, match accesses.len() {
             0 => Self::Locator(locator),
-            _ => Self::Member(locator, identifiers),
+            _ => Self::Access(locator, accesses),
         }))
     }
 }
@@ -75,11 +74,11 @@ impl<N: Network> Display for Register<N> {
         match self {
             // Prints the register, i.e. r0
             Self::Locator(locator) => write!(f, "r{locator}"),
-            // Prints the register member, i.e. r0.owner
-            Self::Member(locator, identifiers) => {
+            // Prints the register access, i.e. r0.owner or r0[3u32]
+            Self::Access(locator, accesses) => {
                 write!(f, "r{locator}")?;
-                for identifier in identifiers {
-                    write!(f, ".{identifier}")?;
+                for access in accesses {
+                    write!(f, "{access}")?;
                 }
                 Ok(())
             }
@@ -90,6 +89,8 @@ impl<N: Network> Display for Register<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Identifier;
+    use snarkvm_console_types::U32;
     use snarkvm_console_network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -103,26 +104,26 @@ mod tests {
         assert_eq!("r3", format!("{}", Register::<CurrentNetwork>::Locator(3)));
         assert_eq!("r4", format!("{}", Register::<CurrentNetwork>::Locator(4)));
 
-        // Register::Member
+        // Register::Access
         assert_eq!(
             "r0.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Access(0, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r1.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Access(1, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r2.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(2, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Access(2, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r3.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(3, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Access(3, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r4.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(4, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Access(4, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         Ok(())
     }
@@ -136,25 +137,25 @@ mod tests {
         assert_eq!(Register::<CurrentNetwork>::Locator(3).to_string(), "r3".to_string());
         assert_eq!(Register::<CurrentNetwork>::Locator(4).to_string(), "r4".to_string());
 
-        // Register::Member
+        // Register::Access
         assert_eq!(
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Access(0, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r0.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Access(1, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r1.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(2, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Access(2, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r2.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(3, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Access(3, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r3.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(4, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Access(4, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r4.owner".to_string()
         );
         Ok(())
@@ -169,29 +170,51 @@ mod tests {
         assert_eq!(("", Register::<CurrentNetwork>::Locator(3)), Register::parse("r3").unwrap());
         assert_eq!(("", Register::<CurrentNetwork>::Locator(4)), Register::parse("r4").unwrap());
 
-        // Register::Member
+        // Register::Access
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Access(0, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r0.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Access(1, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r1.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(2, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Access(2, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r2.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(3, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Access(3, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r3.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(4, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Access(4, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r4.owner").unwrap()
         );
 
-        // Register::Member with multiple identifiers
+        // Register::Access with indexes
+        assert_eq!(
+            ("", Register::<CurrentNetwork>::Access(0, vec![Access::Index(U32::new(3))])),
+            Register::parse("r0[3u32]").unwrap()
+        );
+        assert_eq!(
+            (
+                "",
+                Register::<CurrentNetwork>::Access(1, vec![
+                    Access::Member(Identifier::from_str("owner")?),
+                    Access::Index(U32::new(0)),
+                    Access::Index(U32::new(1)),
+                    Access::Member(Identifier::from_str("foo")?),
+                ])
+            ),
+            Register::parse("r1.owner[0u32][1u32].foo").unwrap()
+        );
+        assert_eq!(
+            "r1.owner[0u32][1u32].foo",
+            Register::<CurrentNetwork>::from_str("r1.owner[0u32][1u32].foo")?.to_string()
+        );
+
+        // Register::Access with multiple identifiers
         for i in 1..=CurrentNetwork::MAX_DATA_DEPTH {
             let mut string = "r0.".to_string();
             for _ in 0..i {
@@ -200,7 +223,7 @@ mod tests {
             string.pop(); // Remove last '.'
 
             assert_eq!(
-                ("", Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?; i])),
+                ("", Register::<CurrentNetwork>::Access(0, vec![Access::Member(Identifier::from_str("owner")?); i])),
                 Register::<CurrentNetwork>::parse(&string).unwrap()
             );
         }
@@ -212,8 +235,10 @@ mod tests {
     fn test_register_parser_fails() {
         assert!(Register::<CurrentNetwork>::parse("").is_err());
         assert!(Register::<CurrentNetwork>::parse("r").is_err());
+        assert!(Register::<CurrentNetwork>::from_str("r0[3]").is_err());
+        assert!(Register::<CurrentNetwork>::from_str("r0[r1]").is_err());
 
-        // Register::Member with multiple identifiers that exceed the maximum depth.
+        // Register::Access with multiple identifiers that exceed the maximum depth.
         for i in CurrentNetwork::MAX_DATA_DEPTH + 1..CurrentNetwork::MAX_DATA_DEPTH * 2 {
             let mut string = "r0.".to_string();
             for _ in 0..i {
@@ -83,6 +83,8 @@ mod tests {
             check_serde_json(Register::<CurrentNetwork>::from_str(&format!("r{i}.a.b.c.e")).unwrap());
             check_serde_json(Register::<CurrentNetwork>::from_str(&format!("r{i}.a.b.c.e.f")).unwrap());
             check_serde_json(Register::<CurrentNetwork>::from_str(&format!("r{i}.hello_world_foo_bar")).unwrap());
+            check_serde_json(Register::<CurrentNetwork>::from_str(&format!("r{i}[0u32]")).unwrap());
+            check_serde_json(Register::<CurrentNetwork>::from_str(&format!("r{i}.a[1u32].b[2u32][3u32]")).unwrap());
         }
     }
 
@@ -96,6 +98,8 @@ mod tests {
             check_bincode(Register::<CurrentNetwork>::from_str(&format!("r{i}.a.b.c.e")).unwrap());
             check_bincode(Register::<CurrentNetwork>::from_str(&format!("r{i}.a.b.c.e.f")).unwrap());
             check_bincode(Register::<CurrentNetwork>::from_str(&format!("r{i}.hello_world_foo_bar")).unwrap());
+            check_bincode(Register::<CurrentNetwork>::from_str(&format!("r{i}[0u32]")).unwrap());
+            check_bincode(Register::<CurrentNetwork>::from_str(&format!("r{i}.a[1u32].b[2u32][3u32]")).unwrap());
         }
     }
 }
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for ArrayType<N> {
+    /// Reads an array type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let length = U32::read_le(&mut reader)?;
+        // Note: The element type is read last, so the reader can be passed by value (avoiding unbounded recursion).
+        let element_type = PlaintextType::read_le(reader)?;
+        Self::new(element_type, length).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ArrayType<N> {
+    /// Writes the array type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.length.write_le(&mut writer)?;
+        // Note: The element type is written last, so the writer can be passed by value (avoiding unbounded recursion).
+        self.element_type.write_le(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["[u8; 1u32]", "[field; 32u32]", "[foo; 4u32]", "[[boolean; 2u32]; 3u32]"] {
+            let expected = ArrayType::<CurrentNetwork>::from_str(string)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, ArrayType::read_le(&expected_bytes[..])?);
+        }
+
+        // Ensure an out-of-bounds length fails to deserialize.
+        let mut bytes = U32::<CurrentNetwork>::new(33).to_bytes_le()?;
+        bytes.extend(PlaintextType::<CurrentNetwork>::from_str("u8")?.to_bytes_le()?);
+        assert!(ArrayType::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
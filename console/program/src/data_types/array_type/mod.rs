@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::PlaintextType;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::U32;
+
+/// An `ArrayType` defines the element type and the length of an array, i.e. `[u8; 4u32]`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The type of the elements in the array.
+    element_type: Box<PlaintextType<N>>,
+    /// The number of elements in the array.
+    length: U32<N>,
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type from the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: U32<N>) -> Result<Self> {
+        // Ensure the number of elements is within the bounds.
+        ensure!(
+            *length as usize >= N::MIN_ARRAY_ELEMENTS,
+            "An array must have at least {} element(s)",
+            N::MIN_ARRAY_ELEMENTS
+        );
+        ensure!(
+            *length as usize <= N::MAX_ARRAY_ELEMENTS,
+            "An array cannot have more than {} elements",
+            N::MAX_ARRAY_ELEMENTS
+        );
+        Ok(Self { element_type: Box::new(element_type), length })
+    }
+
+    /// Returns the element type.
+    #[inline]
+    pub fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+
+    /// Returns the base element type, i.e. the element type of the innermost array.
+    #[inline]
+    pub fn base_element_type(&self) -> &PlaintextType<N> {
+        let mut element_type = self.element_type();
+        while let PlaintextType::Array(array_type) = element_type {
+            element_type = array_type.element_type();
+        }
+        element_type
+    }
+
+    /// Returns the number of elements in the array.
+    #[inline]
+    pub const fn length(&self) -> &U32<N> {
+        &self.length
+    }
+}
@@ -18,11 +18,11 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{Identifier, LiteralType};
+use crate::{ArrayType, Identifier, LiteralType};
 use snarkvm_console_network::prelude::*;
 
 /// A `ValueType` defines the type parameter for an entry in an `Struct`.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum PlaintextType<N: Network> {
     /// A literal type contains its type name.
     /// The format of the type is `<type_name>`.
@@ -30,6 +30,9 @@ pub enum PlaintextType<N: Network> {
     /// An struct type contains its identifier.
     /// The format of the type is `<identifier>`.
     Struct(Identifier<N>),
+    /// An array type contains its element type and length.
+    /// The format of the type is `[<element_type>; <length>]`.
+    Array(ArrayType<N>),
 }
 
 impl<N: Network> From<LiteralType> for PlaintextType<N> {
@@ -45,3 +48,10 @@ impl<N: Network> From<Identifier<N>> for PlaintextType<N> {
         PlaintextType::Struct(struct_)
     }
 }
+
+impl<N: Network> From<ArrayType<N>> for PlaintextType<N> {
+    /// Initializes a plaintext type from an array type.
+    fn from(array: ArrayType<N>) -> Self {
+        PlaintextType::Array(array)
+    }
+}
@@ -24,6 +24,7 @@ impl<N: Network> Parser for PlaintextType<N> {
         alt((
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
+            map(ArrayType::parse, |array_type| Self::Array(array_type)),
         ))(string)
     }
 }
@@ -60,6 +61,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Literal(literal) => Display::fmt(literal, f),
             // Prints the struct, i.e. signature
             Self::Struct(struct_) => Display::fmt(struct_, f),
+            // Prints the array, i.e. [u8; 4u32]
+            Self::Array(array) => Display::fmt(array, f),
         }
     }
 }
@@ -79,7 +82,15 @@ mod tests {
         );
         assert_eq!(
             PlaintextType::parse("signature"),
-            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?)))
+            Ok(("", PlaintextType::<CurrentNetwork>::Literal(LiteralType::Signature)))
+        );
+        assert_eq!(
+            PlaintextType::parse("message"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("message")?)))
+        );
+        assert_eq!(
+            PlaintextType::parse("[field; 4u32]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 4u32]")?)))
         );
         Ok(())
     }
@@ -153,6 +164,10 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?).to_string(),
             "signature"
         );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[[u8; 2u32]; 4u32]")?).to_string(),
+            "[[u8; 2u32]; 4u32]"
+        );
         Ok(())
     }
 }
@@ -237,6 +237,14 @@ impl<N: Network> Transaction<N> {
             Self::Execute(_, _, None) => Ok(U64::zero()),
         }
     }
+
+    /// Returns the fee transition, if one exists.
+    pub fn fee_transition(&self) -> Option<&Fee<N>> {
+        match self {
+            Self::Deploy(_, _, _, fee) => Some(fee),
+            Self::Execute(_, _, fee) => fee.as_ref(),
+        }
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -40,12 +40,13 @@ impl<N: Network> Process<N> {
     }
 
     /// Verifies the given deployment is ordered.
+    /// On success, returns the number of constraints of each function in the deployment.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
-    ) -> Result<()> {
+    ) -> Result<Vec<(Identifier<N>, u64)>> {
         let timer = timer!("Process::verify_deployment");
         // Retrieve the program ID.
         let program_id = deployment.program().id();
@@ -64,6 +65,36 @@ impl<N: Network> Process<N> {
         verification
     }
 
+    /// Returns the number of constraints of each function in the given deployment, by synthesizing its circuits.
+    #[inline]
+    pub fn deployment_num_constraints<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<(Identifier<N>, u64)>> {
+        let timer = timer!("Process::deployment_num_constraints");
+
+        // Compute the stack.
+        let stack = Stack::new(self, deployment.program())?;
+        lap!(timer, "Compute the stack");
+
+        // Synthesize the circuit of each function.
+        let num_constraints = deployment
+            .program()
+            .functions()
+            .keys()
+            .map(|function_name| {
+                let assignment = stack.synthesize_assignment::<A, R>(function_name, rng)?;
+                Ok((*function_name, assignment.num_constraints()))
+            })
+            .collect::<Result<Vec<_>>>();
+        lap!(timer, "Synthesize the circuits");
+
+        finish!(timer);
+
+        num_constraints
+    }
+
     /// Finalizes the deployment.
     /// This method assumes the given deployment **is valid**.
     #[inline]
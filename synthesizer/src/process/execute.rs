@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::finalize::{Branch, Command, Finalize};
 
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
@@ -223,11 +224,12 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
-    /// Finalizes the execution.
+    /// Finalizes the execution, given the global state of the block being finalized.
     /// This method assumes the given execution **is valid**.
     #[inline]
     pub fn finalize_execution<P: ProgramStorage<N>>(
         &self,
+        state: FinalizeGlobalState<N>,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
     ) -> Result<()> {
@@ -274,7 +276,11 @@ impl<N: Network> Process<N> {
                 };
 
                 // Initialize the registers.
-                let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+                let mut registers = FinalizeRegisters::<N>::new(
+                    state,
+                    *transition.id(),
+                    stack.get_finalize_types(finalize.name())?.clone(),
+                );
 
                 // Store the inputs.
                 finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
@@ -282,11 +288,22 @@ impl<N: Network> Process<N> {
                     registers.store(stack, register, input.clone())
                 })?;
 
+                // Initialize a counter for the commands.
+                let mut counter = 0;
                 // Evaluate the commands.
-                for command in finalize.commands() {
+                while counter < finalize.commands().len() {
+                    // Retrieve the command.
+                    let command = &finalize.commands()[counter];
+                    // Finalize the command, and retrieve the index of the next command.
+                    let result = match command {
+                        Command::BranchEq(branch_eq) => branch_to(counter, branch_eq, finalize, stack, &registers),
+                        Command::BranchNeq(branch_neq) => branch_to(counter, branch_neq, finalize, stack, &registers),
+                        _ => command.finalize(stack, store, &mut registers).map(|_| counter + 1),
+                    };
                     // If the evaluation fails, bail and return the error.
-                    if let Err(error) = command.finalize(stack, store, &mut registers) {
-                        bail!("'finalize' failed to evaluate command ({command}): {error}");
+                    match result {
+                        Ok(next) => counter = next,
+                        Err(error) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                     }
                 }
 
@@ -298,3 +315,27 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 }
+
+/// Evaluates the given `branch` command, and returns the index of the next command to finalize.
+#[inline]
+fn branch_to<N: Network, const VARIANT: u8>(
+    counter: usize,
+    branch: &Branch<N, VARIANT>,
+    finalize: &Finalize<N>,
+    stack: &Stack<N>,
+    registers: &impl Load<N>,
+) -> Result<usize> {
+    // If the branch is not taken, proceed to the next command.
+    if !branch.is_taken(stack, registers)? {
+        return Ok(counter + 1);
+    }
+    // Retrieve the index of the position.
+    let position = match finalize.positions().get(branch.position()) {
+        Some(position) => *position,
+        None => bail!("Position '{}' does not exist.", branch.position()),
+    };
+    // Ensure the branch jumps forward.
+    ensure!(position > counter, "Cannot branch backwards to position '{}'", branch.position());
+    // Return the index of the position.
+    Ok(position)
+}
@@ -99,6 +99,21 @@ impl<N: Network> Process<N> {
         // Return success.
         Ok(())
     }
+
+    /// Removes the given program from the process.
+    /// This is used to undo the deployment of a program, when the block that deployed it is removed.
+    #[inline]
+    pub(crate) fn remove_program(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure no other program imports the program.
+        if let Some(stack) = self.stacks.values().find(|stack| stack.program().imports().contains_key(program_id)) {
+            bail!("Cannot remove '{program_id}' from the process, as '{}' imports it", stack.program_id())
+        }
+        // Remove the stack from the process.
+        match self.stacks.shift_remove(program_id) {
+            Some(_) => Ok(()),
+            None => bail!("Program '{program_id}' does not exist in the process"),
+        }
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -1239,7 +1254,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
 
         // Check that the account balance is now 8.
         let candidate =
@@ -1339,7 +1354,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
 
         // Check that the account balance is now 0.
         let candidate =
@@ -1457,7 +1472,7 @@ finalize mint_public:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
 
         // Check the account balance.
         let candidate =
@@ -1596,7 +1611,7 @@ function mint:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
 
         // Check the account balance.
         let candidate =
@@ -1698,11 +1713,411 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
 
         // Check that the account balance is now 8.
         let candidate =
             store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
         assert_eq!(candidate, Value::from_str("16u64").unwrap());
     }
+
+    #[test]
+    fn test_process_execute_and_finalize_contains() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+mapping before:
+    key owner as address.public;
+    value status as boolean.public;
+
+mapping after:
+    key owner as address.public;
+    value status as boolean.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    contains account[r0] into r2;
+    set r2 into before[r0];
+    set r1 into account[r0];
+    contains account[r0] into r3;
+    set r3 into after[r0];
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mappings.
+        let account_name = Identifier::from_str("account").unwrap();
+        let before_name = Identifier::from_str("before").unwrap();
+        let after_name = Identifier::from_str("after").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        // Check that the circuit key can be synthesized.
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+        // Reset the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Ensure the key does not exist in the mapping before finalizing.
+        assert!(!store.contains_key(program_id, &account_name, &key).unwrap());
+
+        // Now, finalize the execution.
+        process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
+
+        // Check that `contains` observed the key as missing before it was set, and present after.
+        let candidate = store.get_value(program_id, &before_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("false").unwrap());
+        let candidate = store.get_value(program_id, &after_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("true").unwrap());
+
+        // Check that the account balance is now 8.
+        let candidate = store.get_value(program_id, &account_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("8u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_remove() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into account[r0];
+    remove account[r0];
+    remove account[r0];
+    contains account[r0] into r2;
+    assert.eq r2 false;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        // Check that the circuit key can be synthesized.
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+        // Reset the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        // Note that the second `remove` is a no-op, as the key no longer exists.
+        process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
+
+        // Check that the key was removed from the mapping.
+        assert!(!store.contains_key(program_id, &mapping_name, &key).unwrap());
+        assert!(store.get_value(program_id, &mapping_name, &key).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_branch() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    branch.eq r2 0u64 to initialize;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    branch.eq true true to exit;
+    position initialize;
+    set r1 into account[r0];
+    position exit;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        // Check that the circuit key can be synthesized.
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+        // Reset the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Execute and finalize the function twice.
+        // The first call branches to `initialize`, and the second call branches to `exit`.
+        for expected in ["8u64", "16u64"] {
+            // Declare the input value.
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+            let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Execute the request.
+            let (response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            let candidate = response.outputs();
+            assert_eq!(0, candidate.len());
+
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+
+            // Now, finalize the execution.
+            process.finalize_execution(FinalizeGlobalState::new_genesis(), &store, &execution).unwrap();
+
+            // Check the account balance.
+            let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+            assert_eq!(candidate, Value::from_str(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_rand_chacha() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping results:
+    key left as u8.public;
+    value right as u64.public;
+
+function compute:
+    input r0 as u8.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u8.public;
+    rand.chacha into r1 as u64;
+    rand.chacha into r2 as u64;
+    set r1 into results[r0];
+    add r0 1u8 into r3;
+    set r2 into results[r3];
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("results").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize the program stores.
+        let stores = (0..3).map(|_| ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap()).collect::<Vec<_>>();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        for store in &stores {
+            process.finalize_deployment(store, &deployment).unwrap();
+        }
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str("0u8").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(0, response.outputs().len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Finalize the execution twice with the same global state, and once with a different global state.
+        let state = FinalizeGlobalState::new(10, Default::default());
+        process.finalize_execution(state, &stores[0], &execution).unwrap();
+        process.finalize_execution(state, &stores[1], &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new(11, Default::default()), &stores[2], &execution).unwrap();
+
+        // Retrieve the random values from each store.
+        let results = stores
+            .iter()
+            .map(|store| {
+                ["0u8", "1u8"].map(|key| {
+                    let key = Plaintext::from_str(key).unwrap();
+                    store.get_value(program_id, &mapping_name, &key).unwrap().unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure each call to `rand.chacha` samples a different value.
+        assert_ne!(results[0][0], results[0][1]);
+        // Ensure replaying the execution with the same global state is deterministic.
+        assert_eq!(results[0], results[1]);
+        // Ensure finalizing in a different block samples different values.
+        assert_ne!(results[0], results[2]);
+    }
 }
@@ -54,12 +54,13 @@ impl<N: Network> Stack<N> {
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
+    /// On success, returns the number of constraints of each function, as synthesized for the check.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
-    ) -> Result<()> {
+    ) -> Result<Vec<(Identifier<N>, u64)>> {
         let timer = timer!("Stack::verify_deployment");
 
         // Sanity Checks //
@@ -73,65 +74,27 @@ impl<N: Network> Stack<N> {
 
         let program_id = self.program.id();
 
+        // Initialize a vector for the number of constraints of each function.
+        let mut num_constraints = Vec::with_capacity(deployment.verifying_keys().len());
+
         // Iterate through the program functions.
         for (function, (_, (verifying_key, certificate))) in
             deployment.program().functions().values().zip_eq(deployment.verifying_keys())
         {
-            // Initialize a burner private key.
-            let burner_private_key = PrivateKey::new(rng)?;
-            // Compute the burner address.
-            let burner_address = Address::try_from(&burner_private_key)?;
-            // Retrieve the input types.
-            let input_types = function.input_types();
-            // Sample the inputs.
-            let inputs = input_types
-                .iter()
-                .map(|input_type| match input_type {
-                    ValueType::ExternalRecord(locator) => {
-                        // Retrieve the external stack.
-                        let stack = self.get_external_stack(locator.program_id())?;
-                        // Sample the input.
-                        stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
-                    }
-                    _ => self.sample_value(&burner_address, input_type, rng),
-                })
-                .collect::<Result<Vec<_>>>()?;
-            lap!(timer, "Sample the inputs");
-
-            // Compute the request, with a burner private key.
-            let request = Request::sign(
-                &burner_private_key,
-                *program_id,
-                *function.name(),
-                inputs.into_iter(),
-                &input_types,
-                rng,
-            )?;
-            lap!(timer, "Compute the request for {}", function.name());
-            // Initialize the assignments.
-            let assignments = Assignments::<N>::default();
-            // Initialize the call stack.
-            let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
             // Synthesize the circuit.
-            let _response = self.execute_function::<A, R>(call_stack, rng)?;
-            lap!(timer, "Synthesize the circuit");
-            // Check the certificate.
-            match assignments.read().last() {
-                None => {
-                    bail!("The assignment for function '{}' is missing in '{program_id}'", function.name())
-                }
-                Some(assignment) => {
-                    // Ensure the certificate is valid.
-                    if !certificate.verify(function.name(), assignment, verifying_key) {
-                        bail!("The certificate for function '{}' is invalid in '{program_id}'", function.name())
-                    }
-                    lap!(timer, "Ensure the certificate is valid");
-                }
-            };
+            let assignment = self.synthesize_assignment::<A, R>(function.name(), rng)?;
+            lap!(timer, "Synthesize the circuit for {}", function.name());
+            // Ensure the certificate is valid.
+            if !certificate.verify(function.name(), &assignment, verifying_key) {
+                bail!("The certificate for function '{}' is invalid in '{program_id}'", function.name())
+            }
+            lap!(timer, "Ensure the certificate is valid");
+            // Record the number of constraints of the function.
+            num_constraints.push((*function.name(), assignment.num_constraints()));
         }
 
         finish!(timer);
 
-        Ok(())
+        Ok(num_constraints)
     }
 }
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Block;
+use console::network::prelude::*;
+
+/// The global state of the block being finalized, as exposed to the `finalize` scope.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FinalizeGlobalState<N: Network> {
+    /// The height of the block being finalized.
+    block_height: u32,
+    /// The hash of the block preceding the block being finalized.
+    previous_block_hash: N::BlockHash,
+}
+
+impl<N: Network> FinalizeGlobalState<N> {
+    /// Initializes a new global state, given the height and previous block hash of the block being finalized.
+    #[inline]
+    pub const fn new(block_height: u32, previous_block_hash: N::BlockHash) -> Self {
+        Self { block_height, previous_block_hash }
+    }
+
+    /// Initializes the global state for the genesis block.
+    #[inline]
+    pub fn new_genesis() -> Self {
+        Self { block_height: 0, previous_block_hash: N::BlockHash::default() }
+    }
+
+    /// Returns the height of the block being finalized.
+    #[inline]
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the hash of the block preceding the block being finalized.
+    #[inline]
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+}
+
+impl<N: Network> From<&Block<N>> for FinalizeGlobalState<N> {
+    /// Returns the global state for finalizing the given block.
+    #[inline]
+    fn from(block: &Block<N>) -> Self {
+        Self::new(block.height(), block.previous_hash())
+    }
+}
@@ -17,7 +17,7 @@
 mod load;
 mod store;
 
-use crate::{FinalizeTypes, Load, Operand, Stack, Store};
+use crate::{FinalizeGlobalState, FinalizeTypes, Load, Operand, Stack, Store};
 use console::{
     network::prelude::*,
     program::{Literal, Plaintext, Register, Value},
@@ -27,6 +27,12 @@ use indexmap::IndexMap;
 
 #[derive(Clone)]
 pub struct FinalizeRegisters<N: Network> {
+    /// The global state of the block being finalized.
+    state: FinalizeGlobalState<N>,
+    /// The transition ID of the transition being finalized.
+    transition_id: N::TransitionID,
+    /// The number of `rand.chacha` commands that have been finalized.
+    nonce: u64,
     /// The mapping of all registers to their defined types.
     finalize_types: FinalizeTypes<N>,
     /// The mapping of assigned registers to their values.
@@ -34,9 +40,33 @@ pub struct FinalizeRegisters<N: Network> {
 }
 
 impl<N: Network> FinalizeRegisters<N> {
-    /// Initializes a new set of registers, given the finalize types.
+    /// Initializes a new set of registers, given the global state, transition ID, and finalize types.
     #[inline]
-    pub fn new(finalize_types: FinalizeTypes<N>) -> Self {
-        Self { finalize_types, registers: IndexMap::new() }
+    pub fn new(
+        state: FinalizeGlobalState<N>,
+        transition_id: N::TransitionID,
+        finalize_types: FinalizeTypes<N>,
+    ) -> Self {
+        Self { state, transition_id, nonce: 0, finalize_types, registers: IndexMap::new() }
+    }
+
+    /// Returns the global state of the block being finalized.
+    #[inline]
+    pub const fn state(&self) -> &FinalizeGlobalState<N> {
+        &self.state
+    }
+
+    /// Returns the transition ID of the transition being finalized.
+    #[inline]
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the current nonce, and increments it for the next `rand.chacha` command.
+    #[inline]
+    pub fn increment_nonce(&mut self) -> Result<u64> {
+        let nonce = self.nonce;
+        self.nonce = nonce.checked_add(1).ok_or_else(|| anyhow!("Nonce overflowed in 'finalize'"))?;
+        Ok(nonce)
     }
 }
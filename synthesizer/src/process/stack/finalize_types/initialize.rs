@@ -16,7 +16,7 @@
 
 use super::*;
 
-use crate::finalize::{Get, GetOrInit, Set};
+use crate::finalize::{Branch, Contains, Get, GetOrInit, RandChaCha, Remove, Set};
 
 impl<N: Network> FinalizeTypes<N> {
     /// Initializes a new instance of `FinalizeTypes` for the given finalize.
@@ -64,7 +64,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -87,7 +87,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -110,10 +110,18 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            PlaintextType::Array(array_type) => {
+                // Ensure the base element struct, if any, is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
         };
 
         // Insert the input register.
-        self.add_input(register.clone(), *plaintext_type)?;
+        self.add_input(register.clone(), plaintext_type.clone())?;
 
         // Ensure the register type and the input type match.
         if *plaintext_type != self.get_type(stack, register)? {
@@ -130,6 +138,13 @@ impl<N: Network> FinalizeTypes<N> {
             Command::Get(get) => self.check_get(stack, finalize_name, get)?,
             Command::GetOrInit(get_or_init) => self.check_get_or_init(stack, finalize_name, get_or_init)?,
             Command::Set(set) => self.check_set(stack, finalize_name, set)?,
+            Command::Contains(contains) => self.check_contains(stack, finalize_name, contains)?,
+            Command::Remove(remove) => self.check_remove(stack, finalize_name, remove)?,
+            // Note that the position is checked when the function is added to the program.
+            Command::Position(_) => (),
+            Command::BranchEq(branch_eq) => self.check_branch(stack, finalize_name, branch_eq)?,
+            Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize_name, branch_neq)?,
+            Command::RandChaCha(rand_chacha) => self.check_rand_chacha(rand_chacha)?,
         }
         Ok(())
     }
@@ -159,7 +174,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference a member).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, *mapping_value_type)?;
+        self.add_destination(destination, mapping_value_type.clone())?;
         Ok(())
     }
 
@@ -207,7 +222,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference a member).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, *mapping_value_type)?;
+        self.add_destination(destination, mapping_value_type.clone())?;
         Ok(())
     }
 
@@ -242,6 +257,101 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `contains` command is well-formed.
+    #[inline]
+    fn check_contains(
+        &mut self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        contains: &Contains<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `contains` is defined in the program.
+        if !stack.program().contains_mapping(contains.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", contains.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(contains.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, contains.key())?;
+        // Check that the key type in the mapping matches the key type in the instruction.
+        if *mapping_key_type != key_type {
+            bail!(
+                "Key type in `contains` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+            )
+        }
+        // Get the destination register.
+        let destination = contains.destination().clone();
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, PlaintextType::Literal(LiteralType::Boolean))?;
+        Ok(())
+    }
+
+    /// Ensures the given `rand.chacha` command is well-formed.
+    #[inline]
+    fn check_rand_chacha(&mut self, rand_chacha: &RandChaCha<N>) -> Result<()> {
+        // Get the destination register.
+        let destination = rand_chacha.destination().clone();
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, PlaintextType::Literal(rand_chacha.destination_type()))?;
+        Ok(())
+    }
+
+    /// Ensures the given `remove` command is well-formed.
+    #[inline]
+    fn check_remove(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, remove: &Remove<N>) -> Result<()> {
+        // Ensure the declared mapping in `remove` is defined in the program.
+        if !stack.program().contains_mapping(remove.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", remove.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(remove.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, remove.key())?;
+        // Check that the key type in the mapping matches the key type.
+        if *mapping_key_type != key_type {
+            bail!("Key type in `remove` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
+        }
+        Ok(())
+    }
+
+    /// Ensures the given `branch` command is well-formed.
+    #[inline]
+    fn check_branch<const VARIANT: u8>(
+        &self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        branch: &Branch<N, VARIANT>,
+    ) -> Result<()> {
+        // Ensure the branch has two operands.
+        ensure!(
+            branch.operands().len() == 2,
+            "Branch in '{}/{finalize_name}' must have two operands.",
+            stack.program_id()
+        );
+        // Retrieve the register type of the first operand.
+        let first_type = self.get_type_from_operand(stack, branch.first())?;
+        // Retrieve the register type of the second operand.
+        let second_type = self.get_type_from_operand(stack, branch.second())?;
+        // Check that the operand types match.
+        if first_type != second_type {
+            bail!(
+                "Command '{}' expects operands of the same type. Found operands of type '{first_type}' and '{second_type}'",
+                Branch::<N, VARIANT>::opcode()
+            )
+        }
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -295,7 +405,10 @@ impl<N: Network> FinalizeTypes<N> {
                 // Ensure the opcode **is** a reserved opcode.
                 ensure!(Program::<N>::is_reserved_opcode(opcode), "'{opcode}' is not an opcode.");
                 // Ensure the instruction is not the cast operation.
-                ensure!(!matches!(instruction, Instruction::Cast(..)), "Instruction '{instruction}' is a 'cast'.");
+                ensure!(
+                    !matches!(instruction, Instruction::Cast(..) | Instruction::CastLossy(..)),
+                    "Instruction '{instruction}' is a 'cast'."
+                );
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -323,11 +436,19 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
-            Opcode::Cast => {
-                // Retrieve the cast operation.
-                let operation = match instruction {
-                    Instruction::Cast(operation) => operation,
-                    _ => bail!("Instruction '{instruction}' is not a cast operation."),
+            Opcode::Cast(opcode) => {
+                // Retrieve the casted register type.
+                let register_type = match (opcode, instruction) {
+                    ("cast", Instruction::Cast(operation)) => operation.register_type(),
+                    ("cast.lossy", Instruction::CastLossy(operation)) => {
+                        // Ensure the lossy cast is into a literal type.
+                        ensure!(
+                            matches!(operation.register_type(), RegisterType::Plaintext(PlaintextType::Literal(..))),
+                            "Instruction '{instruction}' can only cast into a literal type."
+                        );
+                        operation.register_type()
+                    }
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 };
 
                 // Ensure the instruction has one destination register.
@@ -337,9 +458,13 @@ impl<N: Network> FinalizeTypes<N> {
                 );
 
                 // Ensure the casted register type is defined.
-                match operation.register_type() {
+                match register_type {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has exactly one operand.
+                        ensure!(
+                            instruction.operands().len() == 1,
+                            "Instruction '{instruction}' must have exactly one operand."
+                        );
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -351,6 +476,16 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the base element struct, if any, exists in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                            if !stack.program().contains_struct(struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the element type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Illegal operation: Cannot cast to a record.")
                     }
@@ -420,6 +555,8 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.keccak256",
+                    "hash.sha3_256",
                 ]
                 .contains(&opcode)
                 {
@@ -463,6 +600,14 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -484,6 +629,14 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{}'.",
+                    instruction.opcode()
+                );
+            }
         }
         Ok(())
     }
@@ -66,7 +66,7 @@ impl<N: Network> FinalizeTypes<N> {
                     let program_ref_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the program ID type matches the member type.
                     ensure!(
-                        program_ref_type == RegisterType::Plaintext(*member_type),
+                        program_ref_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{program_ref_type}' in the operand '{operand}'.",
                     )
                 }
@@ -76,7 +76,7 @@ impl<N: Network> FinalizeTypes<N> {
                     let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the caller type matches the member type.
                     ensure!(
-                        caller_type == RegisterType::Plaintext(*member_type),
+                        caller_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
@@ -84,4 +84,36 @@ impl<N: Network> FinalizeTypes<N> {
         }
         Ok(())
     }
+
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the operands length is at least the minimum required.
+        if operands.len() < N::MIN_ARRAY_ELEMENTS {
+            bail!("'{array_type}' must have at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
+        }
+        // Ensure the number of array elements does not exceed the maximum.
+        if operands.len() > N::MAX_ARRAY_ELEMENTS {
+            bail!("'{array_type}' cannot exceed {} elements", N::MAX_ARRAY_ELEMENTS)
+        }
+
+        // Ensure the number of array elements match.
+        let num_elements = operands.len();
+        let expected_num_elements = **array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Ensure the operand types match the element type.
+        let element_type = array_type.element_type();
+        for operand in operands.iter() {
+            // Retrieve the operand type.
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == *element_type,
+                "Array element expects {element_type}, but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
 }
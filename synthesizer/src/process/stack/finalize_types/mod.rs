@@ -27,7 +27,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, LiteralType, PlaintextType, Register, RegisterType, Struct},
+    program::{Access, ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, Struct},
 };
 
 use indexmap::IndexMap;
@@ -79,43 +79,57 @@ impl<N: Network> FinalizeTypes<N> {
         // Initialize a tracker for the type of the register.
         let mut plaintext_type = if self.is_input(register) {
             // Retrieve the input value type as a register type.
-            *self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+            self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?.clone()
         } else {
             // Retrieve the destination register type.
-            *self
-                .destinations
+            self.destinations
                 .get(&register.locator())
                 .ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+                .clone()
         };
 
         // Retrieve the member path if the register is a member. Otherwise, return the type.
         let path = match &register {
             // If the register is a locator, then output the register type.
             Register::Locator(..) => return Ok(plaintext_type),
-            // If the register is a member, then traverse the member path to output the register type.
-            Register::Member(_, path) => {
-                // Ensure the member path is valid.
-                ensure!(!path.is_empty(), "Register '{register}' references no members.");
+            // If the register is an access, then traverse the access path to output the register type.
+            Register::Access(_, path) => {
+                // Ensure the access path is valid.
+                ensure!(!path.is_empty(), "Register '{register}' references no accesses.");
                 // Output the member path.
                 path
             }
         };
 
-        // Traverse the member path to find the register type.
-        for path_name in path.iter() {
-            // Update the register type at each step.
-            plaintext_type = match &plaintext_type {
-                // Ensure the plaintext type is not a literal, as the register references a member.
-                PlaintextType::Literal(..) => bail!("'{register}' references a literal."),
+        // Traverse the access path to find the register type.
+        for access in path.iter() {
+            // Update the plaintext type at each step.
+            plaintext_type = match (&plaintext_type, access) {
+                // Ensure the plaintext type is not a literal, as the register references a member or an element.
+                (PlaintextType::Literal(..), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                PlaintextType::Struct(struct_name) => {
+                (PlaintextType::Struct(struct_name), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
-                        Some(plaintext_type) => *plaintext_type,
+                        Some(plaintext_type) => plaintext_type.clone(),
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
+                // Traverse the array to output the register type.
+                (PlaintextType::Array(array_type), Access::Index(index)) => {
+                    // Ensure the index is within the bounds of the array.
+                    match index < array_type.length() {
+                        // Update the element type.
+                        true => array_type.element_type().clone(),
+                        false => bail!("'{index}' is out of bounds for '{array_type}'"),
+                    }
+                }
+                // Ensure the access matches the plaintext type.
+                (PlaintextType::Struct(..), Access::Index(..)) => bail!("'{register}' cannot index into a struct."),
+                (PlaintextType::Array(..), Access::Member(..)) => {
+                    bail!("'{register}' cannot access a member of an array.")
+                }
             }
         }
         // Output the member type.
@@ -195,6 +195,8 @@ impl<N: Network> Stack<N> {
                 }
                 // If `plaintext` is a struct, this is a mismatch.
                 Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected literal, found struct"),
+                // If `plaintext` is an array, this is a mismatch.
+                Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected literal, found array"),
             },
             PlaintextType::Struct(struct_name) => {
                 // Ensure the struct name is valid.
@@ -215,6 +217,7 @@ impl<N: Network> Stack<N> {
                 let members = match plaintext {
                     Plaintext::Literal(..) => bail!("'{struct_name}' is invalid: expected struct, found literal"),
                     Plaintext::Struct(members, ..) => members,
+                    Plaintext::Array(..) => bail!("'{struct_name}' is invalid: expected struct, found array"),
                 };
 
                 // Ensure the number of struct members does not exceed the maximum.
@@ -247,6 +250,27 @@ impl<N: Network> Stack<N> {
                     self.matches_plaintext_internal(member, expected_type, depth + 1)?;
                 }
 
+                Ok(())
+            }
+            PlaintextType::Array(array_type) => {
+                // Retrieve the array elements.
+                let elements = match plaintext {
+                    Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected array, found literal"),
+                    Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected array, found struct"),
+                    Plaintext::Array(elements, ..) => elements,
+                };
+
+                // Ensure the number of elements matches the array length.
+                let num_elements = elements.len();
+                if num_elements != **array_type.length() as usize {
+                    bail!("'{plaintext_type}' expected {} elements, found {num_elements} elements", array_type.length())
+                }
+
+                // Ensure each element matches the element type (recursive call).
+                for element in elements.iter() {
+                    self.matches_plaintext_internal(element, array_type.element_type(), depth + 1)?;
+                }
+
                 Ok(())
             }
         }
@@ -26,6 +26,9 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod finalize_global_state;
+pub use finalize_global_state::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
@@ -68,7 +68,7 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(!matches!(input.value_type(), ValueType::Constant(..)), "Constant inputs are not supported (yet)");
 
             // Check the input register type.
-            register_types.check_input(stack, input.register(), &RegisterType::from(*input.value_type()))?;
+            register_types.check_input(stack, input.register(), &RegisterType::from(input.value_type().clone()))?;
         }
 
         // Step 2. Check the instructions are well-formed.
@@ -80,7 +80,7 @@ impl<N: Network> RegisterTypes<N> {
         // Step 3. Check the outputs are well-formed.
         for output in function.outputs() {
             // Check the output operand type.
-            register_types.check_output(stack, output.operand(), &RegisterType::from(*output.value_type()))?;
+            register_types.check_output(stack, output.operand(), &RegisterType::from(output.value_type().clone()))?;
         }
 
         // Step 4. If the function has a finalize command, check that its operands are all defined.
@@ -108,6 +108,13 @@ impl<N: Network> RegisterTypes<N> {
                             function.name()
                         );
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                        bail!(
+                            "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                            stack.program_id(),
+                            function.name()
+                        );
+                    }
                     RegisterType::Record(..) => {
                         bail!(
                             "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -152,7 +159,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -175,7 +182,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -193,6 +200,14 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the base element struct, if any, is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -208,7 +223,7 @@ impl<N: Network> RegisterTypes<N> {
         };
 
         // Insert the input register.
-        self.add_input(register.clone(), *register_type)?;
+        self.add_input(register.clone(), register_type.clone())?;
 
         // Ensure the register type and the input type match.
         if *register_type != self.get_type(stack, register)? {
@@ -242,6 +257,14 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the base element struct, if any, is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -315,7 +338,10 @@ impl<N: Network> RegisterTypes<N> {
                 // Ensure the opcode **is** a reserved opcode.
                 ensure!(Program::<N>::is_reserved_opcode(opcode), "'{opcode}' is not an opcode.");
                 // Ensure the instruction is not the cast operation.
-                ensure!(!matches!(instruction, Instruction::Cast(..)), "Instruction '{instruction}' is a 'cast'.");
+                ensure!(
+                    !matches!(instruction, Instruction::Cast(..) | Instruction::CastLossy(..)),
+                    "Instruction '{instruction}' is a 'cast'."
+                );
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -393,11 +419,19 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
             }
-            Opcode::Cast => {
-                // Retrieve the cast operation.
-                let operation = match instruction {
-                    Instruction::Cast(operation) => operation,
-                    _ => bail!("Instruction '{instruction}' is not a cast operation."),
+            Opcode::Cast(opcode) => {
+                // Retrieve the casted register type.
+                let register_type = match (opcode, instruction) {
+                    ("cast", Instruction::Cast(operation)) => operation.register_type(),
+                    ("cast.lossy", Instruction::CastLossy(operation)) => {
+                        // Ensure the lossy cast is into a literal type.
+                        ensure!(
+                            matches!(operation.register_type(), RegisterType::Plaintext(PlaintextType::Literal(..))),
+                            "Instruction '{instruction}' can only cast into a literal type."
+                        );
+                        operation.register_type()
+                    }
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 };
 
                 // Ensure the instruction has one destination register.
@@ -407,9 +441,13 @@ impl<N: Network> RegisterTypes<N> {
                 );
 
                 // Ensure the casted register type is defined.
-                match operation.register_type() {
+                match register_type {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has exactly one operand.
+                        ensure!(
+                            instruction.operands().len() == 1,
+                            "Instruction '{instruction}' must have exactly one operand."
+                        );
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -421,6 +459,16 @@ impl<N: Network> RegisterTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the base element struct, if any, exists in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                            if !stack.program().contains_struct(struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the element type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(record_name) => {
                         // Ensure the record type is defined in the program.
                         if !stack.program().contains_record(record_name) {
@@ -501,6 +549,8 @@ impl<N: Network> RegisterTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.keccak256",
+                    "hash.sha3_256",
                 ]
                 .contains(&opcode)
                 {
@@ -544,6 +594,14 @@ impl<N: Network> RegisterTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -565,6 +623,14 @@ impl<N: Network> RegisterTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{}'.",
+                    instruction.opcode()
+                );
+            }
         }
         Ok(())
     }
@@ -21,6 +21,8 @@ use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Progr
 use console::{
     network::prelude::*,
     program::{
+        Access,
+        ArrayType,
         EntryType,
         Identifier,
         LiteralType,
@@ -87,44 +89,53 @@ impl<N: Network> RegisterTypes<N> {
         // Initialize a tracker for the register type.
         let mut register_type = if self.is_input(register) {
             // Retrieve the input value type as a register type.
-            *self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+            self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?.clone()
         } else {
             // Retrieve the destination register type.
-            *self
-                .destinations
+            self.destinations
                 .get(&register.locator())
                 .ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+                .clone()
         };
 
         // Retrieve the member path if the register is a member. Otherwise, return the register type.
         let path = match &register {
             // If the register is a locator, then output the register type.
             Register::Locator(..) => return Ok(register_type),
-            // If the register is a member, then traverse the member path to output the register type.
-            Register::Member(_, path) => {
-                // Ensure the member path is valid.
-                ensure!(!path.is_empty(), "Register '{register}' references no members.");
+            // If the register is an access, then traverse the access path to output the register type.
+            Register::Access(_, path) => {
+                // Ensure the access path is valid.
+                ensure!(!path.is_empty(), "Register '{register}' references no accesses.");
                 // Output the member path.
                 path
             }
         };
 
-        // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        // Traverse the access path to find the register type.
+        for access in path.iter() {
             // Update the register type at each step.
-            register_type = match &register_type {
-                // Ensure the plaintext type is not a literal, as the register references a member.
-                RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+            register_type = match (&register_type, access) {
+                // Ensure the plaintext type is not a literal, as the register references a member or an element.
+                (RegisterType::Plaintext(PlaintextType::Literal(..)), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                (RegisterType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
-                        Some(plaintext_type) => RegisterType::Plaintext(*plaintext_type),
+                        Some(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
-                RegisterType::Record(record_name) => {
+                // Traverse the array to output the register type.
+                (RegisterType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
+                    // Ensure the index is within the bounds of the array.
+                    match index < array_type.length() {
+                        // Update the element type.
+                        true => RegisterType::Plaintext(array_type.element_type().clone()),
+                        false => bail!("'{index}' is out of bounds for '{array_type}'"),
+                    }
+                }
+                (RegisterType::Record(record_name), Access::Member(path_name)) => {
                     // Ensure the record type exists.
                     ensure!(stack.program().contains_record(record_name), "Record '{record_name}' does not exist");
                     // Retrieve the member type from the record.
@@ -138,13 +149,13 @@ impl<N: Network> RegisterTypes<N> {
                             Some(entry_type) => match entry_type {
                                 EntryType::Constant(plaintext_type)
                                 | EntryType::Public(plaintext_type)
-                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(*plaintext_type),
+                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
                             },
                             None => bail!("'{path_name}' does not exist in record '{record_name}'"),
                         }
                     }
                 }
-                RegisterType::ExternalRecord(locator) => {
+                (RegisterType::ExternalRecord(locator), Access::Member(path_name)) => {
                     // Ensure the external record type exists.
                     ensure!(stack.contains_external_record(locator), "External record '{locator}' does not exist");
                     // Retrieve the member type from the external record.
@@ -158,12 +169,21 @@ impl<N: Network> RegisterTypes<N> {
                             Some(entry_type) => match entry_type {
                                 EntryType::Constant(plaintext_type)
                                 | EntryType::Public(plaintext_type)
-                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(*plaintext_type),
+                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
                             },
                             None => bail!("'{path_name}' does not exist in external record '{locator}'"),
                         }
                     }
                 }
+                // Ensure the access matches the register type.
+                (RegisterType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                | (RegisterType::Record(..), Access::Index(..))
+                | (RegisterType::ExternalRecord(..), Access::Index(..)) => {
+                    bail!("'{register}' cannot index into a struct or record.")
+                }
+                (RegisterType::Plaintext(PlaintextType::Array(..)), Access::Member(..)) => {
+                    bail!("'{register}' cannot access a member of an array.")
+                }
             }
         }
         // Output the member type.
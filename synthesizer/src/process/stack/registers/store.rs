@@ -20,7 +20,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Store<N> for Registers<N, A> {
     /// Assigns the given value to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
-    /// This method will halt if the given register is a register member.
+    /// This method will halt if the given register is a register access.
     /// This method will halt if the given register is an input register.
     /// This method will halt if the register is already used.
     #[inline]
@@ -52,8 +52,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Store<N> for Registers<N, A> {
                     None => Ok(()),
                 }
             }
-            // Ensure the register is not a register member.
-            Register::Member(..) => bail!("Cannot store to a register member: '{register}'"),
+            // Ensure the register is not a register access.
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
 }
@@ -62,7 +62,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> StoreCircuit<N, A> for Registers
     /// Assigns the given value to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
-    /// This method will halt if the given register is a register member.
+    /// This method will halt if the given register is a register access.
     /// This method will halt if the given register is an input register.
     /// This method will halt if the register is already used.
     #[inline]
@@ -101,8 +101,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> StoreCircuit<N, A> for Registers
                     None => Ok(()),
                 }
             }
-            // Ensure the register is not a register member.
-            Register::Member(..) => bail!("Cannot store to a register member: '{register}'"),
+            // Ensure the register is not a register access.
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
 }
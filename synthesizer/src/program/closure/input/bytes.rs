@@ -22,10 +22,10 @@ impl<N: Network> FromBytes for Input<N> {
         let register = FromBytes::read_le(&mut reader)?;
         let register_type = FromBytes::read_le(&mut reader)?;
 
-        // Ensure the register is not a register member.
+        // Ensure the register is not a register access.
         match matches!(register, Register::Locator(..)) {
             true => Ok(Self { register, register_type }),
-            false => Err(error(format!("Input '{register}' cannot be a register member"))),
+            false => Err(error(format!("Input '{register}' cannot be a register access"))),
         }
     }
 }
@@ -33,9 +33,9 @@ impl<N: Network> FromBytes for Input<N> {
 impl<N: Network> ToBytes for Input<N> {
     /// Writes the input to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Ensure the register is not a register member.
+        // Ensure the register is not a register access.
         if !matches!(self.register, Register::Locator(..)) {
-            return Err(error(format!("Input '{}' cannot be a register member", self.register)));
+            return Err(error(format!("Input '{}' cannot be a register access", self.register)));
         }
         self.register.write_le(&mut writer)?;
         self.register_type.write_le(&mut writer)
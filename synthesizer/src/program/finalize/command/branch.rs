@@ -0,0 +1,233 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Load, Opcode, Operand, Stack};
+use console::{network::prelude::*, program::Identifier};
+
+/// Jumps to `position`, if `first` equals `second`.
+pub type BranchEq<N> = Branch<N, { Variant::BranchEq as u8 }>;
+/// Jumps to `position`, if `first` does **not** equal `second`.
+pub type BranchNeq<N> = Branch<N, { Variant::BranchNeq as u8 }>;
+
+enum Variant {
+    BranchEq,
+    BranchNeq,
+}
+
+/// Compares `first` and `second` and jumps to `position`, if the condition is met.
+/// Note: Branches may only jump forward to a `position` command in the same finalize scope.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Branch<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The position to jump to.
+    position: Identifier<N>,
+}
+
+impl<N: Network, const VARIANT: u8> Branch<N, VARIANT> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Command("branch.eq"),
+            1 => Opcode::Command("branch.neq"),
+            _ => panic!("Invalid 'branch' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the command.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Command '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the first operand.
+    #[inline]
+    pub fn first(&self) -> &Operand<N> {
+        &self.operands[0]
+    }
+
+    /// Returns the second operand.
+    #[inline]
+    pub fn second(&self) -> &Operand<N> {
+        &self.operands[1]
+    }
+
+    /// Returns the position to jump to.
+    #[inline]
+    pub const fn position(&self) -> &Identifier<N> {
+        &self.position
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Branch<N, VARIANT> {
+    /// Evaluates the branch condition, returning `true` if the branch should be taken.
+    #[inline]
+    pub fn is_taken(&self, stack: &Stack<N>, registers: &impl Load<N>) -> Result<bool> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Command '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let input_a = registers.load(stack, &self.operands[0])?;
+        let input_b = registers.load(stack, &self.operands[1])?;
+
+        // Check the inputs.
+        match VARIANT {
+            0 => Ok(input_a == input_b),
+            1 => Ok(input_a != input_b),
+            _ => bail!("Invalid 'branch' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for Branch<N, VARIANT> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the "to" keyword from the string.
+        let (string, _) = tag("to")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the position from the string.
+        let (string, position) = Identifier::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { operands: vec![first, second], position }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for Branch<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for Branch<N, VARIANT> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for Branch<N, VARIANT> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the operands.
+        write!(f, "{} {} ", self.operands[0], self.operands[1])?;
+        // Print the position.
+        write!(f, "to {};", self.position)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for Branch<N, VARIANT> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the first operand.
+        let first = Operand::read_le(&mut reader)?;
+        // Read the second operand.
+        let second = Operand::read_le(&mut reader)?;
+        // Read the position.
+        let position = Identifier::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { operands: vec![first, second], position })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for Branch<N, VARIANT> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the position.
+        self.position.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, branch) = BranchEq::<CurrentNetwork>::parse("branch.eq r0 r1 to exit;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(branch.operands().len(), 2, "The number of operands is incorrect");
+        assert_eq!(branch.first(), &Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(branch.second(), &Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(branch.position(), &Identifier::from_str("exit").unwrap(), "The position is incorrect");
+
+        let (string, branch) = BranchNeq::<CurrentNetwork>::parse("branch.neq r3 1u8 to end;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(branch.operands().len(), 2, "The number of operands is incorrect");
+        assert_eq!(branch.first(), &Operand::Register(Register::Locator(3)), "The first operand is incorrect");
+        assert_eq!(branch.second(), &Operand::from_str("1u8").unwrap(), "The second operand is incorrect");
+        assert_eq!(branch.position(), &Identifier::from_str("end").unwrap(), "The position is incorrect");
+
+        // Ensure the variants do not parse each other.
+        assert!(BranchEq::<CurrentNetwork>::parse("branch.neq r0 r1 to exit;").is_err());
+        assert!(BranchNeq::<CurrentNetwork>::parse("branch.eq r0 r1 to exit;").is_err());
+    }
+}
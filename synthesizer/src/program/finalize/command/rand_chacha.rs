@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FinalizeRegisters, Opcode, Stack, Store};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, Register, Value},
+    types::{U16, U32, U64},
+};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// A random command, e.g. `rand.chacha into r0 as field;`.
+/// Samples a pseudorandom literal of `destination_type` and stores the result in `destination`.
+///
+/// The output is deterministic, not random: it is seeded by the network ID, the height and previous
+/// block hash of the block being finalized, the transition ID, and the number of `rand.chacha` commands
+/// previously finalized in the transition. All of these are known to the submitter of the transaction,
+/// who can compute the output before the transaction is included in a block. As such, the output must
+/// not be relied upon where an unpredictable value is required, e.g. to select a winner.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RandChaCha<N: Network> {
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination register type.
+    destination_type: LiteralType,
+}
+
+impl<N: Network> RandChaCha<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("rand.chacha")
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> LiteralType {
+        self.destination_type
+    }
+}
+
+impl<N: Network> RandChaCha<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        // Retrieve the nonce, and increment it for the next command.
+        let nonce = registers.increment_nonce()?;
+
+        // Construct the preimage as `(network_id, block_height, previous_block_hash, transition_id, nonce)`.
+        let preimage = (
+            U16::<N>::new(N::ID),
+            U32::<N>::new(registers.state().block_height()),
+            *registers.state().previous_block_hash(),
+            **registers.transition_id(),
+            U64::<N>::new(nonce),
+        )
+            .to_bits_le();
+        // Compute the seed as `Hash(preimage)`.
+        let seed = N::hash_bhp1024(&preimage)?.to_bytes_le()?;
+
+        // Initialize the RNG from the seed.
+        let mut rng = ChaCha20Rng::from_seed(seed.try_into().map_err(|_| anyhow!("Invalid seed for 'rand.chacha'"))?);
+        // Sample a random literal of the destination type.
+        let output = Literal::sample(self.destination_type, &mut rng);
+
+        // Assign the output to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+}
+
+impl<N: Network> Parser for RandChaCha<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" keyword from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = LiteralType::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { destination, destination_type }))
+    }
+}
+
+impl<N: Network> FromStr for RandChaCha<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for RandChaCha<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for RandChaCha<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the destination register and type.
+        write!(f, "into {} as {};", self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network> FromBytes for RandChaCha<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = LiteralType::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { destination, destination_type })
+    }
+}
+
+impl<N: Network> ToBytes for RandChaCha<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, rand) = RandChaCha::<CurrentNetwork>::parse("rand.chacha into r0 as field;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(rand.destination, Register::Locator(0), "The destination register is incorrect");
+        assert_eq!(rand.destination_type, LiteralType::Field, "The destination type is incorrect");
+        assert_eq!(rand.to_string(), "rand.chacha into r0 as field;");
+
+        let (string, rand) = RandChaCha::<CurrentNetwork>::parse("rand.chacha into r3 as u8;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(rand.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(rand.destination_type, LiteralType::U8, "The destination type is incorrect");
+
+        assert!(RandChaCha::<CurrentNetwork>::parse("rand.chacha r0 into r1 as field;").is_err());
+        assert!(RandChaCha::<CurrentNetwork>::parse("rand.chacha into r0;").is_err());
+    }
+}
@@ -21,7 +21,7 @@ impl<N: Network> Parser for Input<N> {
     /// The input statement is of the form `input {register} as {value_type};`.
     ///
     /// # Errors
-    /// This function will halt if the given register is a register member.
+    /// This function will halt if the given register is a register access.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
@@ -32,10 +32,10 @@ impl<N: Network> Parser for Input<N> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the register from the string.
         let (string, register) = map_res(Register::parse, |register| {
-            // Ensure the register is not a register member.
+            // Ensure the register is not a register access.
             match &register {
                 Register::Locator(..) => Ok(register),
-                Register::Member(..) => Err(error(format!("Input register {register} cannot be a register member"))),
+                Register::Access(..) => Err(error(format!("Input register {register} cannot be a register access"))),
             }
         })(string)?;
         // Parse the whitespace from the string.
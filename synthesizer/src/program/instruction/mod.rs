@@ -77,6 +77,8 @@ pub enum Instruction<N: Network> {
     Call(Call<N>),
     /// Casts the operands into the declared type.
     Cast(Cast<N>),
+    /// Casts the operand into the declared literal type, with lossy truncation.
+    CastLossy(CastLossy<N>),
     /// Performs a BHP commitment on inputs of 256-bit chunks.
     CommitBHP256(CommitBHP256<N>),
     /// Performs a BHP commitment on inputs of 512-bit chunks.
@@ -117,6 +119,10 @@ pub enum Instruction<N: Network> {
     HashPSD4(HashPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashPSD8(HashPSD8<N>),
+    /// Performs a Keccak hash with a 256-bit output.
+    HashKeccak256(HashKeccak256<N>),
+    /// Performs a SHA-3 hash with a 256-bit output.
+    HashSha3_256(HashSha3_256<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -159,6 +165,8 @@ pub enum Instruction<N: Network> {
     Shr(Shr<N>),
     /// Shifts `first` right by `second` bits, continuing past the boundary of the type, storing the outcome in `destination`.
     ShrWrapped(ShrWrapped<N>),
+    /// Computes whether `signature` is valid for the given `address` and `message`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
     /// Squares 'first', storing the outcome in `destination`.
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
@@ -211,6 +219,7 @@ macro_rules! instruction {
             AssertNeq,
             Call,
             Cast,
+            CastLossy,
             CommitBHP256,
             CommitBHP512,
             CommitBHP768,
@@ -231,6 +240,8 @@ macro_rules! instruction {
             HashPSD2,
             HashPSD4,
             HashPSD8,
+            HashKeccak256,
+            HashSha3_256,
             Inv,
             IsEq,
             IsNeq,
@@ -252,6 +263,7 @@ macro_rules! instruction {
             ShlWrapped,
             Shr,
             ShrWrapped,
+            SignVerify,
             Square,
             SquareRoot,
             Sub,
@@ -410,7 +422,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            60,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -36,9 +36,48 @@ use console::{
 
 use indexmap::IndexMap;
 
+/// Casts the operands into the declared type, ensuring the value of a literal is preserved.
+pub type Cast<N> = CastOperation<N, { CastVariant::Cast as u8 }>;
+/// Casts the operand into the declared literal type, with lossy truncation.
+pub type CastLossy<N> = CastOperation<N, { CastVariant::CastLossy as u8 }>;
+
+/// The variant of the cast operation.
+enum CastVariant {
+    Cast,
+    CastLossy,
+}
+
+/// A literal that can be cast into a literal type, in either the console or the circuit.
+trait CastLiteral: Sized {
+    /// Casts the literal into the given literal type, ensuring the value is preserved.
+    fn cast(&self, literal_type: LiteralType) -> Result<Self>;
+    /// Casts the literal into the given literal type, with lossy truncation.
+    fn cast_lossy(&self, literal_type: LiteralType) -> Result<Self>;
+}
+
+impl<N: Network> CastLiteral for Literal<N> {
+    fn cast(&self, literal_type: LiteralType) -> Result<Self> {
+        Literal::cast(self, literal_type)
+    }
+
+    fn cast_lossy(&self, literal_type: LiteralType) -> Result<Self> {
+        Literal::cast_lossy(self, literal_type)
+    }
+}
+
+impl<A: circuit::Aleo> CastLiteral for circuit::Literal<A> {
+    fn cast(&self, literal_type: LiteralType) -> Result<Self> {
+        circuit::Literal::cast(self, literal_type)
+    }
+
+    fn cast_lossy(&self, literal_type: LiteralType) -> Result<Self> {
+        circuit::Literal::cast_lossy(self, literal_type)
+    }
+}
+
 /// Casts the operands into the declared type.
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct Cast<N: Network> {
+pub struct CastOperation<N: Network, const VARIANT: u8> {
     /// The operands.
     operands: Vec<Operand<N>>,
     /// The destination register.
@@ -47,11 +86,15 @@ pub struct Cast<N: Network> {
     register_type: RegisterType<N>,
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
-        Opcode::Cast
+        match VARIANT {
+            0 => Opcode::Cast("cast"),
+            1 => Opcode::Cast("cast.lossy"),
+            _ => panic!("Invalid 'cast' instruction opcode"),
+        }
     }
 
     /// Returns the operands in the operation.
@@ -71,9 +114,31 @@ impl<N: Network> Cast<N> {
     pub const fn register_type(&self) -> &RegisterType<N> {
         &self.register_type
     }
+
+    /// Ensures the register type is a literal type, if this is a lossy cast.
+    #[inline]
+    fn check_lossy_register_type(&self) -> Result<()> {
+        match VARIANT == CastVariant::CastLossy as u8 {
+            true => match &self.register_type {
+                RegisterType::Plaintext(PlaintextType::Literal(..)) => Ok(()),
+                _ => bail!("Instruction '{}' can only cast into a literal type", Self::opcode()),
+            },
+            false => Ok(()),
+        }
+    }
+
+    /// Casts the given literal into the given literal type.
+    #[inline]
+    fn cast_literal<L: CastLiteral>(literal: &L, literal_type: LiteralType) -> Result<L> {
+        match VARIANT {
+            0 => CastLiteral::cast(literal, literal_type),
+            1 => CastLiteral::cast_lossy(literal, literal_type),
+            _ => bail!("Invalid 'cast' variant: {VARIANT}"),
+        }
+    }
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(
@@ -84,8 +149,21 @@ impl<N: Network> Cast<N> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+        // Ensure a lossy cast is into a literal type.
+        self.check_lossy_register_type()?;
+
+        match &self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly 1 operand");
+                // Cast the literal into the literal type.
+                let output = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Literal(literal, ..)) => Self::cast_literal(literal, *literal_type)?,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+                // Store the output.
+                registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_STRUCT_ENTRIES {
@@ -109,7 +187,7 @@ impl<N: Network> Cast<N> {
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         Value::Plaintext(plaintext) => {
@@ -130,6 +208,47 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the operands length is at least the minimum.
+                if inputs.len() < N::MIN_ARRAY_ELEMENTS {
+                    bail!("Casting to an array requires at least {} operand", N::MIN_ARRAY_ELEMENTS)
+                }
+
+                // Ensure that the number of operands is equal to the number of array elements.
+                if inputs.len() != **array_type.length() as usize {
+                    bail!(
+                        "Casting to the array {array_type} requires {} operands, but {} were provided",
+                        array_type.length(),
+                        inputs.len()
+                    )
+                }
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -167,7 +286,7 @@ impl<N: Network> Cast<N> {
                     inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
                 {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         Value::Plaintext(plaintext) => {
@@ -218,8 +337,27 @@ impl<N: Network> Cast<N> {
         let inputs: Vec<_> =
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
-        match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+        // Ensure a lossy cast is into a literal type.
+        self.check_lossy_register_type()?;
+
+        match &self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly 1 operand");
+                // Cast the literal into the literal type.
+                let output = match &inputs[0] {
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => {
+                        Self::cast_literal(literal, *literal_type)?
+                    }
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+                // Store the output.
+                registers.store_circuit(
+                    stack,
+                    &self.destination,
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default())),
+                )
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_STRUCT_ENTRIES {
@@ -243,7 +381,7 @@ impl<N: Network> Cast<N> {
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         circuit::Value::Plaintext(plaintext) => {
@@ -269,6 +407,52 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the operands length is at least the minimum.
+                if inputs.len() < N::MIN_ARRAY_ELEMENTS {
+                    bail!("Casting to an array requires at least {} operand", N::MIN_ARRAY_ELEMENTS)
+                }
+
+                // Ensure that the number of operands is equal to the number of array elements.
+                if inputs.len() != **array_type.length() as usize {
+                    bail!(
+                        "Casting to the array {array_type} requires {} operands, but {} were provided",
+                        array_type.length(),
+                        inputs.len()
+                    )
+                }
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(
+                                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                                &register_type,
+                            )?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        circuit::Value::Record(..) => {
+                            bail!("Casting a record into an array element is illegal")
+                        }
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -309,7 +493,7 @@ impl<N: Network> Cast<N> {
                     inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
                 {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         circuit::Value::Plaintext(plaintext) => {
@@ -358,8 +542,21 @@ impl<N: Network> Cast<N> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+        // Ensure a lossy cast is into a literal type.
+        self.check_lossy_register_type()?;
+
+        match &self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly 1 operand");
+                // Cast the literal into the literal type.
+                let output = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Literal(literal, ..)) => Self::cast_literal(literal, *literal_type)?,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+                // Store the output.
+                registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_STRUCT_ENTRIES {
@@ -383,7 +580,7 @@ impl<N: Network> Cast<N> {
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         Value::Plaintext(plaintext) => {
@@ -404,6 +601,47 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the operands length is at least the minimum.
+                if inputs.len() < N::MIN_ARRAY_ELEMENTS {
+                    bail!("Casting to an array requires at least {} operand", N::MIN_ARRAY_ELEMENTS)
+                }
+
+                // Ensure that the number of operands is equal to the number of array elements.
+                if inputs.len() != **array_type.length() as usize {
+                    bail!(
+                        "Casting to the array {array_type} requires {} operands, but {} were provided",
+                        array_type.length(),
+                        inputs.len()
+                    )
+                }
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -425,9 +663,28 @@ impl<N: Network> Cast<N> {
             self.operands.len(),
         );
 
+        // Ensure a lossy cast is into a literal type.
+        self.check_lossy_register_type()?;
+
         // Ensure the output type is defined in the program.
-        match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+        match &self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one input type.
+                ensure!(
+                    input_types.len() == 1,
+                    "Casting to a literal requires exactly 1 operand, but {} were provided",
+                    input_types.len()
+                );
+                // Ensure the input type is a literal type that can be cast into the literal type.
+                match &input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Literal(input_type)) => ensure!(
+                        (*input_type == LiteralType::String) == (*literal_type == LiteralType::String)
+                            && (*input_type == LiteralType::Signature) == (*literal_type == LiteralType::Signature),
+                        "Cannot cast a '{input_type}' into a '{literal_type}'"
+                    ),
+                    input_type => bail!("Casting to a literal requires a literal operand, found '{input_type}'"),
+                }
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
@@ -466,6 +723,42 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure that the input types length is at least the minimum.
+                ensure!(
+                    input_types.len() >= N::MIN_ARRAY_ELEMENTS,
+                    "Casting to an array requires at least {} operand",
+                    N::MIN_ARRAY_ELEMENTS
+                );
+                // Ensure that the number of input types is equal to the number of array elements.
+                ensure!(
+                    input_types.len() == **array_type.length() as usize,
+                    "Casting to the array {array_type} requires {} operands, but {} were provided",
+                    array_type.length(),
+                    input_types.len()
+                );
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    match input_type {
+                        // Ensure the plaintext type matches the element type.
+                        RegisterType::Plaintext(plaintext_type) => ensure!(
+                            array_type.element_type() == plaintext_type,
+                            "Array '{array_type}' element type mismatch: expected '{}', found '{plaintext_type}'",
+                            array_type.element_type()
+                        ),
+                        // Ensure the input type cannot be a record (this is unsupported behavior).
+                        RegisterType::Record(record_name) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{}', found record '{record_name}'",
+                            array_type.element_type()
+                        ),
+                        // Ensure the input type cannot be an external record (this is unsupported behavior).
+                        RegisterType::ExternalRecord(locator) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{}', found external record '{locator}'",
+                            array_type.element_type()
+                        ),
+                    }
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(&record_name)?;
@@ -522,11 +815,11 @@ impl<N: Network> Cast<N> {
             }
         }
 
-        Ok(vec![self.register_type])
+        Ok(vec![self.register_type.clone()])
     }
 }
 
-impl<N: Network> Parser for Cast<N> {
+impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
@@ -574,7 +867,7 @@ impl<N: Network> Parser for Cast<N> {
     }
 }
 
-impl<N: Network> FromStr for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromStr for CastOperation<N, VARIANT> {
     type Err = Error;
 
     /// Parses a string into an operation.
@@ -592,14 +885,14 @@ impl<N: Network> FromStr for Cast<N> {
     }
 }
 
-impl<N: Network> Debug for Cast<N> {
+impl<N: Network, const VARIANT: u8> Debug for CastOperation<N, VARIANT> {
     /// Prints the operation as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
     }
 }
 
-impl<N: Network> Display for Cast<N> {
+impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
     /// Prints the operation to a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Ensure the number of operands is within the bounds.
@@ -618,7 +911,7 @@ impl<N: Network> Display for Cast<N> {
     }
 }
 
-impl<N: Network> FromBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the number of operands.
@@ -658,7 +951,7 @@ impl<N: Network> FromBytes for Cast<N> {
     }
 }
 
-impl<N: Network> ToBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
     /// Writes the operation to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Ensure the number of operands is within the bounds.
@@ -684,7 +977,10 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{network::Testnet3, program::Identifier};
+    use console::{
+        network::Testnet3,
+        program::{Access, Identifier},
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -696,12 +992,12 @@ mod tests {
         assert_eq!(cast.operands.len(), 2, "The number of operands is incorrect");
         assert_eq!(
             cast.operands[0],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("owner").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("owner").unwrap())])),
             "The first operand is incorrect"
         );
         assert_eq!(
             cast.operands[1],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("token_amount").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("token_amount").unwrap())])),
             "The second operand is incorrect"
         );
         assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
@@ -712,6 +1008,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_literal() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)),
+            "The value type is incorrect"
+        );
+
+        let (string, cast) = CastLossy::<CurrentNetwork>::parse("cast.lossy r0 into r1 as field").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast.lossy r0 into r1 as field");
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(Cast::<CurrentNetwork>::from_str("cast.lossy r0 into r1 as u8").is_err());
+        assert!(CastLossy::<CurrentNetwork>::from_str("cast r0 into r1 as u8").is_err());
+    }
+
+    #[test]
+    fn test_cast_lossy_into_struct_fails() {
+        let cast = CastLossy::<CurrentNetwork>::from_str("cast.lossy r0 into r1 as foo").unwrap();
+        assert!(cast.check_lossy_register_type().is_err());
+        let cast = CastLossy::<CurrentNetwork>::from_str("cast.lossy r0 into r1 as u8").unwrap();
+        assert!(cast.check_lossy_register_type().is_ok());
+        let cast = Cast::<CurrentNetwork>::from_str("cast r0 into r1 as foo").unwrap();
+        assert!(cast.check_lossy_register_type().is_ok());
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
@@ -50,6 +50,8 @@ pub struct CommitInstruction<N: Network, const VARIANT: u8> {
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination register type.
+    destination_type: LiteralType,
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
@@ -81,6 +83,12 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> LiteralType {
+        self.destination_type
+    }
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
@@ -102,15 +110,33 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         };
 
         // Commit the input.
-        let output = match VARIANT {
-            0 => Literal::Field(N::commit_bhp256(&input.to_bits_le(), &randomizer)?),
-            1 => Literal::Field(N::commit_bhp512(&input.to_bits_le(), &randomizer)?),
-            2 => Literal::Field(N::commit_bhp768(&input.to_bits_le(), &randomizer)?),
-            3 => Literal::Field(N::commit_bhp1024(&input.to_bits_le(), &randomizer)?),
-            4 => Literal::Group(N::commit_ped64(&input.to_bits_le(), &randomizer)?),
-            5 => Literal::Group(N::commit_ped128(&input.to_bits_le(), &randomizer)?),
+        // Note: A Pedersen commitment is cast into the destination type by its x-coordinate.
+        let output = match (VARIANT, self.destination_type) {
+            (_, LiteralType::Boolean | LiteralType::String | LiteralType::Signature) => {
+                bail!("Instruction '{}' cannot commit into a '{}'", Self::opcode(), self.destination_type)
+            }
+            (0, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::commit_to_group_bhp256(&input.to_bits_le(), &randomizer)?)
+            }
+            (1, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::commit_to_group_bhp512(&input.to_bits_le(), &randomizer)?)
+            }
+            (2, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::commit_to_group_bhp768(&input.to_bits_le(), &randomizer)?)
+            }
+            (3, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::commit_to_group_bhp1024(&input.to_bits_le(), &randomizer)?)
+            }
+            (0, _) => Literal::Field(N::commit_bhp256(&input.to_bits_le(), &randomizer)?),
+            (1, _) => Literal::Field(N::commit_bhp512(&input.to_bits_le(), &randomizer)?),
+            (2, _) => Literal::Field(N::commit_bhp768(&input.to_bits_le(), &randomizer)?),
+            (3, _) => Literal::Field(N::commit_bhp1024(&input.to_bits_le(), &randomizer)?),
+            (4, _) => Literal::Group(N::commit_ped64(&input.to_bits_le(), &randomizer)?),
+            (5, _) => Literal::Group(N::commit_ped128(&input.to_bits_le(), &randomizer)?),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         };
+        // Cast the output into the destination type.
+        let output = output.cast_lossy(self.destination_type)?;
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
@@ -141,15 +167,33 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         };
 
         // Commits the input.
-        let output = match VARIANT {
-            0 => circuit::Literal::Field(A::commit_bhp256(&input.to_bits_le(), &randomizer)),
-            1 => circuit::Literal::Field(A::commit_bhp512(&input.to_bits_le(), &randomizer)),
-            2 => circuit::Literal::Field(A::commit_bhp768(&input.to_bits_le(), &randomizer)),
-            3 => circuit::Literal::Field(A::commit_bhp1024(&input.to_bits_le(), &randomizer)),
-            4 => circuit::Literal::Group(A::commit_ped64(&input.to_bits_le(), &randomizer)),
-            5 => circuit::Literal::Group(A::commit_ped128(&input.to_bits_le(), &randomizer)),
+        // Note: A Pedersen commitment is cast into the destination type by its x-coordinate.
+        let output = match (VARIANT, self.destination_type) {
+            (_, LiteralType::Boolean | LiteralType::String | LiteralType::Signature) => {
+                bail!("Instruction '{}' cannot commit into a '{}'", Self::opcode(), self.destination_type)
+            }
+            (0, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::commit_to_group_bhp256(&input.to_bits_le(), &randomizer))
+            }
+            (1, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::commit_to_group_bhp512(&input.to_bits_le(), &randomizer))
+            }
+            (2, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::commit_to_group_bhp768(&input.to_bits_le(), &randomizer))
+            }
+            (3, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::commit_to_group_bhp1024(&input.to_bits_le(), &randomizer))
+            }
+            (0, _) => circuit::Literal::Field(A::commit_bhp256(&input.to_bits_le(), &randomizer)),
+            (1, _) => circuit::Literal::Field(A::commit_bhp512(&input.to_bits_le(), &randomizer)),
+            (2, _) => circuit::Literal::Field(A::commit_bhp768(&input.to_bits_le(), &randomizer)),
+            (3, _) => circuit::Literal::Field(A::commit_bhp1024(&input.to_bits_le(), &randomizer)),
+            (4, _) => circuit::Literal::Group(A::commit_ped64(&input.to_bits_le(), &randomizer)),
+            (5, _) => circuit::Literal::Group(A::commit_ped128(&input.to_bits_le(), &randomizer)),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         };
+        // Cast the output into the destination type.
+        let output = output.cast_lossy(self.destination_type)?;
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
@@ -176,9 +220,16 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
+        // Ensure the destination type is supported.
+        match self.destination_type {
+            LiteralType::Boolean | LiteralType::String | LiteralType::Signature => {
+                bail!("Instruction '{}' cannot commit into a '{}'", Self::opcode(), self.destination_type)
+            }
+            _ => (),
+        }
+
         match VARIANT {
-            0 | 1 | 2 | 3 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))]),
-            4 | 5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group))]),
+            0 | 1 | 2 | 3 | 4 | 5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))]),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         }
     }
@@ -206,8 +257,16 @@ impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = LiteralType::parse(string)?;
 
-        Ok((string, Self { operands: vec![first, second], destination }))
+        Ok((string, Self { operands: vec![first, second], destination, destination_type }))
     }
 }
 
@@ -247,7 +306,7 @@ impl<N: Network, const VARIANT: u8> Display for CommitInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {} as {}", self.destination, self.destination_type)
     }
 }
 
@@ -262,9 +321,11 @@ impl<N: Network, const VARIANT: u8> FromBytes for CommitInstruction<N, VARIANT>
         }
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = LiteralType::read_le(&mut reader)?;
 
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destination, destination_type })
     }
 }
 
@@ -278,7 +339,9 @@ impl<N: Network, const VARIANT: u8> ToBytes for CommitInstruction<N, VARIANT> {
         // Write the operands.
         self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
         // Write the destination register.
-        self.destination.write_le(&mut writer)
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
     }
 }
 
@@ -291,11 +354,17 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let (string, commit) = CommitBHP512::<CurrentNetwork>::parse("commit.bhp512 r0 r1 into r2").unwrap();
+        let (string, commit) = CommitBHP512::<CurrentNetwork>::parse("commit.bhp512 r0 r1 into r2 as field").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
         assert_eq!(commit.operands.len(), 2, "The number of operands is incorrect");
         assert_eq!(commit.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(commit.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
         assert_eq!(commit.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(commit.destination_type, LiteralType::Field, "The destination type is incorrect");
+
+        let (string, commit) = CommitPED64::<CurrentNetwork>::parse("commit.ped64 r0 r1 into r2 as address").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.destination_type, LiteralType::Address, "The destination type is incorrect");
+        assert_eq!(commit.to_string(), "commit.ped64 r0 r1 into r2 as address");
     }
 }
@@ -17,7 +17,8 @@
 use crate::{Load, LoadCircuit, Opcode, Operand, Stack, Store, StoreCircuit};
 use console::{
     network::prelude::*,
-    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{U32, U8},
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -41,6 +42,13 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// Keccak256 is the cryptographic hash function used in Ethereum, with a 256-bit output.
+/// The raw little-endian bytes of the input are hashed into a `[u8; 32u32]` digest.
+pub type HashKeccak256<N> = HashInstruction<N, { Hasher::Keccak256 as u8 }>;
+/// SHA3-256 is the cryptographic hash function standardized in FIPS 202, with a 256-bit output.
+/// The raw little-endian bytes of the input are hashed into a `[u8; 32u32]` digest.
+pub type HashSha3_256<N> = HashInstruction<N, { Hasher::Sha3_256 as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -51,6 +59,8 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    Keccak256,
+    Sha3_256,
 }
 
 /// Hashes the operand into the declared type.
@@ -60,6 +70,8 @@ pub struct HashInstruction<N: Network, const VARIANT: u8> {
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination register type.
+    destination_type: PlaintextType<N>,
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -76,6 +88,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.keccak256"),
+            10 => Opcode::Hash("hash.sha3_256"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -94,6 +108,66 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> &PlaintextType<N> {
+        &self.destination_type
+    }
+
+    /// Returns `true` if the hash function outputs a byte digest, instead of a literal.
+    #[inline]
+    const fn is_byte_digest() -> bool {
+        VARIANT == Hasher::Keccak256 as u8 || VARIANT == Hasher::Sha3_256 as u8
+    }
+
+    /// Returns the type of a byte digest, which is `[u8; 32u32]`.
+    #[inline]
+    fn byte_digest_type() -> Result<PlaintextType<N>> {
+        Ok(PlaintextType::Array(ArrayType::new(PlaintextType::Literal(LiteralType::U8), U32::new(32))?))
+    }
+
+    /// Returns the raw little-endian bits of the given plaintext, where each literal is padded to a whole byte.
+    /// Note: Unlike `to_bits_le`, the bits do not contain the variants or sizes of the plaintext.
+    fn to_raw_bits_le(plaintext: &Plaintext<N>) -> Result<Vec<bool>> {
+        match plaintext {
+            Plaintext::Literal(literal, ..) => {
+                let mut bits = literal.to_bits_le();
+                // Pad the bits to a whole byte.
+                while bits.len() % 8 != 0 {
+                    bits.push(false);
+                }
+                Ok(bits)
+            }
+            Plaintext::Array(elements, ..) => {
+                Ok(elements.iter().map(Self::to_raw_bits_le).collect::<Result<Vec<_>>>()?.concat())
+            }
+            Plaintext::Struct(..) => bail!("Instruction '{}' cannot hash a struct", Self::opcode()),
+        }
+    }
+
+    /// Returns the raw little-endian bits of the given circuit plaintext, where each literal is padded to a whole byte.
+    /// Note: Unlike `to_bits_le`, the bits do not contain the variants or sizes of the plaintext.
+    fn to_raw_bits_le_circuit<A: circuit::Aleo<Network = N>>(
+        plaintext: &circuit::Plaintext<A>,
+    ) -> Result<Vec<circuit::Boolean<A>>> {
+        use circuit::{Inject, ToBits};
+
+        match plaintext {
+            circuit::Plaintext::Literal(literal, ..) => {
+                let mut bits = literal.to_bits_le();
+                // Pad the bits to a whole byte.
+                while bits.len() % 8 != 0 {
+                    bits.push(circuit::Boolean::constant(false));
+                }
+                Ok(bits)
+            }
+            circuit::Plaintext::Array(elements, ..) => {
+                Ok(elements.iter().map(Self::to_raw_bits_le_circuit).collect::<Result<Vec<_>>>()?.concat())
+            }
+            circuit::Plaintext::Struct(..) => bail!("Instruction '{}' cannot hash a struct", Self::opcode()),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -106,21 +180,89 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         }
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+
+        // If the hash function outputs a byte digest, hash the raw bytes of the input.
+        if Self::is_byte_digest() {
+            // Ensure the destination type is a byte digest.
+            if self.destination_type != Self::byte_digest_type()? {
+                bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type)
+            }
+            // Retrieve the raw bits of the input.
+            let bits = match &input {
+                Value::Plaintext(plaintext) => Self::to_raw_bits_le(plaintext)?,
+                Value::Record(..) => bail!("Instruction '{}' cannot hash a record", Self::opcode()),
+            };
+            // Hash the raw bits.
+            let digest = match VARIANT {
+                9 => N::hash_keccak256(&bits)?,
+                10 => N::hash_sha3_256(&bits)?,
+                _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+            };
+            // Convert the digest into a byte array.
+            let bytes = digest
+                .chunks(8)
+                .map(|byte| Ok(Plaintext::from(Literal::U8(U8::from_bits_le(byte)?))))
+                .collect::<Result<Vec<_>>>()?;
+            // Store the output.
+            let output = Value::Plaintext(Plaintext::Array(bytes, Default::default()));
+            return registers.store(stack, &self.destination, output);
+        }
+
+        // Retrieve the literal destination type.
+        let destination_type = match &self.destination_type {
+            PlaintextType::Literal(destination_type) => *destination_type,
+            _ => bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type),
+        };
         // Hash the input.
-        let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
+        let output = match (VARIANT, destination_type) {
+            (_, LiteralType::Boolean | LiteralType::String | LiteralType::Signature) => {
+                bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type)
+            }
+            (0, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?)
+            }
+            (1, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_bhp512(&input.to_bits_le())?)
+            }
+            (2, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_bhp768(&input.to_bits_le())?)
+            }
+            (3, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_bhp1024(&input.to_bits_le())?)
+            }
+            (4, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_ped64(&input.to_bits_le())?)
+            }
+            (5, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_ped128(&input.to_bits_le())?)
+            }
+            (6, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_psd2(&input.to_fields()?)?)
+            }
+            (7, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_psd4(&input.to_fields()?)?)
+            }
+            (8, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_psd8(&input.to_fields()?)?)
+            }
+            (6, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd2(&input.to_fields()?)?),
+            (7, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd4(&input.to_fields()?)?),
+            (8, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd8(&input.to_fields()?)?),
+            (0, _) => Literal::Field(N::hash_bhp256(&input.to_bits_le())?),
+            (1, _) => Literal::Field(N::hash_bhp512(&input.to_bits_le())?),
+            (2, _) => Literal::Field(N::hash_bhp768(&input.to_bits_le())?),
+            (3, _) => Literal::Field(N::hash_bhp1024(&input.to_bits_le())?),
+            (4, _) => Literal::Field(N::hash_ped64(&input.to_bits_le())?),
+            (5, _) => Literal::Field(N::hash_ped128(&input.to_bits_le())?),
+            (6, _) => Literal::Field(N::hash_psd2(&input.to_fields()?)?),
+            (7, _) => Literal::Field(N::hash_psd4(&input.to_fields()?)?),
+            (8, _) => Literal::Field(N::hash_psd8(&input.to_fields()?)?),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
+        // Cast the output into the destination type.
+        let output = output.cast_lossy(destination_type)?;
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
 
     /// Executes the instruction.
@@ -130,7 +272,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         stack: &Stack<N>,
         registers: &mut (impl LoadCircuit<N, A> + StoreCircuit<N, A>),
     ) -> Result<()> {
-        use circuit::{ToBits, ToFields};
+        use circuit::{FromBits, ToBits, ToFields};
 
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
@@ -138,22 +280,92 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         }
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+
+        // If the hash function outputs a byte digest, hash the raw bytes of the input.
+        if Self::is_byte_digest() {
+            // Ensure the destination type is a byte digest.
+            if self.destination_type != Self::byte_digest_type()? {
+                bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type)
+            }
+            // Retrieve the raw bits of the input.
+            let bits = match &input {
+                circuit::Value::Plaintext(plaintext) => Self::to_raw_bits_le_circuit(plaintext)?,
+                circuit::Value::Record(..) => bail!("Instruction '{}' cannot hash a record", Self::opcode()),
+            };
+            // Hash the raw bits.
+            let digest = match VARIANT {
+                9 => A::hash_keccak256(&bits),
+                10 => A::hash_sha3_256(&bits),
+                _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+            };
+            // Convert the digest into a byte array.
+            let bytes = digest
+                .chunks(8)
+                .map(|byte| {
+                    let byte = circuit::Literal::U8(circuit::U8::from_bits_le(byte));
+                    circuit::Plaintext::Literal(byte, Default::default())
+                })
+                .collect();
+            // Store the output.
+            let output = circuit::Value::Plaintext(circuit::Plaintext::Array(bytes, Default::default()));
+            return registers.store_circuit(stack, &self.destination, output);
+        }
+
+        // Retrieve the literal destination type.
+        let destination_type = match &self.destination_type {
+            PlaintextType::Literal(destination_type) => *destination_type,
+            _ => bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type),
+        };
         // Hash the input.
-        let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
+        let output = match (VARIANT, destination_type) {
+            (_, LiteralType::Boolean | LiteralType::String | LiteralType::Signature) => {
+                bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type)
+            }
+            (0, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_bhp256(&input.to_bits_le()))
+            }
+            (1, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_bhp512(&input.to_bits_le()))
+            }
+            (2, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_bhp768(&input.to_bits_le()))
+            }
+            (3, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_bhp1024(&input.to_bits_le()))
+            }
+            (4, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_ped64(&input.to_bits_le()))
+            }
+            (5, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_ped128(&input.to_bits_le()))
+            }
+            (6, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_psd2(&input.to_fields()))
+            }
+            (7, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_psd4(&input.to_fields()))
+            }
+            (8, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_psd8(&input.to_fields()))
+            }
+            (6, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd2(&input.to_fields())),
+            (7, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd4(&input.to_fields())),
+            (8, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd8(&input.to_fields())),
+            (0, _) => circuit::Literal::Field(A::hash_bhp256(&input.to_bits_le())),
+            (1, _) => circuit::Literal::Field(A::hash_bhp512(&input.to_bits_le())),
+            (2, _) => circuit::Literal::Field(A::hash_bhp768(&input.to_bits_le())),
+            (3, _) => circuit::Literal::Field(A::hash_bhp1024(&input.to_bits_le())),
+            (4, _) => circuit::Literal::Field(A::hash_ped64(&input.to_bits_le())),
+            (5, _) => circuit::Literal::Field(A::hash_ped128(&input.to_bits_le())),
+            (6, _) => circuit::Literal::Field(A::hash_psd2(&input.to_fields())),
+            (7, _) => circuit::Literal::Field(A::hash_psd4(&input.to_fields())),
+            (8, _) => circuit::Literal::Field(A::hash_psd8(&input.to_fields())),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
+        // Cast the output into the destination type.
+        let output = output.cast_lossy(destination_type)?;
         // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
         registers.store_circuit(stack, &self.destination, output)
     }
@@ -178,9 +390,35 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
+        // Ensure the destination type is supported.
+        // Note: The Keccak and SHA-3 hash functions only hash into a byte digest.
+        let is_supported = match &self.destination_type {
+            destination_type if Self::is_byte_digest() => *destination_type == Self::byte_digest_type()?,
+            PlaintextType::Literal(LiteralType::Boolean | LiteralType::String | LiteralType::Signature) => false,
+            PlaintextType::Literal(..) => true,
+            PlaintextType::Struct(..) | PlaintextType::Array(..) => false,
+        };
+        if !is_supported {
+            bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type)
+        }
+
+        // Ensure the input of a byte digest is a literal or an array of literals.
+        if Self::is_byte_digest() {
+            let is_bytes = match &input_types[0] {
+                RegisterType::Plaintext(PlaintextType::Literal(..)) => true,
+                RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                    matches!(array_type.base_element_type(), PlaintextType::Literal(..))
+                }
+                _ => false,
+            };
+            if !is_bytes {
+                bail!("Instruction '{}' cannot hash a '{}'", Self::opcode(), input_types[0])
+            }
+        }
+
         match VARIANT {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
-                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 => {
+                Ok(vec![RegisterType::Plaintext(self.destination_type.clone())])
             }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
@@ -205,8 +443,16 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = PlaintextType::parse(string)?;
 
-        Ok((string, Self { operands: vec![operand], destination }))
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
     }
 }
 
@@ -246,7 +492,7 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {} as {}", self.destination, self.destination_type)
     }
 }
 
@@ -257,8 +503,10 @@ impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
         let operands = vec![Operand::read_le(&mut reader)?];
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = PlaintextType::read_le(&mut reader)?;
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destination, destination_type })
     }
 }
 
@@ -272,7 +520,9 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
         // Write the operand.
         self.operands[0].write_le(&mut writer)?;
         // Write the destination register.
-        self.destination.write_le(&mut writer)
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
     }
 }
 
@@ -282,13 +532,76 @@ mod tests {
     use console::network::Testnet3;
 
     type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns the bytes of the given little-endian bits, as a hex string.
+    fn to_hex(bits: &[bool]) -> String {
+        bits.chunks(8)
+            .map(|byte| format!("{:02x}", byte.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8)))
+            .collect()
+    }
+
+    #[test]
+    fn test_byte_digest_matches_known_vectors() {
+        use circuit::{Aleo, Eject, Inject};
+
+        // Hash the raw bytes of the string "abc", and of `1337u64`.
+        for (input, keccak256, sha3_256) in [
+            (
+                "[97u8, 98u8, 99u8]",
+                "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+                "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            ),
+            (
+                "1337u64",
+                "da13b335bab8956816e757c4dddd6bd224107b1ba0745bd307c1be59f8f3d990",
+                "dedd47a06471fa2977d2bc5edc49702d7f47ec4ec2babfc7b79f4f4c7844edb8",
+            ),
+        ] {
+            let plaintext = Plaintext::<CurrentNetwork>::from_str(input).unwrap();
+            // Ensure the digests match the known vectors.
+            let bits = HashKeccak256::<CurrentNetwork>::to_raw_bits_le(&plaintext).unwrap();
+            assert_eq!(keccak256, to_hex(&CurrentNetwork::hash_keccak256(&bits).unwrap()));
+            assert_eq!(sha3_256, to_hex(&CurrentNetwork::hash_sha3_256(&bits).unwrap()));
+
+            // Ensure the circuit digests match the known vectors.
+            let circuit_plaintext = circuit::Plaintext::<CurrentAleo>::new(circuit::Mode::Private, plaintext);
+            let bits = HashKeccak256::<CurrentNetwork>::to_raw_bits_le_circuit(&circuit_plaintext).unwrap();
+            assert_eq!(keccak256, to_hex(&CurrentAleo::hash_keccak256(&bits).eject_value()));
+            assert_eq!(sha3_256, to_hex(&CurrentAleo::hash_sha3_256(&bits).eject_value()));
+        }
+    }
 
     #[test]
     fn test_parse() {
-        let (string, hash) = HashBHP512::<CurrentNetwork>::parse("hash.bhp512 r0 into r1").unwrap();
+        let (string, hash) = HashBHP512::<CurrentNetwork>::parse("hash.bhp512 r0 into r1 as field").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
         assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, LiteralType::Field.into(), "The destination type is incorrect");
+
+        let (string, hash) = HashPSD2::<CurrentNetwork>::parse("hash.psd2 r0 into r1 as u64").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.destination_type, LiteralType::U64.into(), "The destination type is incorrect");
+        assert_eq!(hash.to_string(), "hash.psd2 r0 into r1 as u64");
+
+        let (string, hash) =
+            HashKeccak256::<CurrentNetwork>::parse("hash.keccak256 r0 into r1 as [u8; 32u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(
+            hash.destination_type,
+            HashKeccak256::<CurrentNetwork>::byte_digest_type().unwrap(),
+            "The destination type is incorrect"
+        );
+        assert_eq!(hash.to_string(), "hash.keccak256 r0 into r1 as [u8; 32u32]");
+
+        let (string, hash) = HashSha3_256::<CurrentNetwork>::parse("hash.sha3_256 r0 into r1 as [u8; 32u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.to_string(), "hash.sha3_256 r0 into r1 as [u8; 32u32]");
+
+        // Ensure the hash instruction round-trips through bytes.
+        let bytes = hash.to_bytes_le().unwrap();
+        assert_eq!(hash, HashSha3_256::<CurrentNetwork>::from_bytes_le(&bytes).unwrap());
     }
 }
@@ -35,6 +35,12 @@ pub use is::*;
 mod literals;
 pub use literals::*;
 
+mod sign_verify;
+pub use sign_verify::*;
+
+mod ternary;
+pub use ternary::Ternary;
+
 mod macros;
 
 use crate::Opcode;
@@ -727,30 +733,6 @@ crate::operation!(
     }
 );
 
-/// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
-pub type Ternary<N> = TernaryLiteral<N, TernaryOperation<N>>;
-
-crate::operation!(
-    pub struct TernaryOperation<console::prelude::Ternary, circuit::prelude::Ternary, ternary, "ternary"> {
-        (Boolean, Address, Address) => Address,
-        (Boolean, Boolean, Boolean) => Boolean,
-        (Boolean, Field, Field) => Field,
-        (Boolean, Group, Group) => Group,
-        (Boolean, I8, I8) => I8,
-        (Boolean, I16, I16) => I16,
-        (Boolean, I32, I32) => I32,
-        (Boolean, I64, I64) => I64,
-        (Boolean, I128, I128) => I128,
-        (Boolean, U8, U8) => U8,
-        (Boolean, U16, U16) => U16,
-        (Boolean, U32, U32) => U32,
-        (Boolean, U64, U64) => U64,
-        (Boolean, U128, U128) => U128,
-        (Boolean, Scalar, Scalar) => Scalar,
-        // (Boolean, StringType, StringType) => StringType,
-    }
-);
-
 /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
 pub type Xor<N> = BinaryLiteral<N, XorOperation<N>>;
 
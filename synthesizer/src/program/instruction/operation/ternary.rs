@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Load, LoadCircuit, Opcode, Operand, Stack, Store, StoreCircuit};
+use console::{
+    network::prelude::*,
+    program::{EntryType, Literal, LiteralType, PlaintextType, RecordType, Register, RegisterType},
+};
+
+/// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Ternary<N: Network> {
+    /// The operands, as `(condition, first, second)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Ternary<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("ternary")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> Ternary<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(&self, stack: &Stack<N>, registers: &mut (impl Load<N> + Store<N>)) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the condition.
+        let condition = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Boolean(condition) => condition,
+            literal => bail!("Instruction '{}' expects a boolean condition, found '{literal}'", Self::opcode()),
+        };
+        // Retrieve the inputs.
+        let first = registers.load(stack, &self.operands[1])?;
+        let second = registers.load(stack, &self.operands[2])?;
+
+        // Select the output.
+        let output = match *condition {
+            true => first,
+            false => second,
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut (impl LoadCircuit<N, A> + StoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Ternary as _;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the condition.
+        let condition = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Boolean(condition) => condition,
+            _ => bail!("Instruction '{}' expects a boolean condition", Self::opcode()),
+        };
+        // Retrieve the inputs.
+        let first = registers.load_circuit(stack, &self.operands[1])?;
+        let second = registers.load_circuit(stack, &self.operands[2])?;
+
+        // Select the output, member-wise.
+        let output = circuit::Value::ternary(&condition, &first, &second);
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut (impl Load<N> + Store<N>)) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the condition is a boolean.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean)) {
+            bail!("Instruction '{}' expects a boolean condition, found '{}'", Self::opcode(), input_types[0])
+        }
+        // Ensure the operands are of the same type.
+        if input_types[1] != input_types[2] {
+            bail!(
+                "Instruction '{}' expects inputs of the same type. Found inputs of type '{}' and '{}'",
+                Self::opcode(),
+                input_types[1],
+                input_types[2]
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the operand type can be selected in a circuit.
+        match &input_types[1] {
+            RegisterType::Plaintext(plaintext_type) => Self::check_plaintext_type(stack, plaintext_type, 0)?,
+            RegisterType::Record(record_name) => {
+                Self::check_record_type(stack, &stack.program().get_record(record_name)?)?
+            }
+            RegisterType::ExternalRecord(locator) => {
+                // Retrieve the external stack.
+                let external_stack = stack.get_external_stack(locator.program_id())?;
+                // Check the external record type.
+                Self::check_record_type(external_stack, &external_stack.program().get_record(locator.resource())?)?
+            }
+        }
+
+        Ok(vec![input_types[1].clone()])
+    }
+}
+
+impl<N: Network> Ternary<N> {
+    /// Ensures every entry in the given record type can be selected.
+    fn check_record_type(stack: &Stack<N>, record_type: &RecordType<N>) -> Result<()> {
+        record_type.entries().values().try_for_each(|entry_type| match entry_type {
+            EntryType::Constant(plaintext_type)
+            | EntryType::Public(plaintext_type)
+            | EntryType::Private(plaintext_type) => Self::check_plaintext_type(stack, plaintext_type, 1),
+        })
+    }
+
+    /// Ensures every member in the given plaintext type can be selected.
+    fn check_plaintext_type(stack: &Stack<N>, plaintext_type: &PlaintextType<N>, depth: usize) -> Result<()> {
+        // If the depth exceeds the maximum depth, then the plaintext type is invalid.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+        match plaintext_type {
+            PlaintextType::Literal(LiteralType::String) | PlaintextType::Literal(LiteralType::Signature) => {
+                bail!("Instruction '{}' does not support inputs of type '{plaintext_type}'", Self::opcode())
+            }
+            PlaintextType::Literal(..) => Ok(()),
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct, and check each of its members.
+                stack
+                    .program()
+                    .get_struct(struct_name)?
+                    .members()
+                    .values()
+                    .try_for_each(|member_type| Self::check_plaintext_type(stack, member_type, depth + 1))
+            }
+            PlaintextType::Array(array_type) => Self::check_plaintext_type(stack, array_type.element_type(), depth + 1),
+        }
+    }
+}
+
+impl<N: Network> Parser for Ternary<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the condition operand from the string.
+        let (string, condition) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![condition, first, second], destination }))
+    }
+}
+
+impl<N: Network> FromStr for Ternary<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Ternary<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Ternary<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Ternary<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Ternary<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, ternary) = Ternary::<CurrentNetwork>::parse("ternary r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ternary.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(ternary.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(ternary.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(ternary.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(ternary.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+
+    #[test]
+    fn test_bytes() {
+        let expected = Ternary::<CurrentNetwork>::from_str("ternary r0 true 1u8 into r1").unwrap();
+        let candidate = Ternary::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+    }
+}
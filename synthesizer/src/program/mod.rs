@@ -35,6 +35,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
+use crate::finalize::Command;
 use console::{
     network::prelude::*,
     program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
@@ -368,6 +369,14 @@ impl<N: Network> Program<N> {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
+                PlaintextType::Array(array_type) => {
+                    // Ensure the base element struct name, if any, exists in the program.
+                    if let PlaintextType::Struct(member_identifier) = array_type.base_element_type() {
+                        if !self.structs.contains_key(member_identifier) {
+                            bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
+                        }
+                    }
+                }
             }
         }
 
@@ -418,6 +427,13 @@ impl<N: Network> Program<N> {
                             bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                         }
                     }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.base_element_type() {
+                            if !self.structs.contains_key(identifier) {
+                                bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            }
+                        }
+                    }
                 },
             }
         }
@@ -491,6 +507,7 @@ impl<N: Network> Program<N> {
     /// This method will halt if a destination register already exists in memory.
     /// This method will halt if an output register does not already exist.
     /// This method will halt if an output type references a non-existent definition.
+    /// This method will halt if a finalize branch does not jump forward to a defined position.
     #[inline]
     fn add_function(&mut self, function: Function<N>) -> Result<()> {
         // Retrieve the function name.
@@ -513,6 +530,27 @@ impl<N: Network> Program<N> {
         // Ensure the number of outputs is within the allowed range.
         ensure!(function.outputs().len() <= N::MAX_OUTPUTS, "Function exceeds maximum number of outputs");
 
+        // If the function has a finalize scope, ensure each branch jumps forward to a defined position.
+        // Note that this ensures the finalize scope always terminates.
+        if let Some((_, finalize)) = function.finalize() {
+            for (index, command) in finalize.commands().iter().enumerate() {
+                // Retrieve the position of the branch, if the command is a branch.
+                let position = match command {
+                    Command::BranchEq(branch_eq) => branch_eq.position(),
+                    Command::BranchNeq(branch_neq) => branch_neq.position(),
+                    _ => continue,
+                };
+                // Ensure the position is defined, and is after the branch.
+                match finalize.positions().get(position) {
+                    Some(target) => ensure!(
+                        *target > index,
+                        "Branch in 'finalize {function_name}' must jump forward to position '{position}'"
+                    ),
+                    None => bail!("Position '{position}' in 'finalize {function_name}' is not defined."),
+                }
+            }
+        }
+
         // Add the function name to the identifiers.
         if self.identifiers.insert(function_name, ProgramDefinition::Function).is_some() {
             bail!("'{function_name}' already exists in the program.")
@@ -550,6 +588,7 @@ impl<N: Network> Program<N> {
         "u128",
         "scalar",
         "string",
+        "signature",
         // Boolean
         "true",
         "false",
@@ -629,10 +668,10 @@ mod tests {
     use crate::{CallStack, Execution, Inclusion};
     use circuit::network::AleoV0;
     use console::{
-        account::{Address, PrivateKey},
+        account::{Address, PrivateKey, Signature},
         network::Testnet3,
-        program::{Locator, Plaintext, Record, Value, ValueType},
-        types::Field,
+        program::{Literal, LiteralType, Locator, Plaintext, Record, Value, ValueType},
+        types::{Field, Group, Scalar},
     };
 
     use parking_lot::RwLock;
@@ -736,6 +775,76 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_function_finalize_branch() -> Result<()> {
+        // Create a new function, with a finalize that branches forward.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to skip;
+    add r0 1u64 into r1;
+    branch.neq r1 1u64 to skip;
+    position skip;",
+        )?;
+
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        // Add the function to the program.
+        program.add_function(function.clone())?;
+        // Ensure the retrieved function matches.
+        assert_eq!(function, program.get_function(&Identifier::from_str("compute")?)?);
+
+        // Ensure a branch to an undefined position fails.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to missing;
+    position skip;",
+        )?;
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        assert!(program.add_function(function).is_err());
+
+        // Ensure a branch backwards fails.
+        let result = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    position start;
+    branch.eq r0 0u64 to start;",
+        );
+        assert!(result.is_err());
+
+        // Ensure redefining a position fails.
+        let result = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    position start;
+    position start;",
+        );
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_import() -> Result<()> {
         // Initialize a new program.
@@ -1187,4 +1296,586 @@ function compute:
         assert_eq!(1, candidate.len());
         assert_eq!(expected, candidate[0]);
     }
+
+    #[test]
+    fn test_program_evaluate_cast_literal() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program literal_cast.aleo;
+
+function compute:
+    input r0 as i16.private;
+    input r1 as u64.private;
+    cast r0 into r2 as i8;
+    cast.lossy r0 into r3 as u8;
+    cast r1 into r4 as field;
+    cast.lossy r1 into r5 as boolean;
+    output r2 as i8.private;
+    output r3 as u8.private;
+    output r4 as field.private;
+    output r5 as boolean.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Declare the input and expected output values.
+        let r0 = Value::<CurrentNetwork>::from_str("-3i16").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("7u64").unwrap();
+        let r2 = Value::from_str("-3i8").unwrap();
+        let r3 = Value::from_str("253u8").unwrap();
+        let r4 = Value::from_str("7field").unwrap();
+        let r5 = Value::from_str("true").unwrap();
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Ensure a lossy cast into a struct is rejected.
+        let invalid_program = Program::<CurrentNetwork>::from_str(
+            r"
+program literal_cast_struct.aleo;
+
+struct message:
+    first as field;
+
+function compute:
+    input r0 as field.private;
+    cast.lossy r0 into r1 as message;
+    output r1 as message.private;",
+        )
+        .unwrap();
+        assert!(process.add_program(&invalid_program).is_err());
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Initialize caller private key.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output value.
+        let response =
+            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(4, candidate.len());
+        assert_eq!(r2, candidate[0]);
+        assert_eq!(r3, candidate[1]);
+        assert_eq!(r4, candidate[2]);
+        assert_eq!(r5, candidate[3]);
+
+        // Execute the function.
+        let execution = Arc::new(RwLock::new(Execution::new()));
+        let inclusion = Arc::new(RwLock::new(Inclusion::new()));
+        let metrics = Arc::new(RwLock::new(Vec::new()));
+        let call_stack = CallStack::execute(authorization, execution, inclusion, metrics).unwrap();
+        let response = stack.execute_function::<CurrentAleo, _>(call_stack, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(4, candidate.len());
+        assert_eq!(r2, candidate[0]);
+        assert_eq!(r3, candidate[1]);
+        assert_eq!(r4, candidate[2]);
+        assert_eq!(r5, candidate[3]);
+    }
+
+    #[test]
+    fn test_program_evaluate_array() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program array_access.aleo;
+
+struct bag:
+    items as [u8; 4u32];
+
+mapping bags:
+    key owner as [u8; 2u32].public;
+    value contents as bag.public;
+
+function compute:
+    input r0 as [u8; 4u32].private;
+    input r1 as bag.private;
+    cast r0[1u32] r1.items[3u32] into r2 as [u8; 2u32];
+    add r0[0u32] r1.items[2u32] into r3;
+    cast r2 r2 into r4 as [[u8; 2u32]; 2u32];
+    output r2 as [u8; 2u32].private;
+    output r3 as u8.private;
+    output r4[1u32] as [u8; 2u32].private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Declare the input and expected output values.
+        let r0 = Value::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8, 4u8]").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("{ items: [5u8, 6u8, 7u8, 8u8] }").unwrap();
+        let r2 = Value::from_str("[2u8, 8u8]").unwrap();
+        let r3 = Value::from_str("8u8").unwrap();
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Ensure an out-of-bounds index is rejected.
+        let invalid_program = Program::<CurrentNetwork>::from_str(
+            r"
+program array_out_of_bounds.aleo;
+
+function compute:
+    input r0 as [u8; 4u32].private;
+    output r0[4u32] as u8.private;",
+        )
+        .unwrap();
+        assert!(process.add_program(&invalid_program).is_err());
+
+        // Ensure a cast with the wrong number of elements is rejected.
+        let invalid_program = Program::<CurrentNetwork>::from_str(
+            r"
+program array_wrong_length.aleo;
+
+function compute:
+    input r0 as u8.private;
+    cast r0 r0 r0 into r1 as [u8; 2u32];
+    output r1 as [u8; 2u32].private;",
+        )
+        .unwrap();
+        assert!(process.add_program(&invalid_program).is_err());
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Initialize caller private key.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output value.
+        let response =
+            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(3, candidate.len());
+        assert_eq!(r2, candidate[0]);
+        assert_eq!(r3, candidate[1]);
+        assert_eq!(r2, candidate[2]);
+
+        // Execute the function.
+        let execution = Arc::new(RwLock::new(Execution::new()));
+        let inclusion = Arc::new(RwLock::new(Inclusion::new()));
+        let metrics = Arc::new(RwLock::new(Vec::new()));
+        let call_stack = CallStack::execute(authorization, execution, inclusion, metrics).unwrap();
+        let response = stack.execute_function::<CurrentAleo, _>(call_stack, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(3, candidate.len());
+        assert_eq!(r2, candidate[0]);
+        assert_eq!(r3, candidate[1]);
+        assert_eq!(r2, candidate[2]);
+    }
+
+    #[test]
+    fn test_program_evaluate_sign_verify() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program signature_check.aleo;
+
+function verify:
+    input r0 as signature.private;
+    input r1 as address.private;
+    input r2 as field.private;
+    sign.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("verify").unwrap();
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Ensure the operands must be a signature and an address.
+        let invalid_program = Program::<CurrentNetwork>::from_str(
+            r"
+program signature_swapped.aleo;
+
+function verify:
+    input r0 as signature.private;
+    input r1 as address.private;
+    input r2 as field.private;
+    sign.verify r1 r0 r2 into r3;
+    output r3 as boolean.private;",
+        )
+        .unwrap();
+        assert!(process.add_program(&invalid_program).is_err());
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Initialize caller private key.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Sign a message with a third-party signer.
+        let signer_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let signer = Address::try_from(&signer_private_key).unwrap();
+        let message = Value::<CurrentNetwork>::from_str("1337field").unwrap();
+        let signature = Signature::sign(&signer_private_key, &message.to_fields().unwrap(), rng).unwrap();
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        for (address, expected) in [(signer, true), (Address::try_from(&caller_private_key).unwrap(), false)] {
+            // Declare the inputs and the expected output.
+            let r0 = Value::<CurrentNetwork>::from_str(&signature.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap();
+            let r2 = message.clone();
+            let r3 = Value::from_str(&expected.to_string()).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Compute the output value.
+            let response = stack
+                .evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap())
+                .unwrap();
+            let candidate = response.outputs();
+            assert_eq!(1, candidate.len());
+            assert_eq!(r3, candidate[0]);
+
+            // Execute the function.
+            let execution = Arc::new(RwLock::new(Execution::new()));
+            let inclusion = Arc::new(RwLock::new(Inclusion::new()));
+            let metrics = Arc::new(RwLock::new(Vec::new()));
+            let call_stack = CallStack::execute(authorization, execution, inclusion, metrics).unwrap();
+            let response = stack.execute_function::<CurrentAleo, _>(call_stack, rng).unwrap();
+            let candidate = response.outputs();
+            assert_eq!(1, candidate.len());
+            assert_eq!(r3, candidate[0]);
+        }
+    }
+
+    #[test]
+    fn test_program_evaluate_hash_and_commit() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program hash_commit.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as scalar.private;
+    hash.bhp256 r0 into r2 as group;
+    hash.ped64 r0 into r3 as address;
+    hash.psd2 r0 into r4 as scalar;
+    hash.psd4 r0 into r5 as u8;
+    hash.bhp512 r0 into r6 as i64;
+    commit.bhp256 r0 r1 into r7 as group;
+    commit.ped64 r0 r1 into r8 as field;
+    commit.bhp512 r0 r1 into r9 as u128;
+    output r2 as group.private;
+    output r3 as address.private;
+    output r4 as scalar.private;
+    output r5 as u8.private;
+    output r6 as i64.private;
+    output r7 as group.private;
+    output r8 as field.private;
+    output r9 as u128.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Declare the inputs.
+        let r0 = Value::<CurrentNetwork>::from_str("1337u32").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("1234scalar").unwrap();
+
+        // Compute the expected outputs.
+        let bits = r0.to_bits_le();
+        let fields = r0.to_fields().unwrap();
+        let randomizer = Scalar::<CurrentNetwork>::from_str("1234scalar").unwrap();
+        let expected = [
+            Literal::Group(CurrentNetwork::hash_to_group_bhp256(&bits).unwrap()),
+            Literal::Address(Address::new(CurrentNetwork::hash_to_group_ped64(&bits).unwrap())),
+            Literal::Scalar(CurrentNetwork::hash_to_scalar_psd2(&fields).unwrap()),
+            Literal::Field(CurrentNetwork::hash_psd4(&fields).unwrap()).cast_lossy(LiteralType::U8).unwrap(),
+            Literal::Field(CurrentNetwork::hash_bhp512(&bits).unwrap()).cast_lossy(LiteralType::I64).unwrap(),
+            Literal::Group(CurrentNetwork::commit_to_group_bhp256(&bits, &randomizer).unwrap()),
+            Literal::Field(CurrentNetwork::commit_ped64(&bits, &randomizer).unwrap().to_x_coordinate()),
+            Literal::Field(CurrentNetwork::commit_bhp512(&bits, &randomizer).unwrap())
+                .cast_lossy(LiteralType::U128)
+                .unwrap(),
+        ]
+        .map(|literal| Value::Plaintext(Plaintext::from(literal)));
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Ensure a hash into a boolean is rejected.
+        let invalid_program = Program::<CurrentNetwork>::from_str(
+            r"
+program hash_boolean.aleo;
+
+function compute:
+    input r0 as field.private;
+    hash.psd2 r0 into r1 as boolean;
+    output r1 as boolean.private;",
+        )
+        .unwrap();
+        assert!(process.add_program(&invalid_program).is_err());
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Initialize caller private key.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output value.
+        let response =
+            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap()).unwrap();
+        assert_eq!(expected.as_slice(), response.outputs());
+
+        // Execute the function.
+        let execution = Arc::new(RwLock::new(Execution::new()));
+        let inclusion = Arc::new(RwLock::new(Inclusion::new()));
+        let metrics = Arc::new(RwLock::new(Vec::new()));
+        let call_stack = CallStack::execute(authorization, execution, inclusion, metrics).unwrap();
+        let response = stack.execute_function::<CurrentAleo, _>(call_stack, rng).unwrap();
+        assert_eq!(expected.as_slice(), response.outputs());
+    }
+
+    #[test]
+    fn test_program_evaluate_keccak256_and_sha3_256() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program keccak.aleo;
+
+function compute:
+    input r0 as [u8; 3u32].private;
+    input r1 as u64.private;
+    input r2 as field.private;
+    hash.keccak256 r0 into r3 as [u8; 32u32];
+    hash.sha3_256 r0 into r4 as [u8; 32u32];
+    hash.sha3_256 r1 into r5 as [u8; 32u32];
+    hash.sha3_256 r2 into r6 as [u8; 32u32];
+    output r3 as [u8; 32u32].private;
+    output r4 as [u8; 32u32].private;
+    output r5 as [u8; 32u32].private;
+    output r6 as [u8; 32u32].private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Declare the inputs, where `r0` is the string "abc".
+        let r0 = Value::<CurrentNetwork>::from_str("[97u8, 98u8, 99u8]").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("1337u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("1field").unwrap();
+
+        // Declare the expected outputs, which are the digests of the little-endian bytes of the inputs.
+        let to_value = |digest: &str| {
+            let bytes = (0..digest.len())
+                .step_by(2)
+                .map(|i| format!("{}u8", u8::from_str_radix(&digest[i..i + 2], 16).unwrap()))
+                .collect::<Vec<_>>();
+            Value::<CurrentNetwork>::from_str(&format!("[{}]", bytes.join(", "))).unwrap()
+        };
+        let expected = [
+            to_value("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+            to_value("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+            to_value("dedd47a06471fa2977d2bc5edc49702d7f47ec4ec2babfc7b79f4f4c7844edb8"),
+            to_value("17cd8acc6c4e438664ef675e23dd274fed89954bc8e1e5ad0003f99332212603"),
+        ];
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Ensure a Keccak hash into a literal is rejected.
+        let invalid_program = Program::<CurrentNetwork>::from_str(
+            r"
+program keccak_field.aleo;
+
+function compute:
+    input r0 as field.private;
+    hash.keccak256 r0 into r1 as field;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        assert!(process.add_program(&invalid_program).is_err());
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Initialize caller private key.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output value.
+        let response =
+            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap()).unwrap();
+        assert_eq!(expected.as_slice(), response.outputs());
+
+        // Execute the function.
+        let execution = Arc::new(RwLock::new(Execution::new()));
+        let inclusion = Arc::new(RwLock::new(Inclusion::new()));
+        let metrics = Arc::new(RwLock::new(Vec::new()));
+        let call_stack = CallStack::execute(authorization, execution, inclusion, metrics).unwrap();
+        let response = stack.execute_function::<CurrentAleo, _>(call_stack, rng).unwrap();
+        assert_eq!(expected.as_slice(), response.outputs());
+    }
+
+    #[test]
+    fn test_program_evaluate_ternary_struct_and_record() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program ternary.aleo;
+
+struct point:
+    x as u32;
+    y as field;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    location as point.public;
+
+function select:
+    input r0 as boolean.private;
+    input r1 as point.private;
+    input r2 as point.public;
+    input r3 as token.record;
+    input r4 as token.record;
+    ternary r0 r1 r2 into r5;
+    ternary r0 r3 r4 into r6;
+    is.eq r5 r1 into r7;
+    is.eq r6 r3 into r8;
+    assert.neq r1 r2;
+    assert.neq r3 r4;
+    output r5 as point.private;
+    output r6.amount as u64.private;
+    output r6.location as point.private;
+    output r7 as boolean.private;
+    output r8 as boolean.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("select").unwrap();
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Ensure a ternary over mismatched structs is rejected.
+        let invalid_program = Program::<CurrentNetwork>::from_str(
+            r"
+program ternary_mismatch.aleo;
+
+struct point:
+    x as u32;
+    y as field;
+
+struct other:
+    x as u32;
+    y as field;
+
+function select:
+    input r0 as boolean.private;
+    input r1 as point.private;
+    input r2 as other.private;
+    ternary r0 r1 r2 into r3;
+    output r3 as point.private;",
+        )
+        .unwrap();
+        assert!(process.add_program(&invalid_program).is_err());
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Initialize caller private key.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        for (condition, expected) in [
+            ("true", ["{ x: 1u32, y: 2field }", "5u64", "{ x: 7u32, y: 8field }", "true", "true"]),
+            ("false", ["{ x: 3u32, y: 4field }", "6u64", "{ x: 9u32, y: 10field }", "false", "false"]),
+        ] {
+            // Declare the inputs.
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(condition).unwrap(),
+                Value::from_str("{ x: 1u32, y: 2field }").unwrap(),
+                Value::from_str("{ x: 3u32, y: 4field }").unwrap(),
+                Value::from_str(&format!(
+                    "{{ owner: {caller}.private, amount: 5u64.private, location: {{ x: 7u32.public, y: 8field.public }}, _nonce: 0group.public }}"
+                ))
+                .unwrap(),
+                Value::from_str(&format!(
+                    "{{ owner: {caller}.private, amount: 6u64.private, location: {{ x: 9u32.public, y: 10field.public }}, _nonce: {}.public }}",
+                    Group::<CurrentNetwork>::generator()
+                ))
+                .unwrap(),
+            ];
+            // Declare the expected outputs.
+            let expected = expected.map(|output| Value::<CurrentNetwork>::from_str(output).unwrap());
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Compute the output value.
+            let response =
+                stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap()).unwrap();
+            assert_eq!(expected.as_slice(), response.outputs());
+
+            // Execute the function.
+            let execution = Arc::new(RwLock::new(Execution::new()));
+            let inclusion = Arc::new(RwLock::new(Inclusion::new()));
+            let metrics = Arc::new(RwLock::new(Vec::new()));
+            let call_stack = CallStack::execute(authorization, execution, inclusion, metrics).unwrap();
+            let response = stack.execute_function::<CurrentAleo, _>(call_stack, rng).unwrap();
+            assert_eq!(expected.as_slice(), response.outputs());
+        }
+    }
 }
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The domain separator of a spent record in the ledger digest.
+const SERIAL_NUMBER_DOMAIN: u64 = 0;
+/// The domain separator of a created record in the ledger digest.
+const COMMITMENT_DOMAIN: u64 = 1;
+/// The domain separator of a deployment in the ledger digest.
+const DEPLOYMENT_DOMAIN: u64 = 2;
+
+/// Returns the ledger digest of a spent record, for the given `(serial number, tag)` pair.
+///
+/// The ledger digest is a multiset hash over the spent records, the created records, and the deployments
+/// in the ledger, computed as the sum of a hash to the group of each element. As a sum, the ledger digest
+/// is updated by adding (or subtracting) the digest of each block, and is independent of the order of the elements.
+pub fn to_serial_number_digest<N: Network>(serial_number: &Field<N>, tag: &Field<N>) -> Result<Group<N>> {
+    N::hash_to_group_psd4(&[Field::from_u64(SERIAL_NUMBER_DOMAIN), *serial_number, *tag])
+}
+
+/// Returns the ledger digest of a created record, for the given `(commitment, checksum)` pair.
+pub fn to_commitment_digest<N: Network>(commitment: &Field<N>, checksum: &Field<N>) -> Result<Group<N>> {
+    N::hash_to_group_psd4(&[Field::from_u64(COMMITMENT_DOMAIN), *commitment, *checksum])
+}
+
+/// Returns the ledger digest of a deployment, for the given `transaction ID`.
+pub fn to_deployment_digest<N: Network>(transaction_id: &N::TransactionID) -> Result<Group<N>> {
+    N::hash_to_group_psd4(&[Field::from_u64(DEPLOYMENT_DOMAIN), **transaction_id])
+}
+
+/// Returns the ledger digest of the given transactions, given the status of each transaction.
+/// An accepted transaction adds its deployment (if any) and the records of every transition,
+/// while an aborted transaction only adds the records of its fee transition.
+pub fn to_ledger_digest<N: Network>(transactions: &Transactions<N>, statuses: &[TransactionStatus]) -> Result<Group<N>> {
+    // Ensure there is a status for each transaction.
+    ensure!(
+        statuses.len() == transactions.len(),
+        "Expected {} transaction statuses for the ledger digest, found {}",
+        transactions.len(),
+        statuses.len()
+    );
+
+    let mut digest = Group::zero();
+    for (transaction, status) in transactions.values().zip_eq(statuses) {
+        // Add the deployment, if it is accepted.
+        if let (Transaction::Deploy(..), TransactionStatus::Accepted) = (transaction, status) {
+            digest += to_deployment_digest::<N>(&transaction.id())?;
+        }
+        // Add the spent and created records of each transition in the ledger.
+        for transition in to_ledger_transitions(transaction, *status) {
+            for input in transition.inputs() {
+                if let Input::Record(serial_number, tag) = input {
+                    digest += to_serial_number_digest(serial_number, tag)?;
+                }
+            }
+            for output in transition.outputs() {
+                if let Output::Record(commitment, checksum, _) = output {
+                    digest += to_commitment_digest(commitment, checksum)?;
+                }
+            }
+        }
+    }
+    Ok(digest)
+}
+
+/// Returns the transitions of the given transaction that are stored in the ledger, given its status.
+/// Only the fee transition of an aborted transaction is stored in the ledger.
+pub(super) fn to_ledger_transitions<N: Network>(
+    transaction: &Transaction<N>,
+    status: TransactionStatus,
+) -> impl '_ + Iterator<Item = &Transition<N>> {
+    let fee_id = transaction.fee_transition().map(|fee| *fee.transition_id());
+    transaction.transitions().filter(move |transition| status.is_accepted() || Some(*transition.id()) == fee_id)
+}
@@ -14,14 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod digest;
+pub use digest::*;
+
+mod pruned;
+pub use pruned::*;
+
+mod status;
+pub use status::*;
+
 use crate::{
     atomic_write_batch,
-    block::{Block, Header, Transactions},
+    block::{Block, Header, Input, Output, Transaction, Transactions, Transition},
     coinbase_puzzle::{CoinbaseSolution, PuzzleCommitment},
     cow_to_cloned,
     cow_to_copied,
     store::{
-        helpers::{memory_map::MemoryMap, Map, MapRead},
+        helpers::{
+            memory_map::{finish_atomic_journaled, MemoryMap},
+            Map,
+            MapRead,
+        },
+        IndexPage,
         TransactionMemory,
         TransactionStorage,
         TransactionStore,
@@ -34,11 +48,24 @@ use crate::{
 use console::{
     account::Signature,
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
-    types::Field,
+    program::{
+        BlockPath,
+        BlockTree,
+        HeaderLeaf,
+        ProgramID,
+        StatePath,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionsPath,
+        TransitionLeaf,
+        TransitionPath,
+        TRANSACTIONS_DEPTH,
+    },
+    types::{Field, Group},
 };
 
 use anyhow::Result;
+use indexmap::IndexSet;
 use parking_lot::RwLock;
 use std::{borrow::Cow, sync::Arc};
 
@@ -52,6 +79,92 @@ macro_rules! bail_with_block {
     }};
 }
 
+/// Returns the pruned path for the given `commitment`, which is an output of the given transition,
+/// in the given transaction of the block with the given block hash.
+fn to_pruned_path<N: Network>(
+    block_hash: N::BlockHash,
+    transaction: &Transaction<N>,
+    transition: &Transition<N>,
+    commitment: &Field<N>,
+) -> Result<PrunedPath<N>> {
+    // Construct the transition path and transition leaf.
+    let transition_leaf = transition.to_leaf(commitment, false)?;
+    let transition_path = transition.to_path(&transition_leaf)?;
+
+    // Construct the transaction path and transaction leaf.
+    let transaction_leaf = transaction.to_leaf(transition.id())?;
+    let transaction_path = transaction.to_path(&transaction_leaf)?;
+
+    Ok((block_hash, transaction.id(), transaction_path, transaction_leaf, transition_path, transition_leaf))
+}
+
+/// Returns the state path for the given pruned path, given the global state root and the block path,
+/// and the previous block hash, block header, and transaction IDs of the block of the pruned path.
+fn to_state_path<N: Network>(
+    global_state_root: N::StateRoot,
+    block_path: BlockPath<N>,
+    previous_hash: N::BlockHash,
+    block_header: &Header<N>,
+    transaction_ids: &[N::TransactionID],
+    pruned_path: PrunedPath<N>,
+) -> Result<StatePath<N>> {
+    let (block_hash, transaction_id, transaction_path, transaction_leaf, transition_path, transition_leaf) =
+        pruned_path;
+
+    // Construct the transactions path.
+    let transactions_path = to_transactions_path(transaction_ids, &transaction_id)?;
+
+    // Construct the block header path.
+    let header_root = block_header.to_root()?;
+    let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
+    let header_path = block_header.to_path(&header_leaf)?;
+
+    Ok(StatePath::from(
+        global_state_root,
+        block_path,
+        block_hash,
+        previous_hash,
+        header_root,
+        header_path,
+        header_leaf,
+        transactions_path,
+        transaction_id,
+        transaction_path,
+        transaction_leaf,
+        transition_path,
+        transition_leaf,
+    ))
+}
+
+/// Returns the Merkle path for the given `transaction ID`, given the transaction IDs of its block, in order.
+/// Note: This matches `Transactions::to_path`, without requiring the transactions of the block.
+fn to_transactions_path<N: Network>(
+    transaction_ids: &[N::TransactionID],
+    transaction_id: &N::TransactionID,
+) -> Result<TransactionsPath<N>> {
+    match transaction_ids.iter().position(|id| id == transaction_id) {
+        Some(index) => {
+            let leaves = transaction_ids.iter().map(|id| id.to_bits_le()).collect::<Vec<_>>();
+            N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves)?.prove(index, &transaction_id.to_bits_le())
+        }
+        None => bail!("The transaction '{transaction_id}' is not in the block transactions"),
+    }
+}
+
+/// Returns the program IDs that the given transactions deploy or execute, including the fee transitions.
+fn to_program_ids<'a, N: Network>(transactions: impl Iterator<Item = &'a Transaction<N>>) -> IndexSet<ProgramID<N>> {
+    let mut program_ids = IndexSet::new();
+    for transaction in transactions {
+        // Add the program ID of the deployment.
+        if let Transaction::Deploy(_, _, deployment, _) = transaction {
+            program_ids.insert(*deployment.program_id());
+        }
+        // Add the program ID of each transition.
+        program_ids.extend(transaction.transitions().map(|transition| *transition.program_id()));
+    }
+    program_ids
+}
+
 /// A trait for block storage.
 pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of `block height` to `state root`.
@@ -68,6 +181,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type TransactionsMap: for<'a> Map<'a, N::BlockHash, Vec<N::TransactionID>>;
     /// The mapping of `transaction ID` to `block hash`.
     type ReverseTransactionsMap: for<'a> Map<'a, N::TransactionID, N::BlockHash>;
+    /// The mapping of `transaction ID` to `transaction status`.
+    type TransactionStatusMap: for<'a> Map<'a, N::TransactionID, TransactionStatus>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -78,10 +193,21 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type CoinbasePuzzleCommitmentMap: for<'a> Map<'a, PuzzleCommitment<N>, N::BlockHash>;
     /// The mapping of `block hash` to `block signature`.
     type SignatureMap: for<'a> Map<'a, N::BlockHash, Signature<N>>;
+    /// The mapping of `block hash` to `block height`, for blocks whose transactions have been pruned.
+    type PrunedMap: for<'a> Map<'a, N::BlockHash, u32>;
+    /// The mapping of `commitment` to `pruned path`, for commitments in pruned blocks.
+    type PrunedPathMap: for<'a> Map<'a, Field<N>, PrunedPath<N>>;
+    /// The mapping of `(program ID, block hash)` to `()`, for the blocks that deploy or execute the program.
+    type BlockIndexMap: for<'a> Map<'a, (ProgramID<N>, N::BlockHash), ()>;
+    /// The mapping of `()` to the `ledger digest`, as of the latest block.
+    type LedgerDigestMap: for<'a> Map<'a, (), Group<N>>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self>;
 
+    /// Returns a read-only view of the block storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self>;
+
     /// Returns the state root map.
     fn state_root_map(&self) -> &Self::StateRootMap;
     /// Returns the reverse state root map.
@@ -96,6 +222,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn transactions_map(&self) -> &Self::TransactionsMap;
     /// Returns the reverse transactions map.
     fn reverse_transactions_map(&self) -> &Self::ReverseTransactionsMap;
+    /// Returns the transaction status map.
+    fn transaction_status_map(&self) -> &Self::TransactionStatusMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
     /// Returns the coinbase solution map.
@@ -104,6 +232,16 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap;
     /// Returns the signature map.
     fn signature_map(&self) -> &Self::SignatureMap;
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap;
+    /// Returns the pruned path map.
+    fn pruned_path_map(&self) -> &Self::PrunedPathMap;
+    /// Returns the ledger digest map.
+    fn ledger_digest_map(&self) -> &Self::LedgerDigestMap;
+    /// Returns the block index map, if the storage maintains the index.
+    fn block_index_map(&self) -> Option<&Self::BlockIndexMap> {
+        None
+    }
 
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
@@ -124,10 +262,17 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.header_map().start_atomic();
         self.transactions_map().start_atomic();
         self.reverse_transactions_map().start_atomic();
+        self.transaction_status_map().start_atomic();
         self.transaction_store().start_atomic();
         self.coinbase_solution_map().start_atomic();
         self.coinbase_puzzle_commitment_map().start_atomic();
         self.signature_map().start_atomic();
+        self.pruned_map().start_atomic();
+        self.pruned_path_map().start_atomic();
+        self.ledger_digest_map().start_atomic();
+        if let Some(block_index_map) = self.block_index_map() {
+            block_index_map.start_atomic();
+        }
     }
 
     /// Checks if an atomic batch is in progress.
@@ -139,10 +284,15 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.header_map().is_atomic_in_progress()
             || self.transactions_map().is_atomic_in_progress()
             || self.reverse_transactions_map().is_atomic_in_progress()
+            || self.transaction_status_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
             || self.coinbase_solution_map().is_atomic_in_progress()
             || self.coinbase_puzzle_commitment_map().is_atomic_in_progress()
             || self.signature_map().is_atomic_in_progress()
+            || self.pruned_map().is_atomic_in_progress()
+            || self.pruned_path_map().is_atomic_in_progress()
+            || self.ledger_digest_map().is_atomic_in_progress()
+            || self.block_index_map().is_some_and(|map| map.is_atomic_in_progress())
     }
 
     /// Aborts an atomic batch write operation.
@@ -154,10 +304,17 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.header_map().abort_atomic();
         self.transactions_map().abort_atomic();
         self.reverse_transactions_map().abort_atomic();
+        self.transaction_status_map().abort_atomic();
         self.transaction_store().abort_atomic();
         self.coinbase_solution_map().abort_atomic();
         self.coinbase_puzzle_commitment_map().abort_atomic();
         self.signature_map().abort_atomic();
+        self.pruned_map().abort_atomic();
+        self.pruned_path_map().abort_atomic();
+        self.ledger_digest_map().abort_atomic();
+        if let Some(block_index_map) = self.block_index_map() {
+            block_index_map.abort_atomic();
+        }
     }
 
     /// Finishes an atomic batch write operation.
@@ -169,14 +326,31 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.header_map().finish_atomic()?;
         self.transactions_map().finish_atomic()?;
         self.reverse_transactions_map().finish_atomic()?;
+        self.transaction_status_map().finish_atomic()?;
         self.transaction_store().finish_atomic()?;
         self.coinbase_solution_map().finish_atomic()?;
         self.coinbase_puzzle_commitment_map().finish_atomic()?;
-        self.signature_map().finish_atomic()
+        self.signature_map().finish_atomic()?;
+        self.pruned_map().finish_atomic()?;
+        self.pruned_path_map().finish_atomic()?;
+        self.ledger_digest_map().finish_atomic()?;
+        match self.block_index_map() {
+            Some(block_index_map) => block_index_map.finish_atomic(),
+            None => Ok(()),
+        }
     }
 
-    /// Stores the given `(state root, block)` pair into storage.
-    fn insert(&self, state_root: N::StateRoot, block: &Block<N>) -> Result<()> {
+    /// Stores the given `(state root, block)` pair into storage, along with the status of each transaction.
+    fn insert(&self, state_root: N::StateRoot, block: &Block<N>, statuses: &[TransactionStatus]) -> Result<()> {
+        // Ensure there is a status for each transaction.
+        ensure!(
+            statuses.len() == block.transactions().len(),
+            "Expected {} transaction statuses for block {}, found {}",
+            block.transactions().len(),
+            block.height(),
+            statuses.len()
+        );
+
         atomic_write_batch!(self, {
             // Store the (block height, state root) pair.
             self.state_root_map().insert(block.height(), state_root)?;
@@ -194,11 +368,16 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             self.transactions_map().insert(block.hash(), block.transaction_ids().copied().collect())?;
 
             // Store the block transactions.
-            for transaction in block.transactions().values() {
+            for (transaction, status) in block.transactions().values().zip_eq(statuses) {
                 // Store the reverse transaction ID.
                 self.reverse_transactions_map().insert(transaction.id(), block.hash())?;
-                // Store the transaction.
-                self.transaction_store().insert(transaction)?;
+                // Store the transaction status.
+                self.transaction_status_map().insert(transaction.id(), *status)?;
+                // Store the transaction, keeping only the fee transition of an aborted transaction.
+                match status.is_accepted() {
+                    true => self.transaction_store().insert(transaction)?,
+                    false => self.transaction_store().insert_aborted(transaction)?,
+                }
             }
 
             // Store the block coinbase solution.
@@ -214,7 +393,34 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             // Store the block signature.
             self.signature_map().insert(block.hash(), *block.signature())?;
 
-            Ok(())
+            // Update the ledger digest.
+            let ledger_digest =
+                self.get_ledger_digest_speculative()? + to_ledger_digest(block.transactions(), statuses)?;
+            self.ledger_digest_map().insert((), ledger_digest)?;
+
+            // Index the block by the program IDs that it deploys or executes.
+            self.index_block(block.hash(), to_program_ids(block.transactions().values()))
+        });
+
+        Ok(())
+    }
+
+    /// Stores the given `(state root, block hash)` pair for the given block height, without the block itself.
+    /// The block is marked as pruned, as its transactions are not in storage.
+    /// Note: This is used to bootstrap the storage from a snapshot of the consensus state.
+    fn insert_block_hash(&self, state_root: N::StateRoot, block_height: u32, block_hash: N::BlockHash) -> Result<()> {
+        atomic_write_batch!(self, {
+            // Store the (block height, state root) pair.
+            self.state_root_map().insert(block_height, state_root)?;
+            // Store the (state root, block height) pair.
+            self.reverse_state_root_map().insert(state_root, block_height)?;
+
+            // Store the block hash.
+            self.id_map().insert(block_height, block_hash)?;
+            // Store the block height.
+            self.reverse_id_map().insert(block_hash, block_height)?;
+            // Mark the block as pruned.
+            self.pruned_map().insert(block_hash, block_height)
         });
 
         Ok(())
@@ -227,6 +433,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(height) => height,
             None => bail!("Failed to remove block: missing block height for block hash '{block_hash}'"),
         };
+        // Ensure the block has not been pruned.
+        self.ensure_not_pruned(block_hash)?;
         // Retrieve the state root.
         let state_root = match self.state_root_map().get(&block_height)? {
             Some(state_root) => cow_to_copied!(state_root),
@@ -237,6 +445,25 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(transaction_ids) => transaction_ids,
             None => bail!("Failed to remove block: missing transactions for block '{block_height}' ('{block_hash}')"),
         };
+        // Retrieve the transactions.
+        let transactions = match self.get_block_transactions(block_hash)? {
+            Some(transactions) => transactions,
+            None => bail!("Failed to remove block: missing transactions for block '{block_height}'"),
+        };
+        // Retrieve the program IDs that the block deploys or executes, if the storage maintains the index.
+        let program_ids = match self.block_index_map().is_some() {
+            true => to_program_ids(transactions.values()),
+            false => IndexSet::new(),
+        };
+        // Compute the ledger digest of the block, from the status of each transaction.
+        let statuses = transaction_ids
+            .iter()
+            .map(|transaction_id| match self.get_transaction_status(transaction_id)? {
+                Some(status) => Ok(status),
+                None => bail!("Failed to remove block: missing status for transaction '{transaction_id}'"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let block_digest = to_ledger_digest(&transactions, &statuses)?;
         // Retrieve the coinbase solution.
         let coinbase = match self.coinbase_solution_map().get(block_hash)? {
             Some(coinbase_solution) => cow_to_cloned!(coinbase_solution),
@@ -265,6 +492,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             for transaction_id in transaction_ids.iter() {
                 // Remove the reverse transaction ID.
                 self.reverse_transactions_map().remove(transaction_id)?;
+                // Remove the transaction status.
+                self.transaction_status_map().remove(transaction_id)?;
                 // Remove the transaction.
                 self.transaction_store().remove(transaction_id)?;
             }
@@ -282,12 +511,127 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             // Remove the block signature.
             self.signature_map().remove(block_hash)?;
 
-            Ok(())
+            // Update the ledger digest.
+            self.ledger_digest_map().insert((), self.get_ledger_digest_speculative()? - block_digest)?;
+
+            // Remove the block from the block index.
+            self.unindex_block(block_hash, program_ids)
         });
 
         Ok(())
     }
 
+    /// Prunes the transactions of the block for the given `block hash`. The block header, the serial numbers and
+    /// commitments of the transactions, and the transaction and transition paths of each commitment are kept in
+    /// storage, so that its state path can be rebuilt. Deployment transactions are kept in full, as they are required
+    /// to load the programs.
+    fn prune(&self, block_hash: &N::BlockHash) -> Result<()> {
+        // If the block has already been pruned, return early.
+        if self.pruned_map().contains_key(block_hash)? {
+            return Ok(());
+        }
+        // Retrieve the block.
+        let block = match self.get_block(block_hash)? {
+            Some(block) => block,
+            None => bail!("Failed to prune block: missing block '{block_hash}'"),
+        };
+        // Retrieve the execution transactions.
+        let executions: Vec<_> = block
+            .transactions()
+            .values()
+            .filter(|transaction| matches!(transaction, Transaction::Execute(..)))
+            .collect();
+
+        // Construct the pruned path for each commitment.
+        let mut pruned_paths = Vec::new();
+        for transaction in &executions {
+            // Retrieve the transaction status.
+            let status = match self.get_transaction_status(&transaction.id())? {
+                Some(status) => status,
+                None => bail!("Failed to prune block: missing status for transaction '{}'", transaction.id()),
+            };
+            // Skip the non-fee transitions of an aborted transaction, as their commitments are not in storage.
+            for transition in to_ledger_transitions(transaction, status) {
+                for commitment in transition.commitments() {
+                    pruned_paths.push((*commitment, to_pruned_path(*block_hash, transaction, transition, commitment)?));
+                }
+            }
+        }
+
+        atomic_write_batch!(self, {
+            // Store the pruned paths.
+            for (commitment, pruned_path) in pruned_paths {
+                self.pruned_path_map().insert(commitment, pruned_path)?;
+            }
+            // Prune the execution transactions.
+            for transaction in &executions {
+                self.transaction_store().prune(&transaction.id())?;
+            }
+            // Mark the block as pruned.
+            self.pruned_map().insert(*block_hash, block.height())
+        });
+
+        Ok(())
+    }
+
+    /// Converts the state paths that were stored for the pruned commitments into pruned paths.
+    /// Note: Only a persistent storage can hold state paths from before schema version 7, so by default it is a no-op.
+    fn convert_state_paths(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Adds the given `block hash` to the block index, under each of the given `program IDs`.
+    /// If the storage does not maintain the index, this is a no-op.
+    fn index_block(&self, block_hash: N::BlockHash, program_ids: IndexSet<ProgramID<N>>) -> Result<()> {
+        if let Some(block_index_map) = self.block_index_map() {
+            for program_id in program_ids {
+                block_index_map.insert((program_id, block_hash), ())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the given `block hash` from the block index, under each of the given `program IDs`.
+    /// If the storage does not maintain the index, this is a no-op.
+    fn unindex_block(&self, block_hash: &N::BlockHash, program_ids: IndexSet<ProgramID<N>>) -> Result<()> {
+        if let Some(block_index_map) = self.block_index_map() {
+            for program_id in program_ids {
+                block_index_map.remove(&(program_id, *block_hash))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns up to `limit` block hashes for the blocks that deploy or execute the given `program ID`,
+    /// in block hash order. The page starts after the block hash `cursor`, or at the first block hash if `cursor`
+    /// is `None`.
+    fn get_block_hashes_for_program(
+        &self,
+        program_id: &ProgramID<N>,
+        cursor: Option<&N::BlockHash>,
+        limit: usize,
+    ) -> Result<IndexPage<N::BlockHash>> {
+        // Ensure the limit is nonzero.
+        ensure!(limit > 0, "The page limit must be greater than zero");
+        let block_index_map = match self.block_index_map() {
+            Some(block_index_map) => block_index_map,
+            None => bail!("The block storage does not maintain the block index"),
+        };
+        // Retrieve the block hashes for the program ID, with one more to detect a next page.
+        let after = cursor.map(|cursor| (*program_id, *cursor));
+        let entries = block_index_map.get_prefix_range(program_id, after.as_ref(), limit.saturating_add(1))?;
+        let block_hashes = entries.into_iter().map(|(key, _)| key.1).collect();
+        Ok(IndexPage::from_ids(block_hashes, limit))
+    }
+
+    /// Returns a `PrunedError` if the transactions of the block for the given `block hash` have been pruned.
+    fn ensure_not_pruned(&self, block_hash: &N::BlockHash) -> Result<()> {
+        match self.pruned_map().get(block_hash)? {
+            Some(block_height) => Err(PrunedError::new(cow_to_copied!(block_height)).into()),
+            None => Ok(()),
+        }
+    }
+
     /// Returns the block height that contains the given `state root`.
     fn find_block_height_from_state_root(&self, state_root: N::StateRoot) -> Result<Option<u32>> {
         match self.reverse_state_root_map().get(&state_root)? {
@@ -304,6 +648,30 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the ledger digest, as of the latest block.
+    fn get_ledger_digest(&self) -> Result<Group<N>> {
+        match self.ledger_digest_map().get(&())? {
+            Some(ledger_digest) => Ok(cow_to_copied!(ledger_digest)),
+            None => Ok(Group::zero()),
+        }
+    }
+
+    /// Returns the ledger digest, including the pending changes of the atomic batch in progress.
+    fn get_ledger_digest_speculative(&self) -> Result<Group<N>> {
+        match self.ledger_digest_map().get_speculative(&())? {
+            Some(ledger_digest) => Ok(cow_to_copied!(ledger_digest)),
+            None => Ok(Group::zero()),
+        }
+    }
+
+    /// Returns the status of the transaction for the given `transaction ID`.
+    fn get_transaction_status(&self, transaction_id: &N::TransactionID) -> Result<Option<TransactionStatus>> {
+        match self.transaction_status_map().get(transaction_id)? {
+            Some(status) => Ok(Some(cow_to_copied!(status))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the block hash that contains the given `puzzle commitment`.
     fn find_block_hash_from_puzzle_commitment(
         &self,
@@ -330,79 +698,72 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             bail!("Commitment '{commitment}' does not exist");
         }
 
-        // Find the transition that contains the commitment.
-        let transition_id = self.transition_store().find_transition_id(commitment)?;
-        // Find the transaction that contains the transition.
-        let transaction_id = match self.transaction_store().find_transaction_id_from_transition_id(&transition_id)? {
-            Some(transaction_id) => transaction_id,
-            None => bail!("The transaction ID for commitment '{commitment}' is missing in storage"),
-        };
-        // Find the block that contains the transaction.
-        let block_hash = match self.find_block_hash(&transaction_id)? {
-            Some(block_hash) => block_hash,
-            None => bail!("The block hash for commitment '{commitment}' is missing in storage"),
+        // Retrieve the pruned path for the commitment, or construct it if the block is not pruned.
+        let pruned_path = match self.pruned_path_map().get(commitment)? {
+            // If the commitment is in a pruned block, use the pruned path that was stored when the block was pruned.
+            Some(pruned_path) => cow_to_cloned!(pruned_path),
+            None => {
+                // Find the transition that contains the commitment.
+                let transition_id = self.transition_store().find_transition_id(commitment)?;
+                // Find the transaction that contains the transition.
+                let transaction_id =
+                    match self.transaction_store().find_transaction_id_from_transition_id(&transition_id)? {
+                        Some(transaction_id) => transaction_id,
+                        None => bail!("The transaction ID for commitment '{commitment}' is missing in storage"),
+                    };
+                // Find the block that contains the transaction.
+                let block_hash = match self.find_block_hash(&transaction_id)? {
+                    Some(block_hash) => block_hash,
+                    None => bail!("The block hash for commitment '{commitment}' is missing in storage"),
+                };
+
+                // Retrieve the transition.
+                let transition = match self.transition_store().get_transition(&transition_id)? {
+                    Some(transition) => transition,
+                    None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing"),
+                };
+                // Retrieve the transaction.
+                let transaction = match self.transaction_store().get_transaction(&transaction_id)? {
+                    Some(transaction) => transaction,
+                    None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is missing"),
+                };
+
+                // Construct the pruned path.
+                to_pruned_path(block_hash, &transaction, &transition, commitment)?
+            }
         };
 
-        // Retrieve the transition.
-        let transition = match self.transition_store().get_transition(&transition_id)? {
-            Some(transition) => transition,
-            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
+        // Retrieve the block height, header, and transaction IDs.
+        let block_hash = pruned_path.0;
+        let block_height = match self.get_block_height(&block_hash)? {
+            Some(block_height) => block_height,
+            None => bail!("The block height for commitment '{commitment}' is missing in storage"),
         };
-        // Retrieve the block.
-        let block = match self.get_block(&block_hash)? {
-            Some(block) => block,
-            None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
+        let block_header = match self.get_block_header(&block_hash)? {
+            Some(block_header) => block_header,
+            None => bail!("The block header for commitment '{commitment}' is missing in storage"),
+        };
+        let transaction_ids = match self.transactions_map().get(&block_hash)? {
+            Some(transaction_ids) => transaction_ids,
+            None => bail!("The transaction IDs for commitment '{commitment}' are missing in storage"),
+        };
+        // Retrieve the previous block hash.
+        let previous_hash = match self.get_previous_block_hash(block_height)? {
+            Some(previous_hash) => previous_hash,
+            None => bail!("The previous block hash for commitment '{commitment}' is missing in storage"),
         };
 
         // Construct the global state root and block path.
         let global_state_root = *block_tree.root();
-        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+        let block_path = block_tree.prove(block_height as usize, &block_hash.to_bits_le())?;
 
         // Ensure the global state root exists in storage.
         if !self.reverse_state_root_map().contains_key(&global_state_root.into())? {
-            bail!("The global state root '{global_state_root}' for commitment '{commitment}' is missing in storage");
+            bail!("The global state root '{global_state_root}' for commitment '{commitment}' is missing");
         }
 
-        // Construct the transition path and transaction leaf.
-        let transition_leaf = transition.to_leaf(commitment, false)?;
-        let transition_path = transition.to_path(&transition_leaf)?;
-
-        // Construct the transactions path.
-        let transactions = block.transactions();
-        let transactions_path = match transactions.to_path(transaction_id) {
-            Ok(transactions_path) => transactions_path,
-            Err(_) => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
-        };
-
-        // Construct the transaction path and transaction leaf.
-        let transaction = match transactions.get(&transaction_id) {
-            Some(transaction) => transaction,
-            None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
-        };
-        let transaction_leaf = transaction.to_leaf(transition.id())?;
-        let transaction_path = transaction.to_path(&transaction_leaf)?;
-
-        // Construct the block header path.
-        let block_header = block.header();
-        let header_root = block_header.to_root()?;
-        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
-        let header_path = block_header.to_path(&header_leaf)?;
-
-        Ok(StatePath::from(
-            global_state_root.into(),
-            block_path,
-            block.hash(),
-            block.previous_hash(),
-            header_root,
-            header_path,
-            header_leaf,
-            transactions_path,
-            transaction.id(),
-            transaction_path,
-            transaction_leaf,
-            transition_path,
-            transition_leaf,
-        ))
+        // Construct the state path.
+        to_state_path(global_state_root.into(), block_path, previous_hash, &block_header, &transaction_ids, pruned_path)
     }
 
     /// Returns the previous block hash of the given `block height`.
@@ -442,6 +803,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns the block transactions for the given `block hash`.
     fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
+        // Ensure the block has not been pruned.
+        self.ensure_not_pruned(block_hash)?;
         // Retrieve the transaction IDs.
         let transaction_ids = match self.transactions_map().get(block_hash)? {
             Some(transaction_ids) => transaction_ids,
@@ -460,6 +823,21 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(Some(Transactions::from(&transactions)))
     }
 
+    /// Returns the transaction for the given `transaction ID`.
+    fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        match self.transaction_store().get_transaction(transaction_id)? {
+            Some(transaction) => Ok(Some(transaction)),
+            // If the transaction is in a block, ensure the block has not been pruned.
+            None => match self.find_block_hash(transaction_id)? {
+                Some(block_hash) => {
+                    self.ensure_not_pruned(&block_hash)?;
+                    bail_with_block!("Missing transaction '{transaction_id}'", self, block_hash)
+                }
+                None => Ok(None),
+            },
+        }
+    }
+
     /// Returns the block coinbase solution for the given `block hash`.
     fn get_block_coinbase(&self, block_hash: &N::BlockHash) -> Result<Option<CoinbaseSolution<N>>> {
         match self.coinbase_solution_map().get(block_hash)? {
@@ -483,6 +861,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(height) => height,
             None => return Ok(None),
         };
+        // Ensure the block has not been pruned.
+        self.ensure_not_pruned(block_hash)?;
 
         // Retrieve the block header.
         let header = match self.get_block_header(block_hash)? {
@@ -537,6 +917,8 @@ pub struct BlockMemory<N: Network> {
     transactions_map: MemoryMap<N::BlockHash, Vec<N::TransactionID>>,
     /// The reverse transactions map.
     reverse_transactions_map: MemoryMap<N::TransactionID, N::BlockHash>,
+    /// The transaction status map.
+    transaction_status_map: MemoryMap<N::TransactionID, TransactionStatus>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
     /// The coinbase solution map.
@@ -545,6 +927,14 @@ pub struct BlockMemory<N: Network> {
     coinbase_puzzle_commitment_map: MemoryMap<PuzzleCommitment<N>, N::BlockHash>,
     /// The signature map.
     signature_map: MemoryMap<N::BlockHash, Signature<N>>,
+    /// The pruned map.
+    pruned_map: MemoryMap<N::BlockHash, u32>,
+    /// The pruned path map.
+    pruned_path_map: MemoryMap<Field<N>, PrunedPath<N>>,
+    /// The block index map.
+    block_index_map: MemoryMap<(ProgramID<N>, N::BlockHash), ()>,
+    /// The ledger digest map.
+    ledger_digest_map: MemoryMap<(), Group<N>>,
 }
 
 #[rustfmt::skip]
@@ -556,11 +946,16 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type HeaderMap = MemoryMap<N::BlockHash, Header<N>>;
     type TransactionsMap = MemoryMap<N::BlockHash, Vec<N::TransactionID>>;
     type ReverseTransactionsMap = MemoryMap<N::TransactionID, N::BlockHash>;
+    type TransactionStatusMap = MemoryMap<N::TransactionID, TransactionStatus>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
     type CoinbaseSolutionMap = MemoryMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type CoinbasePuzzleCommitmentMap = MemoryMap<PuzzleCommitment<N>, N::BlockHash>;
     type SignatureMap = MemoryMap<N::BlockHash, Signature<N>>;
+    type PrunedMap = MemoryMap<N::BlockHash, u32>;
+    type PrunedPathMap = MemoryMap<Field<N>, PrunedPath<N>>;
+    type BlockIndexMap = MemoryMap<(ProgramID<N>, N::BlockHash), ()>;
+    type LedgerDigestMap = MemoryMap<(), Group<N>>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -577,10 +972,39 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             header_map: MemoryMap::default(),
             transactions_map: MemoryMap::default(),
             reverse_transactions_map: MemoryMap::default(),
+            transaction_status_map: MemoryMap::default(),
             transaction_store,
             coinbase_solution_map: MemoryMap::default(),
             coinbase_puzzle_commitment_map: MemoryMap::default(),
             signature_map: MemoryMap::default(),
+            pruned_map: MemoryMap::default(),
+            pruned_path_map: MemoryMap::default(),
+            block_index_map: MemoryMap::default(),
+            ledger_digest_map: MemoryMap::default(),
+        })
+    }
+
+    /// Returns a read-only view of the block storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self> {
+        // Retrieve a view of the transition store, which is shared by the transaction store.
+        let transition_store = self.transaction_store.transition_store().read_view()?;
+        Ok(Self {
+            state_root_map: self.state_root_map.read_view()?,
+            reverse_state_root_map: self.reverse_state_root_map.read_view()?,
+            id_map: self.id_map.read_view()?,
+            reverse_id_map: self.reverse_id_map.read_view()?,
+            header_map: self.header_map.read_view()?,
+            transactions_map: self.transactions_map.read_view()?,
+            reverse_transactions_map: self.reverse_transactions_map.read_view()?,
+            transaction_status_map: self.transaction_status_map.read_view()?,
+            transaction_store: self.transaction_store.read_view(transition_store)?,
+            coinbase_solution_map: self.coinbase_solution_map.read_view()?,
+            coinbase_puzzle_commitment_map: self.coinbase_puzzle_commitment_map.read_view()?,
+            signature_map: self.signature_map.read_view()?,
+            pruned_map: self.pruned_map.read_view()?,
+            pruned_path_map: self.pruned_path_map.read_view()?,
+            block_index_map: self.block_index_map.read_view()?,
+            ledger_digest_map: self.ledger_digest_map.read_view()?,
         })
     }
 
@@ -619,6 +1043,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.reverse_transactions_map
     }
 
+    /// Returns the transaction status map.
+    fn transaction_status_map(&self) -> &Self::TransactionStatusMap {
+        &self.transaction_status_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -638,6 +1067,26 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     fn signature_map(&self) -> &Self::SignatureMap {
         &self.signature_map
     }
+
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
+    /// Returns the pruned path map.
+    fn pruned_path_map(&self) -> &Self::PrunedPathMap {
+        &self.pruned_path_map
+    }
+
+    /// Returns the block index map.
+    fn block_index_map(&self) -> Option<&Self::BlockIndexMap> {
+        Some(&self.block_index_map)
+    }
+
+    /// Returns the ledger digest map.
+    fn ledger_digest_map(&self) -> &Self::LedgerDigestMap {
+        &self.ledger_digest_map
+    }
 }
 
 /// The block store.
@@ -645,8 +1094,10 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
 pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     /// The block storage.
     storage: B,
-    /// The block tree.
-    tree: Arc<RwLock<BlockTree<N>>>,
+    /// The block tree, which is shared with any read views.
+    tree: Arc<RwLock<Arc<BlockTree<N>>>>,
+    /// The number of most recent blocks to keep in full, if pruning is enabled.
+    prune_depth: Arc<RwLock<Option<u32>>>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -670,15 +1121,34 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
                 None => vec![],
             };
             // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
+            Arc::new(RwLock::new(Arc::new(N::merkle_tree_bhp(&hashes)?)))
         };
 
         // Return the block store.
-        Ok(Self { storage, tree })
+        Ok(Self { storage, tree, prune_depth: Default::default() })
+    }
+
+    /// Returns a read-only view of the block store, as of the last finished atomic batch.
+    /// The view is unaffected by any later writes to the block store, and any writes to the view fail.
+    pub fn read_view(&self) -> Result<Self> {
+        // Acquire the read lock on the block tree, so that no block is inserted or removed while the view is taken.
+        let tree = self.tree.read();
+        // Return the view.
+        // Note: The block tree is shared with the view, as each write replaces the block tree instead of mutating it.
+        Ok(Self {
+            storage: self.storage.read_view()?,
+            tree: Arc::new(RwLock::new(Arc::clone(&*tree))),
+            prune_depth: Default::default(),
+        })
     }
 
-    /// Stores the given block into storage.
+    /// Stores the given block into storage, with every transaction in the block accepted.
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
+        self.insert_with_statuses(block, &vec![TransactionStatus::Accepted; block.transactions().len()])
+    }
+
+    /// Stores the given block into storage, along with the status of each transaction in the block.
+    pub fn insert_with_statuses(&self, block: &Block<N>, statuses: &[TransactionStatus]) -> Result<()> {
         // Acquire the write lock on the block tree.
         let mut tree = self.tree.write();
         // Prepare an updated Merkle tree containing the new block hash.
@@ -687,10 +1157,74 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+
+        atomic_write_batch!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block, statuses)?;
+            // Prune the blocks that are now beyond the prune depth.
+            self.prune(block.height())
+        });
+
+        // Update the block tree.
+        *tree = Arc::new(updated_tree);
+        // Return success.
+        Ok(())
+    }
+
+    /// Stores the given block into an empty block store, along with the hashes of all preceding blocks,
+    /// the status of each transaction in the block, and the ledger digest as of the block.
+    /// Note: The preceding blocks are not stored, only their block hashes and state roots are.
+    pub fn bootstrap(
+        &self,
+        previous_hashes: &[N::BlockHash],
+        block: &Block<N>,
+        statuses: &[TransactionStatus],
+        ledger_digest: Group<N>,
+    ) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Ensure the block store is empty.
+        ensure!(tree.number_of_leaves() == 0, "Cannot bootstrap a block store that already contains blocks");
+        // Ensure there is a block hash for every preceding block.
+        ensure!(
+            previous_hashes.len() == block.height() as usize,
+            "Expected {} preceding block hashes to bootstrap block {}, found {}",
+            block.height(),
+            block.height(),
+            previous_hashes.len()
+        );
+
+        // Compute the block tree and state root for each preceding block.
+        let mut updated_tree: BlockTree<N> = N::merkle_tree_bhp(&[])?;
+        let mut state_roots: Vec<N::StateRoot> = Vec::with_capacity(previous_hashes.len());
+        for block_hash in previous_hashes {
+            updated_tree.append(&[block_hash.to_bits_le()])?;
+            state_roots.push((*updated_tree.root()).into());
+        }
+        // Ensure the preceding block hashes match the block header.
+        if let Some(previous_hash) = previous_hashes.last() {
+            ensure!(*previous_hash == block.previous_hash(), "The previous block hash does not match the block");
+            ensure!(
+                *updated_tree.root() == block.previous_state_root(),
+                "The preceding block hashes do not match the previous state root of the block"
+            );
+        }
+        // Append the new block hash to the block tree.
+        updated_tree.append(&[block.hash().to_bits_le()])?;
+
+        atomic_write_batch!(self, {
+            // Store the block hashes and state roots of the preceding blocks.
+            for (block_height, (block_hash, state_root)) in previous_hashes.iter().zip(state_roots).enumerate() {
+                self.storage.insert_block_hash(state_root, u32::try_from(block_height)?, *block_hash)?;
+            }
+            // Store the block.
+            self.storage.insert((*updated_tree.root()).into(), block, statuses)?;
+            // Store the ledger digest, which includes the records and deployments of the preceding blocks.
+            self.storage.ledger_digest_map().insert((), ledger_digest)
+        });
+
         // Update the block tree.
-        *tree = updated_tree;
+        *tree = Arc::new(updated_tree);
         // Return success.
         Ok(())
     }
@@ -740,11 +1274,173 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         });
 
         // Update the block tree.
-        *tree = updated_tree;
+        *tree = Arc::new(updated_tree);
         // Return success.
         Ok(())
     }
 
+    /// Prunes the transactions of the blocks that are more than `prune depth` blocks below the given block height.
+    /// Pruning stops at the first block that is already pruned.
+    fn prune(&self, block_height: u32) -> Result<()> {
+        // Retrieve the prune depth.
+        let prune_depth = match *self.prune_depth.read() {
+            Some(prune_depth) => prune_depth,
+            None => return Ok(()),
+        };
+        // Determine the latest block height to prune.
+        let end_height = match block_height.checked_sub(prune_depth.saturating_add(1)) {
+            Some(end_height) => end_height,
+            None => return Ok(()),
+        };
+
+        // Determine the block hashes to prune, in descending order.
+        let mut hashes = Vec::new();
+        for height in (0..=end_height).rev() {
+            let block_hash = match self.storage.get_block_hash(height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Failed to prune block {height}: missing block hash"),
+            };
+            if self.storage.pruned_map().contains_key(&block_hash)? {
+                break;
+            }
+            hashes.push(block_hash);
+        }
+
+        // Prune the blocks, in ascending order.
+        for block_hash in hashes.iter().rev() {
+            self.storage.prune(block_hash)?;
+        }
+        Ok(())
+    }
+
+    /// Marks the blocks whose block header is not in storage as pruned.
+    /// Note: This migrates storage from schema version 0, in which the blocks that
+    /// preceded a bootstrapped block were stored without being marked as pruned.
+    pub fn mark_headerless_blocks_as_pruned(&self) -> Result<()> {
+        atomic_write_batch!(self, {
+            for (height, block_hash) in self.storage.id_map().iter() {
+                // If the block header is missing, mark the block as pruned.
+                if !self.storage.header_map().contains_key(&block_hash)? {
+                    self.storage.pruned_map().insert(cow_to_copied!(block_hash), cow_to_copied!(height))?;
+                }
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
+    /// Stores the status of every transaction in storage as accepted.
+    /// Note: This migrates storage from schema version 2, which predates the transaction statuses.
+    /// As a block was rejected as a whole if any of its transactions failed to finalize,
+    /// every transaction in storage was accepted.
+    pub fn mark_transactions_as_accepted(&self) -> Result<()> {
+        atomic_write_batch!(self, {
+            for transaction_id in self.storage.reverse_transactions_map().keys() {
+                self.storage.transaction_status_map().insert(*transaction_id, TransactionStatus::Accepted)?;
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
+    /// Stores each aborted transaction with only its fee transition, removing its other transitions from storage.
+    /// Note: This migrates storage from schema version 3, in which aborted transactions were stored in full.
+    /// The aborted transactions of pruned blocks are no longer in storage, so they are left unchanged.
+    pub fn prune_aborted_transactions(&self) -> Result<()> {
+        atomic_write_batch!(self, {
+            for (transaction_id, status) in self.storage.transaction_status_map().iter() {
+                // Skip the accepted transactions.
+                if status.is_accepted() {
+                    continue;
+                }
+                // Retrieve the transaction, skipping it if its block was pruned.
+                let transaction = match self.storage.transaction_store().get_transaction(&transaction_id)? {
+                    Some(transaction) => transaction,
+                    None => continue,
+                };
+                // Store the transaction again, with only its fee transition.
+                self.storage.transaction_store().remove(&transaction_id)?;
+                self.storage.transaction_store().insert_aborted(&transaction)?;
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
+    /// Computes the ledger digest from the spent records, created records, and deployments in storage.
+    /// Note: This migrates storage from schema version 4, which predates the ledger digest.
+    pub fn build_ledger_digest(&self) -> Result<()> {
+        let mut ledger_digest = Group::zero();
+        // Add the spent records.
+        for (serial_number, tag) in self.transition_store().serial_numbers_with_tags() {
+            ledger_digest += to_serial_number_digest(&serial_number, &tag)?;
+        }
+        // Add the created records.
+        for (commitment, output) in self.transition_store().commitments_with_records() {
+            ledger_digest += to_commitment_digest(&commitment, &output.0)?;
+        }
+        // Add the deployments.
+        for transaction_id in self.storage.transaction_store().deployment_transaction_ids() {
+            ledger_digest += to_deployment_digest::<N>(&transaction_id)?;
+        }
+        // Store the ledger digest.
+        self.storage.ledger_digest_map().insert((), ledger_digest)
+    }
+
+    /// Builds the block index and the function index from the blocks in storage, in ascending order.
+    /// The transitions of pruned blocks are not in storage, so only their deployments are indexed.
+    /// Note: This migrates storage from schema version 1, which predates the indices.
+    pub fn build_indices(&self) -> Result<()> {
+        // Retrieve the block hashes, in ascending order.
+        let mut hashes: Vec<_> = self.storage.id_map().iter().map(|(h, hash)| (*h, cow_to_copied!(hash))).collect();
+        hashes.sort_unstable_by_key(|(height, _)| *height);
+
+        atomic_write_batch!(self, {
+            for (_, block_hash) in hashes {
+                // Retrieve the transaction IDs. Bootstrapped blocks have no transactions in storage.
+                let transaction_ids = match self.storage.transactions_map().get(&block_hash)? {
+                    Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+                    None => continue,
+                };
+                // Retrieve the transactions that are in storage.
+                let mut transactions = Vec::with_capacity(transaction_ids.len());
+                for transaction_id in &transaction_ids {
+                    if let Some(transaction) = self.storage.transaction_store().get_transaction(transaction_id)? {
+                        transactions.push(transaction);
+                    }
+                }
+                // Index the transitions by their program ID and function name.
+                for transition in transactions.iter().flat_map(Transaction::transitions) {
+                    let locator = (*transition.program_id(), *transition.function_name());
+                    self.storage.transition_store().index_transition(*transition.id(), locator)?;
+                }
+                // Index the block by the program IDs that it deploys or executes.
+                self.storage.index_block(block_hash, to_program_ids(transactions.iter()))?;
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
+    /// Converts the full state paths that were stored for the pruned commitments into pruned paths,
+    /// which only hold the transaction and transition paths of each commitment.
+    /// Note: This migrates storage from schema version 6, in which a full state path was stored for each commitment.
+    pub fn convert_state_paths(&self) -> Result<()> {
+        self.storage.convert_state_paths()
+    }
+
+    /// Returns the number of most recent blocks that are kept in full, if pruning is enabled.
+    pub fn prune_depth(&self) -> Option<u32> {
+        *self.prune_depth.read()
+    }
+
+    /// Sets the number of most recent blocks to keep in full, or disables pruning if `None`.
+    /// When a block is inserted, the transactions of every block that is more than `prune depth` blocks
+    /// below it are pruned, while the serial numbers, commitments, and block headers are kept.
+    pub fn set_prune_depth(&self, prune_depth: Option<u32>) {
+        *self.prune_depth.write() = prune_depth;
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -772,7 +1468,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
@@ -792,6 +1488,16 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.find_block_hash(transaction_id)
     }
 
+    /// Returns the ledger digest, as of the latest block.
+    pub fn get_ledger_digest(&self) -> Result<Group<N>> {
+        self.storage.get_ledger_digest()
+    }
+
+    /// Returns the status of the transaction for the given `transaction ID`.
+    pub fn get_transaction_status(&self, transaction_id: &N::TransactionID) -> Result<Option<TransactionStatus>> {
+        self.storage.get_transaction_status(transaction_id)
+    }
+
     /// Returns the block hash that contains the given `puzzle commitment`.
     pub fn find_block_hash_from_puzzle_commitment(
         &self,
@@ -842,6 +1548,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block_transactions(block_hash)
     }
 
+    /// Returns the transaction for the given `transaction ID`.
+    pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.storage.get_transaction(transaction_id)
+    }
+
     /// Returns the block coinbase solution for the given `block hash`.
     pub fn get_block_coinbase(&self, block_hash: &N::BlockHash) -> Result<Option<CoinbaseSolution<N>>> {
         self.storage.get_block_coinbase(block_hash)
@@ -861,6 +1572,19 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
     }
+
+    /// Returns up to `limit` block hashes for the blocks that deploy or execute the given `program ID`,
+    /// in block hash order. The page starts after the block hash `cursor`, or at the first block hash if `cursor`
+    /// is `None`.
+    /// To retrieve the next page, pass in the `next_cursor` of the returned page.
+    pub fn get_block_hashes_for_program(
+        &self,
+        program_id: &ProgramID<N>,
+        cursor: Option<&N::BlockHash>,
+        limit: usize,
+    ) -> Result<IndexPage<N::BlockHash>> {
+        self.storage.get_block_hashes_for_program(program_id, cursor, limit)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -879,6 +1603,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.reverse_id_map().contains_key(block_hash)
     }
 
+    /// Returns `true` if the transactions of the block for the given `block hash` have been pruned.
+    pub fn is_pruned(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        self.storage.pruned_map().contains_key(block_hash)
+    }
+
     /// Returns `true` if the given puzzle commitment exists.
     pub fn contains_puzzle_commitment(&self, puzzle_commitment: &PuzzleCommitment<N>) -> Result<bool> {
         self.storage.coinbase_puzzle_commitment_map().contains_key(puzzle_commitment)
@@ -910,6 +1639,9 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::store::helpers::memory_map::test_helpers::FailingMap;
+
+    type CurrentNetwork = crate::vm::test_helpers::CurrentNetwork;
 
     #[test]
     fn test_insert_get_remove() {
@@ -941,6 +1673,134 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_transaction_statuses() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let transaction_ids = block.transaction_ids().copied().collect::<Vec<_>>();
+        assert!(!transaction_ids.is_empty());
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Ensure the block cannot be inserted without a status for each transaction.
+        assert!(block_store.insert_with_statuses(&block, &[]).is_err());
+
+        // Insert the block, with the first transaction aborted.
+        let mut statuses = vec![TransactionStatus::Accepted; transaction_ids.len()];
+        statuses[0] = TransactionStatus::Aborted;
+        block_store.insert_with_statuses(&block, &statuses).unwrap();
+
+        // Ensure the statuses are stored.
+        for (transaction_id, status) in transaction_ids.iter().zip_eq(&statuses) {
+            assert_eq!(Some(*status), block_store.get_transaction_status(transaction_id).unwrap());
+        }
+
+        // Ensure only the fee transition of the aborted transaction is in the ledger.
+        let aborted = block.transactions().values().next().unwrap();
+        let fee_id = aborted.fee_transition().map(|fee| *fee.transition_id());
+        for transition in aborted.transitions().filter(|transition| Some(*transition.id()) != fee_id) {
+            assert!(!block_store.transition_store().contains_transition_id(transition.id()).unwrap());
+            for commitment in transition.commitments() {
+                assert!(!block_store.transition_store().contains_commitment(commitment).unwrap());
+            }
+        }
+        // Ensure the aborted transaction is still in the block.
+        assert_eq!(Some(block.clone()), block_store.get_block(&block.hash()).unwrap());
+
+        // Remove the block, and ensure the statuses are removed.
+        block_store.remove_last_n(1).unwrap();
+        for transaction_id in &transaction_ids {
+            assert_eq!(None, block_store.get_transaction_status(transaction_id).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_prune_aborted_transactions() {
+        let mut rng = TestRng::default();
+
+        // Sample the transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_fee(&mut rng);
+        let transaction_id = transaction.id();
+        let fee_id = *transaction.fee_transition().unwrap().transition_id();
+
+        // Initialize a new block store, with the aborted transaction stored in full.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        block_store.storage.transaction_store().insert(&transaction).unwrap();
+        block_store.storage.transaction_status_map().insert(transaction_id, TransactionStatus::Aborted).unwrap();
+
+        // Migrate the storage.
+        block_store.prune_aborted_transactions().unwrap();
+
+        // Ensure only the fee transition of the aborted transaction is in storage.
+        for transition in transaction.transitions() {
+            let is_fee = *transition.id() == fee_id;
+            assert_eq!(is_fee, block_store.transition_store().contains_transition_id(transition.id()).unwrap());
+            for commitment in transition.commitments() {
+                assert_eq!(is_fee, block_store.transition_store().contains_commitment(commitment).unwrap());
+            }
+        }
+        // Ensure the aborted transaction is still in storage.
+        let candidate = block_store.storage.transaction_store().get_transaction(&transaction_id).unwrap();
+        assert_eq!(Some(transaction), candidate);
+    }
+
+    #[test]
+    fn test_block_index() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let block_hash = block.hash();
+        let transitions: Vec<_> = block.transactions().values().flat_map(Transaction::transitions).collect();
+        assert!(!transitions.is_empty());
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let unknown_id = ProgramID::from_str("unknown.aleo").unwrap();
+
+        // Ensure the block is not indexed.
+        assert!(block_store.get_block_hashes_for_program(&program_id, None, 10).unwrap().items().is_empty());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the block is indexed by the program ID, and that the transitions are indexed by their function.
+        let check_indices = || {
+            let page = block_store.get_block_hashes_for_program(&program_id, None, 10).unwrap();
+            assert_eq!(page.items(), &[block_hash]);
+            assert!(block_store.get_block_hashes_for_program(&unknown_id, None, 10).unwrap().items().is_empty());
+            for transition in &transitions {
+                let page = block_store
+                    .transition_store()
+                    .get_transition_ids_for_function(transition.program_id(), transition.function_name(), None, 10)
+                    .unwrap();
+                assert!(page.items().contains(transition.id()));
+            }
+        };
+        check_indices();
+
+        // Ensure rebuilding the indices leaves them unchanged.
+        block_store.build_indices().unwrap();
+        check_indices();
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the block and its transitions are no longer indexed.
+        assert!(block_store.get_block_hashes_for_program(&program_id, None, 10).unwrap().items().is_empty());
+        for transition in &transitions {
+            let page = block_store
+                .transition_store()
+                .get_transition_ids_for_function(transition.program_id(), transition.function_name(), None, 10)
+                .unwrap();
+            assert!(page.items().is_empty());
+        }
+    }
+
     #[test]
     fn test_find_block_hash() {
         let mut rng = TestRng::default();
@@ -981,4 +1841,328 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    /// An in-memory block storage, whose maps fail to finish an atomic batch when a failure is injected.
+    #[derive(Clone)]
+    struct FailingBlockMemory<N: Network> {
+        /// The mapping of `block height` to `state root`.
+        state_root_map: FailingMap<u32, N::StateRoot>,
+        /// The mapping of `state root` to `block height`.
+        reverse_state_root_map: FailingMap<N::StateRoot, u32>,
+        /// The mapping of `block height` to `block hash`.
+        id_map: FailingMap<u32, N::BlockHash>,
+        /// The mapping of `block hash` to `block height`.
+        reverse_id_map: FailingMap<N::BlockHash, u32>,
+        /// The header map.
+        header_map: FailingMap<N::BlockHash, Header<N>>,
+        /// The transactions map.
+        transactions_map: FailingMap<N::BlockHash, Vec<N::TransactionID>>,
+        /// The reverse transactions map.
+        reverse_transactions_map: FailingMap<N::TransactionID, N::BlockHash>,
+        /// The transaction status map.
+        transaction_status_map: FailingMap<N::TransactionID, TransactionStatus>,
+        /// The transaction store.
+        transaction_store: TransactionStore<N, TransactionMemory<N>>,
+        /// The coinbase solution map.
+        coinbase_solution_map: FailingMap<N::BlockHash, Option<CoinbaseSolution<N>>>,
+        /// The coinbase puzzle commitment map.
+        coinbase_puzzle_commitment_map: FailingMap<PuzzleCommitment<N>, N::BlockHash>,
+        /// The signature map.
+        signature_map: FailingMap<N::BlockHash, Signature<N>>,
+        /// The pruned map.
+        pruned_map: FailingMap<N::BlockHash, u32>,
+        /// The pruned path map.
+        pruned_path_map: FailingMap<Field<N>, PrunedPath<N>>,
+        /// The block index map.
+        block_index_map: FailingMap<(ProgramID<N>, N::BlockHash), ()>,
+        /// The ledger digest map.
+        ledger_digest_map: FailingMap<(), Group<N>>,
+    }
+
+    #[rustfmt::skip]
+    impl<N: Network> BlockStorage<N> for FailingBlockMemory<N> {
+        type StateRootMap = FailingMap<u32, N::StateRoot>;
+        type ReverseStateRootMap = FailingMap<N::StateRoot, u32>;
+        type IDMap = FailingMap<u32, N::BlockHash>;
+        type ReverseIDMap = FailingMap<N::BlockHash, u32>;
+        type HeaderMap = FailingMap<N::BlockHash, Header<N>>;
+        type TransactionsMap = FailingMap<N::BlockHash, Vec<N::TransactionID>>;
+        type ReverseTransactionsMap = FailingMap<N::TransactionID, N::BlockHash>;
+        type TransactionStatusMap = FailingMap<N::TransactionID, TransactionStatus>;
+        type TransactionStorage = TransactionMemory<N>;
+        type TransitionStorage = TransitionMemory<N>;
+        type CoinbaseSolutionMap = FailingMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
+        type CoinbasePuzzleCommitmentMap = FailingMap<PuzzleCommitment<N>, N::BlockHash>;
+        type SignatureMap = FailingMap<N::BlockHash, Signature<N>>;
+        type PrunedMap = FailingMap<N::BlockHash, u32>;
+        type PrunedPathMap = FailingMap<Field<N>, PrunedPath<N>>;
+        type BlockIndexMap = FailingMap<(ProgramID<N>, N::BlockHash), ()>;
+        type LedgerDigestMap = FailingMap<(), Group<N>>;
+
+        /// Initializes the block storage.
+        fn open(dev: Option<u16>) -> Result<Self> {
+            // Initialize the transition store.
+            let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(dev)?;
+            // Initialize the transaction store.
+            let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
+            // Return the block storage.
+            Ok(Self {
+                state_root_map: FailingMap::default(),
+                reverse_state_root_map: FailingMap::default(),
+                id_map: FailingMap::default(),
+                reverse_id_map: FailingMap::default(),
+                header_map: FailingMap::default(),
+                transactions_map: FailingMap::default(),
+                reverse_transactions_map: FailingMap::default(),
+                transaction_status_map: FailingMap::default(),
+                transaction_store,
+                coinbase_solution_map: FailingMap::default(),
+                coinbase_puzzle_commitment_map: FailingMap::default(),
+                signature_map: FailingMap::default(),
+                pruned_map: FailingMap::default(),
+                pruned_path_map: FailingMap::default(),
+                block_index_map: FailingMap::default(),
+                ledger_digest_map: FailingMap::default(),
+            })
+        }
+
+        /// Returns a read-only view of the block storage.
+        /// Note: Read views are not supported by the failing storage.
+        fn read_view(&self) -> Result<Self> {
+            bail!("Read views are not supported by the failing storage")
+        }
+
+        /// Returns the state root map.
+        fn state_root_map(&self) -> &Self::StateRootMap {
+            &self.state_root_map
+        }
+
+        /// Returns the reverse state root map.
+        fn reverse_state_root_map(&self) -> &Self::ReverseStateRootMap {
+            &self.reverse_state_root_map
+        }
+
+        /// Returns the ID map.
+        fn id_map(&self) -> &Self::IDMap {
+            &self.id_map
+        }
+
+        /// Returns the reverse ID map.
+        fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+            &self.reverse_id_map
+        }
+
+        /// Returns the header map.
+        fn header_map(&self) -> &Self::HeaderMap {
+            &self.header_map
+        }
+
+        /// Returns the transactions map.
+        fn transactions_map(&self) -> &Self::TransactionsMap {
+            &self.transactions_map
+        }
+
+        /// Returns the reverse transactions map.
+        fn reverse_transactions_map(&self) -> &Self::ReverseTransactionsMap {
+            &self.reverse_transactions_map
+        }
+
+        /// Returns the transaction status map.
+        fn transaction_status_map(&self) -> &Self::TransactionStatusMap {
+            &self.transaction_status_map
+        }
+
+        /// Returns the transaction store.
+        fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
+            &self.transaction_store
+        }
+
+        /// Returns the coinbase solution map.
+        fn coinbase_solution_map(&self) -> &Self::CoinbaseSolutionMap {
+            &self.coinbase_solution_map
+        }
+
+        /// Returns the coinbase puzzle commitment map.
+        fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap {
+            &self.coinbase_puzzle_commitment_map
+        }
+
+        /// Returns the signature map.
+        fn signature_map(&self) -> &Self::SignatureMap {
+            &self.signature_map
+        }
+
+        /// Returns the pruned map.
+        fn pruned_map(&self) -> &Self::PrunedMap {
+            &self.pruned_map
+        }
+
+        /// Returns the pruned path map.
+        fn pruned_path_map(&self) -> &Self::PrunedPathMap {
+            &self.pruned_path_map
+        }
+
+        /// Returns the block index map.
+        fn block_index_map(&self) -> Option<&Self::BlockIndexMap> {
+            Some(&self.block_index_map)
+        }
+
+        /// Returns the ledger digest map.
+        fn ledger_digest_map(&self) -> &Self::LedgerDigestMap {
+            &self.ledger_digest_map
+        }
+    }
+
+    #[test]
+    fn test_finish_atomic_failure() {
+        use crate::store::helpers::memory_map::test_helpers::{fail_each_finish_atomic, fail_finish_atomic_after};
+
+        let mut rng = TestRng::default();
+
+        // Sample the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let genesis_hash = genesis.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, FailingBlockMemory<_>>::open(None).unwrap();
+        let state_root = block_store.current_state_root();
+
+        // Fail each map of the insertion in turn, until the block is inserted.
+        fail_each_finish_atomic(
+            || block_store.insert(&genesis),
+            || {
+                // Ensure none of the block was inserted.
+                assert!(!block_store.is_atomic_in_progress());
+                assert!(!block_store.contains_block_hash(&genesis_hash).unwrap());
+                assert_eq!(state_root, block_store.current_state_root());
+                assert_eq!(block_store.transaction_store().transaction_ids().count(), 0);
+                assert_eq!(block_store.transition_store().transition_ids().count(), 0);
+                for transaction_id in genesis.transaction_ids() {
+                    assert_eq!(None, block_store.find_block_hash(transaction_id).unwrap());
+                }
+            },
+        );
+        assert_eq!(Some(genesis.clone()), block_store.get_block(&genesis_hash).unwrap());
+        let state_root = block_store.current_state_root();
+
+        // Fail the removal partway, and ensure the block is unchanged.
+        fail_finish_atomic_after(Some(1));
+        assert!(block_store.remove_last_n(1).is_err());
+        fail_finish_atomic_after(None);
+        assert!(!block_store.is_atomic_in_progress());
+        assert_eq!(Some(genesis), block_store.get_block(&genesis_hash).unwrap());
+        assert_eq!(state_root, block_store.current_state_root());
+    }
+
+    #[test]
+    fn test_read_view() {
+        let mut rng = TestRng::default();
+
+        // Sample the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let genesis_hash = genesis.hash();
+
+        // Initialize a new block store, and insert the genesis block.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&genesis).unwrap();
+        let state_root = block_store.current_state_root();
+
+        // Take a view of the block store, and remove the genesis block from the live store.
+        let view = block_store.read_view().unwrap();
+        block_store.remove_last_n(1).unwrap();
+        assert_eq!(None, block_store.get_block(&genesis_hash).unwrap());
+
+        // Ensure the view still contains the genesis block and its state root.
+        assert_eq!(Some(genesis.clone()), view.get_block(&genesis_hash).unwrap());
+        assert_eq!(state_root, view.current_state_root());
+
+        // Ensure a query over the view serves the state root and state paths as of the view.
+        let query = crate::Query::from(&view);
+        assert_eq!(state_root, query.current_state_root().unwrap());
+        for commitment in genesis.commitments() {
+            let state_path = query.get_state_path_for_commitment(commitment).unwrap();
+            assert_eq!(state_root, state_path.global_state_root());
+        }
+
+        // Ensure the view is read-only.
+        assert!(view.insert(&genesis).is_err());
+        assert!(view.remove_last_n(1).is_err());
+        assert_eq!(Some(genesis), view.get_block(&genesis_hash).unwrap());
+    }
+
+    #[test]
+    fn test_prune() {
+        let mut rng = TestRng::default();
+
+        // Sample the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let genesis_hash = genesis.hash();
+
+        // Initialize a new block store, and one that keeps only the latest block in full.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        let pruned_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        pruned_store.set_prune_depth(Some(0));
+        block_store.insert(&genesis).unwrap();
+        pruned_store.insert(&genesis).unwrap();
+        assert!(!pruned_store.is_pruned(&genesis_hash).unwrap());
+
+        // Sample the next block.
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(&mut rng);
+        let transactions = Transactions::from(&[crate::vm::test_helpers::sample_deployment_transaction(&mut rng)]);
+        let metadata = crate::block::Metadata::new(
+            CurrentNetwork::ID,
+            1,
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            0,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            genesis.last_coinbase_target(),
+            genesis.last_coinbase_timestamp(),
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+        )
+        .unwrap();
+        let header = Header::from(
+            *block_store.current_state_root(),
+            transactions.to_root().unwrap(),
+            Field::zero(),
+            Field::zero(),
+            metadata,
+        )
+        .unwrap();
+        let block = Block::new(&private_key, genesis_hash, header, transactions, None, &mut rng).unwrap();
+
+        // Insert the next block, which prunes the genesis block.
+        block_store.insert(&block).unwrap();
+        pruned_store.insert(&block).unwrap();
+        assert!(pruned_store.is_pruned(&genesis_hash).unwrap());
+        assert!(!pruned_store.is_pruned(&block.hash()).unwrap());
+
+        // Ensure queries for the pruned transactions return a pruned error.
+        let is_pruned_error = |error: anyhow::Error| error.downcast_ref::<PrunedError>() == Some(&PrunedError::new(0));
+        assert!(is_pruned_error(pruned_store.get_block(&genesis_hash).unwrap_err()));
+        assert!(is_pruned_error(pruned_store.get_block_transactions(&genesis_hash).unwrap_err()));
+        for transaction_id in genesis.transaction_ids() {
+            assert!(is_pruned_error(pruned_store.get_transaction(transaction_id).unwrap_err()));
+        }
+
+        // Ensure the block header, serial numbers, commitments, and state paths are kept.
+        assert_eq!(Some(*genesis.header()), pruned_store.get_block_header(&genesis_hash).unwrap());
+        assert_eq!(Some(block.clone()), pruned_store.get_block(&block.hash()).unwrap());
+        for serial_number in genesis.serial_numbers() {
+            assert!(pruned_store.transition_store().contains_serial_number(serial_number).unwrap());
+        }
+        for commitment in genesis.commitments() {
+            assert!(pruned_store.transition_store().contains_commitment(commitment).unwrap());
+            assert_eq!(
+                block_store.get_state_path_for_commitment(commitment).unwrap(),
+                pruned_store.get_state_path_for_commitment(commitment).unwrap()
+            );
+        }
+
+        // Ensure the pruned block cannot be removed.
+        assert!(pruned_store.remove_last_n(2).is_err());
+        pruned_store.remove_last_n(1).unwrap();
+        assert!(pruned_store.get_block_header(&genesis_hash).unwrap().is_some());
+    }
 }
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The path of a commitment in a pruned block, which is stored in place of its state path.
+/// It holds the block hash, the transaction ID, and the transaction and transition paths and leaves
+/// of the commitment. The rest of the state path is rebuilt from the block header and transaction IDs.
+pub type PrunedPath<N> = (
+    <N as Network>::BlockHash,
+    <N as Network>::TransactionID,
+    TransactionPath<N>,
+    TransactionLeaf<N>,
+    TransitionPath<N>,
+    TransitionLeaf<N>,
+);
+
+/// The error returned when querying data of a block whose transactions have been pruned from storage.
+/// Note: This error can be detected with `error.downcast_ref::<PrunedError>()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PrunedError {
+    /// The height of the pruned block.
+    block_height: u32,
+}
+
+impl PrunedError {
+    /// Initializes a new pruned error, for the block at the given height.
+    pub const fn new(block_height: u32) -> Self {
+        Self { block_height }
+    }
+
+    /// Returns the height of the pruned block.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+}
+
+impl Display for PrunedError {
+    /// Displays the pruned error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The transactions of block {} have been pruned from storage", self.block_height)
+    }
+}
+
+impl std::error::Error for PrunedError {}
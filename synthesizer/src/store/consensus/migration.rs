@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The current schema version of the storage.
+/// Note: This must be incremented, along with a new migration step, whenever the storage layout changes.
+pub const SCHEMA_VERSION: u32 = 7;
+
+/// A migration step, which transforms the contents of the storage from one schema version to the next.
+pub struct Migration<N: Network, C: ConsensusStorage<N>> {
+    /// The schema version that the step migrates from.
+    from_version: u32,
+    /// The description of the step.
+    description: &'static str,
+    /// The function that transforms the contents of the storage.
+    migrate: fn(&C) -> Result<()>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Migration<N, C> {
+    /// Initializes a new migration step, from the given schema version to the next.
+    pub const fn new(from_version: u32, description: &'static str, migrate: fn(&C) -> Result<()>) -> Self {
+        Self { from_version, description, migrate, _phantom: PhantomData }
+    }
+
+    /// Returns the schema version that the step migrates from.
+    pub const fn from_version(&self) -> u32 {
+        self.from_version
+    }
+
+    /// Returns the schema version that the step migrates to.
+    pub const fn to_version(&self) -> u32 {
+        self.from_version + 1
+    }
+
+    /// Returns the description of the step.
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+}
+
+/// Returns the registry of migration steps, up to the current schema version.
+pub fn migrations<N: Network, C: ConsensusStorage<N>>() -> Vec<Migration<N, C>> {
+    vec![
+        // Version 0 is the storage layout from before the schema version was recorded.
+        Migration::new(0, "Mark the blocks preceding a bootstrapped block as pruned", |storage| {
+            storage.block_store().mark_headerless_blocks_as_pruned()
+        }),
+        Migration::new(1, "Build the block index and the function index", |storage| {
+            storage.block_store().build_indices()
+        }),
+        Migration::new(2, "Mark the stored transactions as accepted", |storage| {
+            storage.block_store().mark_transactions_as_accepted()
+        }),
+        Migration::new(3, "Keep only the fee transition of the aborted transactions", |storage| {
+            storage.block_store().prune_aborted_transactions()
+        }),
+        Migration::new(4, "Compute the ledger digest", |storage| storage.block_store().build_ledger_digest()),
+        Migration::new(5, "Build the mapping key index", |storage| storage.program_store().build_key_index()),
+        Migration::new(6, "Keep only the transaction and transition paths of the pruned commitments", |storage| {
+            storage.block_store().convert_state_paths()
+        }),
+    ]
+}
+
+/// Migrates the given storage to the given schema version, by applying the given migration steps in order.
+/// Each step is applied in an atomic batch, along with the update to the recorded schema version.
+///
+/// An empty storage is initialized at the given schema version. A storage without a recorded schema version
+/// predates schema versioning, and is migrated from version 0. This method fails if the storage has a newer
+/// schema version than the given one, or if a migration step is missing from the given steps.
+pub fn migrate<N: Network, C: ConsensusStorage<N>>(
+    storage: &C,
+    migrations: &[Migration<N, C>],
+    schema_version: u32,
+) -> Result<()> {
+    // Retrieve the schema version of the storage.
+    let mut version = match storage.schema_version()? {
+        Some(version) => version,
+        // If the storage is empty, initialize it at the given schema version.
+        None if storage.is_empty() => return storage.schema_map().insert((), schema_version),
+        None => 0,
+    };
+
+    // Ensure the schema version of the storage is supported.
+    if version > schema_version {
+        bail!("The storage schema version {version} is newer than the supported schema version {schema_version}")
+    }
+
+    // Apply the migration steps, in order.
+    while version < schema_version {
+        // Retrieve the migration step.
+        let migration = match migrations.iter().find(|migration| migration.from_version() == version) {
+            Some(migration) => migration,
+            None => bail!("Unknown storage schema version {version}: no migration step to version {}", version + 1),
+        };
+
+        atomic_write_batch!(storage, {
+            // Transform the contents of the storage.
+            (migration.migrate)(storage)
+                .map_err(|error| anyhow!("Failed to migrate ('{}'): {error}", migration.description()))?;
+            // Update the schema version.
+            storage.schema_map().insert((), migration.to_version())
+        });
+
+        version = migration.to_version();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{
+        helpers::database::{test_helpers::open_temporary_database, BlockDB, ConsensusDB},
+        ConsensusStore,
+        PrunedError,
+        TransactionStatus,
+    };
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext, ProgramID, Value},
+        types::Field,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the program ID and mapping name used in these tests.
+    fn sample_mapping() -> (ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>) {
+        (ProgramID::from_str("token.aleo").unwrap(), Identifier::from_str("account").unwrap())
+    }
+
+    /// Returns the key used in these tests.
+    fn sample_key() -> Plaintext<CurrentNetwork> {
+        Plaintext::from_str("1field").unwrap()
+    }
+
+    #[test]
+    fn test_migrate_fixture() {
+        let (_directory, _database, dev) = open_temporary_database::<CurrentNetwork>();
+
+        // Write a fixture store at schema version 0, which contains the block hashes
+        // of two bootstrapped blocks, without marking them as pruned, and a transaction ID without a status.
+        let block_hashes: Vec<<CurrentNetwork as Network>::BlockHash> =
+            (0..2).map(|i| Field::<CurrentNetwork>::from_u64(i).into()).collect();
+        let transaction_id: <CurrentNetwork as Network>::TransactionID = Field::<CurrentNetwork>::from_u64(2).into();
+        {
+            let storage = BlockDB::<CurrentNetwork>::open(dev).unwrap();
+            for (height, block_hash) in block_hashes.iter().enumerate() {
+                storage.id_map().insert(height as u32, *block_hash).unwrap();
+                storage.reverse_id_map().insert(*block_hash, height as u32).unwrap();
+            }
+            storage.reverse_transactions_map().insert(transaction_id, block_hashes[1]).unwrap();
+        }
+
+        // Open the consensus store, which migrates the fixture store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        assert_eq!(Some(SCHEMA_VERSION), store.storage.schema_version().unwrap());
+
+        // Ensure the bootstrapped blocks are marked as pruned.
+        for (height, block_hash) in block_hashes.iter().enumerate() {
+            assert!(store.block_store().is_pruned(block_hash).unwrap());
+            let error = store.block_store().get_block(block_hash).unwrap_err();
+            assert_eq!(Some(&PrunedError::new(height as u32)), error.downcast_ref::<PrunedError>());
+        }
+        // Ensure the indices were built, and are empty, as the bootstrapped blocks have no transactions in storage.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let page = store.block_store().get_block_hashes_for_program(&program_id, None, 10).unwrap();
+        assert!(page.items().is_empty());
+        // Ensure the stored transaction is marked as accepted.
+        let status = store.block_store().get_transaction_status(&transaction_id).unwrap();
+        assert_eq!(Some(TransactionStatus::Accepted), status);
+
+        // Ensure reopening the store does not migrate it again.
+        drop(store);
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        assert_eq!(Some(SCHEMA_VERSION), store.storage.schema_version().unwrap());
+    }
+
+    #[test]
+    fn test_migrate_empty_and_newer() {
+        let (_directory, _database, dev) = open_temporary_database::<CurrentNetwork>();
+
+        // Ensure an empty store is initialized at the current schema version.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        assert_eq!(Some(SCHEMA_VERSION), store.storage.schema_version().unwrap());
+
+        // Ensure a store with a newer schema version is refused.
+        store.storage.schema_map().insert((), SCHEMA_VERSION + 1).unwrap();
+        drop(store);
+        assert!(ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).is_err());
+    }
+
+    #[test]
+    fn test_migrate_steps() {
+        // Initialize a store with a mapping, at schema version 0.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        let (program_id, mapping_name) = sample_mapping();
+        store.program_store().initialize_mapping(&program_id, &mapping_name).unwrap();
+        store.storage.schema_map().insert((), 0).unwrap();
+
+        // Prepare the migration steps, which insert a key-value pair, and then update its value.
+        let migrations = [
+            Migration::new(1, "Update the value", |storage: &ConsensusMemory<CurrentNetwork>| {
+                let (program_id, mapping_name) = sample_mapping();
+                let value = Value::from_str("2u64")?;
+                storage.program_store().update_key_value(&program_id, &mapping_name, sample_key(), value)
+            }),
+            Migration::new(0, "Insert the value", |storage: &ConsensusMemory<CurrentNetwork>| {
+                let (program_id, mapping_name) = sample_mapping();
+                let value = Value::from_str("1u64")?;
+                storage.program_store().insert_key_value(&program_id, &mapping_name, sample_key(), value)
+            }),
+        ];
+
+        // Ensure the migration fails if a step is missing, leaving the schema version unchanged.
+        assert!(migrate(&store.storage, &migrations[1..], 2).is_err());
+        assert_eq!(Some(1), store.storage.schema_version().unwrap());
+
+        // Migrate the store, and ensure the steps were applied in order.
+        migrate(&store.storage, &migrations, 2).unwrap();
+        assert_eq!(Some(2), store.storage.schema_version().unwrap());
+        let value = store.program_store().get_value(&program_id, &mapping_name, &sample_key()).unwrap();
+        assert_eq!(Some(Value::from_str("2u64").unwrap()), value);
+
+        // Ensure a store with a newer schema version is refused.
+        assert!(migrate(&store.storage, &migrations, 1).is_err());
+    }
+}
@@ -14,19 +14,31 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::store::{
-    BlockMemory,
-    BlockStorage,
-    BlockStore,
-    ProgramMemory,
-    ProgramStorage,
-    ProgramStore,
-    TransactionMemory,
-    TransactionStorage,
-    TransactionStore,
-    TransitionMemory,
-    TransitionStorage,
-    TransitionStore,
+mod migration;
+pub use migration::*;
+
+use crate::{
+    atomic_write_batch,
+    cow_to_copied,
+    store::{
+        helpers::{
+            memory_map::{finish_atomic_journaled, MemoryMap},
+            Map,
+            MapRead,
+        },
+        BlockMemory,
+        BlockStorage,
+        BlockStore,
+        ProgramMemory,
+        ProgramStorage,
+        ProgramStore,
+        TransactionMemory,
+        TransactionStorage,
+        TransactionStore,
+        TransitionMemory,
+        TransitionStorage,
+        TransitionStore,
+    },
 };
 use console::network::prelude::*;
 
@@ -43,6 +55,8 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
     type TransitionStorage: TransitionStorage<N>;
+    /// The schema version of the storage.
+    type SchemaMap: for<'a> Map<'a, (), u32>;
 
     /// Initializes the consensus storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -51,6 +65,8 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_store(&self) -> &ProgramStore<N, Self::ProgramStorage>;
     /// Returns the block storage.
     fn block_store(&self) -> &BlockStore<N, Self::BlockStorage>;
+    /// Returns the schema map.
+    fn schema_map(&self) -> &Self::SchemaMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         self.block_store().transaction_store()
@@ -66,27 +82,51 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
         self.transition_store().dev()
     }
 
+    /// Returns the schema version of the storage, if it is recorded.
+    fn schema_version(&self) -> Result<Option<u32>> {
+        match self.schema_map().get(&())? {
+            Some(version) => Ok(Some(cow_to_copied!(version))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns `true` if the storage does not contain any blocks or programs.
+    fn is_empty(&self) -> bool {
+        self.block_store().heights().next().is_none() && self.program_store().program_ids().next().is_none()
+    }
+
+    /// Ensures the schema version of the storage is supported, migrating the storage to the current schema version
+    /// if it is older. This method fails on storage with an unknown or newer schema version.
+    fn migrate(&self) -> Result<()> {
+        migrate(self, &migrations(), SCHEMA_VERSION)
+    }
+
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.program_store().start_atomic();
         self.block_store().start_atomic();
+        self.schema_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
-        self.program_store().is_atomic_in_progress() || self.block_store().is_atomic_in_progress()
+        self.program_store().is_atomic_in_progress()
+            || self.block_store().is_atomic_in_progress()
+            || self.schema_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.program_store().abort_atomic();
         self.block_store().abort_atomic();
+        self.schema_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.program_store().finish_atomic()?;
-        self.block_store().finish_atomic()
+        self.block_store().finish_atomic()?;
+        self.schema_map().finish_atomic()
     }
 }
 
@@ -97,6 +137,8 @@ pub struct ConsensusMemory<N: Network> {
     program_store: ProgramStore<N, ProgramMemory<N>>,
     /// The block store.
     block_store: BlockStore<N, BlockMemory<N>>,
+    /// The schema map.
+    schema_map: MemoryMap<(), u32>,
 }
 
 #[rustfmt::skip]
@@ -105,6 +147,7 @@ impl<N: Network> ConsensusStorage<N> for ConsensusMemory<N> {
     type BlockStorage = BlockMemory<N>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
+    type SchemaMap = MemoryMap<(), u32>;
 
     /// Initializes the consensus storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -112,11 +155,16 @@ impl<N: Network> ConsensusStorage<N> for ConsensusMemory<N> {
         let program_store = ProgramStore::<N, ProgramMemory<N>>::open(dev)?;
         // Initialize the block store.
         let block_store = BlockStore::<N, BlockMemory<N>>::open(dev)?;
-        // Return the consensus storage.
-        Ok(Self {
+        // Initialize the consensus storage.
+        let storage = Self {
             program_store,
             block_store,
-        })
+            schema_map: MemoryMap::default(),
+        };
+        // Ensure the storage schema is supported, migrating the storage if it is older.
+        storage.migrate()?;
+        // Return the consensus storage.
+        Ok(storage)
     }
 
     /// Returns the program store.
@@ -128,6 +176,11 @@ impl<N: Network> ConsensusStorage<N> for ConsensusMemory<N> {
     fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
         &self.block_store
     }
+
+    /// Returns the schema map.
+    fn schema_map(&self) -> &Self::SchemaMap {
+        &self.schema_map
+    }
 }
 
 /// The consensus store.
@@ -190,7 +243,7 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    block::Header,
+    coinbase_puzzle::{CoinbaseSolution, PuzzleCommitment},
+    cow_to_cloned,
+    cow_to_copied,
+    store::{
+        helpers::{
+            database::{BlockMap, DataMap, Database, MapID, TransactionDB, TransitionDB},
+            Map,
+            MapRead,
+        },
+        BlockStorage,
+        PrunedPath,
+        TransactionStatus,
+        TransactionStore,
+        TransitionStore,
+    },
+};
+use console::{
+    account::Signature,
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::{Field, Group},
+};
+
+/// A database block storage.
+#[derive(Clone)]
+pub struct BlockDB<N: Network> {
+    /// The mapping of `block height` to `state root`.
+    state_root_map: DataMap<u32, N::StateRoot>,
+    /// The mapping of `state root` to `block height`.
+    reverse_state_root_map: DataMap<N::StateRoot, u32>,
+    /// The mapping of `block height` to `block hash`.
+    id_map: DataMap<u32, N::BlockHash>,
+    /// The mapping of `block hash` to `block height`.
+    reverse_id_map: DataMap<N::BlockHash, u32>,
+    /// The header map.
+    header_map: DataMap<N::BlockHash, Header<N>>,
+    /// The transactions map.
+    transactions_map: DataMap<N::BlockHash, Vec<N::TransactionID>>,
+    /// The reverse transactions map.
+    reverse_transactions_map: DataMap<N::TransactionID, N::BlockHash>,
+    /// The transaction status map.
+    transaction_status_map: DataMap<N::TransactionID, TransactionStatus>,
+    /// The transaction store.
+    transaction_store: TransactionStore<N, TransactionDB<N>>,
+    /// The coinbase solution map.
+    coinbase_solution_map: DataMap<N::BlockHash, Option<CoinbaseSolution<N>>>,
+    /// The coinbase puzzle commitment map.
+    coinbase_puzzle_commitment_map: DataMap<PuzzleCommitment<N>, N::BlockHash>,
+    /// The signature map.
+    signature_map: DataMap<N::BlockHash, Signature<N>>,
+    /// The pruned map.
+    pruned_map: DataMap<N::BlockHash, u32>,
+    /// The pruned path map.
+    pruned_path_map: DataMap<Field<N>, PrunedPath<N>>,
+    /// The state path map, which held a full state path for each pruned commitment before schema version 7.
+    legacy_state_path_map: DataMap<Field<N>, StatePath<N>>,
+    /// The block index map.
+    block_index_map: DataMap<(ProgramID<N>, N::BlockHash), ()>,
+    /// The ledger digest map.
+    ledger_digest_map: DataMap<(), Group<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> BlockStorage<N> for BlockDB<N> {
+    type StateRootMap = DataMap<u32, N::StateRoot>;
+    type ReverseStateRootMap = DataMap<N::StateRoot, u32>;
+    type IDMap = DataMap<u32, N::BlockHash>;
+    type ReverseIDMap = DataMap<N::BlockHash, u32>;
+    type HeaderMap = DataMap<N::BlockHash, Header<N>>;
+    type TransactionsMap = DataMap<N::BlockHash, Vec<N::TransactionID>>;
+    type ReverseTransactionsMap = DataMap<N::TransactionID, N::BlockHash>;
+    type TransactionStatusMap = DataMap<N::TransactionID, TransactionStatus>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+    type CoinbaseSolutionMap = DataMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
+    type CoinbasePuzzleCommitmentMap = DataMap<PuzzleCommitment<N>, N::BlockHash>;
+    type SignatureMap = DataMap<N::BlockHash, Signature<N>>;
+    type PrunedMap = DataMap<N::BlockHash, u32>;
+    type PrunedPathMap = DataMap<Field<N>, PrunedPath<N>>;
+    type BlockIndexMap = DataMap<(ProgramID<N>, N::BlockHash), ()>;
+    type LedgerDigestMap = DataMap<(), Group<N>>;
+
+    /// Initializes the block storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the transition store.
+        let transition_store = TransitionStore::<N, TransitionDB<N>>::open(dev)?;
+        // Initialize the transaction store.
+        let transaction_store = TransactionStore::<N, TransactionDB<N>>::open(transition_store)?;
+        // Return the block storage.
+        Ok(Self {
+            state_root_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::StateRoot))?,
+            reverse_state_root_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::ReverseStateRoot))?,
+            id_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::ID))?,
+            reverse_id_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::ReverseID))?,
+            header_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::Header))?,
+            transactions_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::Transactions))?,
+            reverse_transactions_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::ReverseTransactions))?,
+            transaction_status_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::TransactionStatus))?,
+            transaction_store,
+            coinbase_solution_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbaseSolution))?,
+            coinbase_puzzle_commitment_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbasePuzzleCommitment))?,
+            signature_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::Signature))?,
+            pruned_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::Pruned))?,
+            pruned_path_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::PrunedPath))?,
+            legacy_state_path_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::StatePath))?,
+            block_index_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::BlockIndex))?,
+            ledger_digest_map: Database::open_map(N::ID, dev, MapID::Block(BlockMap::LedgerDigest))?,
+        })
+    }
+
+    /// Returns a read-only view of the block storage.
+    /// Note: Read views are not supported by the database storage.
+    fn read_view(&self) -> Result<Self> {
+        bail!("Read views are not supported by the database storage")
+    }
+
+    /// Returns the state root map.
+    fn state_root_map(&self) -> &Self::StateRootMap {
+        &self.state_root_map
+    }
+
+    /// Returns the reverse state root map.
+    fn reverse_state_root_map(&self) -> &Self::ReverseStateRootMap {
+        &self.reverse_state_root_map
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the header map.
+    fn header_map(&self) -> &Self::HeaderMap {
+        &self.header_map
+    }
+
+    /// Returns the transactions map.
+    fn transactions_map(&self) -> &Self::TransactionsMap {
+        &self.transactions_map
+    }
+
+    /// Returns the reverse transactions map.
+    fn reverse_transactions_map(&self) -> &Self::ReverseTransactionsMap {
+        &self.reverse_transactions_map
+    }
+
+    /// Returns the transaction status map.
+    fn transaction_status_map(&self) -> &Self::TransactionStatusMap {
+        &self.transaction_status_map
+    }
+
+    /// Returns the transaction store.
+    fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
+        &self.transaction_store
+    }
+
+    /// Returns the coinbase solution map.
+    fn coinbase_solution_map(&self) -> &Self::CoinbaseSolutionMap {
+        &self.coinbase_solution_map
+    }
+
+    /// Returns the coinbase puzzle commitment map.
+    fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap {
+        &self.coinbase_puzzle_commitment_map
+    }
+
+    /// Returns the signature map.
+    fn signature_map(&self) -> &Self::SignatureMap {
+        &self.signature_map
+    }
+
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
+    /// Returns the pruned path map.
+    fn pruned_path_map(&self) -> &Self::PrunedPathMap {
+        &self.pruned_path_map
+    }
+
+    /// Returns the block index map.
+    fn block_index_map(&self) -> Option<&Self::BlockIndexMap> {
+        Some(&self.block_index_map)
+    }
+
+    /// Returns the ledger digest map.
+    fn ledger_digest_map(&self) -> &Self::LedgerDigestMap {
+        &self.ledger_digest_map
+    }
+
+    /// Converts the state paths that were stored for the pruned commitments into pruned paths.
+    fn convert_state_paths(&self) -> Result<()> {
+        // Retrieve the state paths.
+        let state_paths: Vec<_> = self
+            .legacy_state_path_map
+            .iter()
+            .map(|(commitment, state_path)| (cow_to_copied!(commitment), cow_to_cloned!(state_path)))
+            .collect();
+        // Replace each state path with a pruned path.
+        for (commitment, state_path) in state_paths {
+            let pruned_path = (
+                state_path.block_hash(),
+                *state_path.transaction_id(),
+                state_path.transaction_path().clone(),
+                *state_path.transaction_leaf(),
+                state_path.transition_path().clone(),
+                *state_path.transition_leaf(),
+            );
+            self.pruned_path_map.insert(commitment, pruned_path)?;
+            self.legacy_state_path_map.remove(&commitment)?;
+        }
+        Ok(())
+    }
+}
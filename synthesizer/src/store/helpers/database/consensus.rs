@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::{
+    helpers::database::{BlockDB, ConsensusMap, DataMap, Database, MapID, ProgramDB, TransactionDB, TransitionDB},
+    BlockStore,
+    ConsensusStorage,
+    ProgramStore,
+};
+use console::network::prelude::*;
+
+/// A database consensus storage.
+/// Note: Every store of the ledger shares one database, so an atomic batch that spans
+/// several stores (e.g. a block and its finalized mappings) is persisted as a whole.
+#[derive(Clone)]
+pub struct ConsensusDB<N: Network> {
+    /// The program store.
+    program_store: ProgramStore<N, ProgramDB<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockDB<N>>,
+    /// The schema map.
+    schema_map: DataMap<(), u32>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
+    type ProgramStorage = ProgramDB<N>;
+    type BlockStorage = BlockDB<N>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+    type SchemaMap = DataMap<(), u32>;
+
+    /// Initializes the consensus storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the program store.
+        let program_store = ProgramStore::<N, ProgramDB<N>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockDB<N>>::open(dev)?;
+        // Initialize the consensus storage.
+        let storage = Self {
+            program_store,
+            block_store,
+            schema_map: Database::open_map(N::ID, dev, MapID::Consensus(ConsensusMap::Schema))?,
+        };
+        // Ensure the storage schema is supported, migrating the storage if it is older.
+        storage.migrate()?;
+        // Return the consensus storage.
+        Ok(storage)
+    }
+
+    /// Returns the program store.
+    fn program_store(&self) -> &ProgramStore<N, Self::ProgramStorage> {
+        &self.program_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+
+    /// Returns the schema map.
+    fn schema_map(&self) -> &Self::SchemaMap {
+        &self.schema_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{
+        helpers::database::{test_helpers::sample_dev, Database},
+        ConsensusStore,
+    };
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext, ProgramID, Value},
+    };
+
+    use std::{path::Path, process::Command};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentStore = ConsensusStore<CurrentNetwork, ConsensusDB<CurrentNetwork>>;
+
+    /// The environment variable that instructs a child test process to crash while writing to the ledger in the given directory.
+    const CRASH_DIRECTORY: &str = "SNARKVM_TEST_CRASH_DIRECTORY";
+
+    /// Opens the consensus store in the given directory, and returns it along with its database.
+    /// Note: The database must be kept alive for as long as the store is in use.
+    fn open_store(directory: &Path) -> (Database, CurrentStore) {
+        let dev = sample_dev();
+        let database = Database::open_at(directory, CurrentNetwork::ID, dev).unwrap();
+        let store = CurrentStore::open(dev).unwrap();
+        (database, store)
+    }
+
+    /// Returns the program ID and mapping name used in these tests.
+    fn sample_mapping() -> (ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>) {
+        (ProgramID::from_str("token.aleo").unwrap(), Identifier::from_str("account").unwrap())
+    }
+
+    /// Returns the `i`-th key-value pair used in these tests.
+    fn sample_key_value(i: u64) -> (Plaintext<CurrentNetwork>, Value<CurrentNetwork>) {
+        (Plaintext::from_str(&format!("{i}field")).unwrap(), Value::from_str(&format!("{i}u64")).unwrap())
+    }
+
+    /// Re-runs the given test in a child process, which writes to the ledger in the given directory
+    /// and then aborts. Returns once the child process has been terminated.
+    fn run_crashing_child(test_name: &str, directory: &Path) {
+        // Strip the crate name from the module path, to obtain the name of the test.
+        let (_, module_path) = module_path!().split_once("::").unwrap();
+        let status = Command::new(std::env::current_exe().unwrap())
+            .args([&format!("{module_path}::{test_name}"), "--exact", "--nocapture", "--test-threads=1"])
+            .env(CRASH_DIRECTORY, directory)
+            .status()
+            .unwrap();
+        // Ensure the child process crashed, rather than exiting normally.
+        assert!(!status.success());
+    }
+
+    /// Initializes the mapping and inserts a key-value pair, then starts an atomic batch
+    /// that inserts two more key-value pairs, optionally finishes it, and crashes the process.
+    fn write_and_crash(directory: &Path, finish_atomic: bool) -> ! {
+        let (_database, store) = open_store(directory);
+        let (program_id, mapping_name) = sample_mapping();
+
+        // Initialize the mapping, and insert a key-value pair.
+        store.program_store().initialize_mapping(&program_id, &mapping_name).unwrap();
+        let (key, value) = sample_key_value(0);
+        store.program_store().insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+
+        // Start an atomic batch, and insert two key-value pairs.
+        store.start_atomic();
+        for i in 1..3 {
+            let (key, value) = sample_key_value(i);
+            store.program_store().insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+        }
+        if finish_atomic {
+            store.finish_atomic().unwrap();
+        }
+
+        // Kill the process, without running any destructors.
+        std::process::abort()
+    }
+
+    #[test]
+    fn test_persistence() {
+        let directory = tempfile::tempdir().unwrap();
+        let (program_id, mapping_name) = sample_mapping();
+
+        // Write to the ledger, and close it.
+        {
+            let (_database, store) = open_store(directory.path());
+            store.program_store().initialize_mapping(&program_id, &mapping_name).unwrap();
+            for i in 0..3 {
+                let (key, value) = sample_key_value(i);
+                store.program_store().insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+            }
+            store.program_store().remove_key_value(&program_id, &mapping_name, &sample_key_value(1).0).unwrap();
+        }
+
+        // Reopen the ledger, and ensure the writes were persisted.
+        let (_database, store) = open_store(directory.path());
+        assert!(store.program_store().contains_mapping(&program_id, &mapping_name).unwrap());
+        for (i, expected) in [true, false, true].into_iter().enumerate() {
+            let (key, value) = sample_key_value(i as u64);
+            let candidate = store.program_store().get_value(&program_id, &mapping_name, &key).unwrap();
+            assert_eq!(candidate, expected.then_some(value));
+        }
+        assert!(store.block_store().heights().next().is_none());
+    }
+
+    #[test]
+    fn test_crash_before_finish_atomic() {
+        // If this is the child process, write to the ledger and crash.
+        if let Some(directory) = std::env::var_os(CRASH_DIRECTORY) {
+            write_and_crash(Path::new(&directory), false);
+        }
+
+        // Crash a child process between `insert` and `finish_atomic`.
+        let directory = tempfile::tempdir().unwrap();
+        run_crashing_child("test_crash_before_finish_atomic", directory.path());
+
+        // Reopen the ledger.
+        let (_database, store) = open_store(directory.path());
+        let (program_id, mapping_name) = sample_mapping();
+        assert!(!store.is_atomic_in_progress());
+
+        // Ensure the writes before the atomic batch were persisted.
+        assert!(store.program_store().contains_mapping(&program_id, &mapping_name).unwrap());
+        let (key, value) = sample_key_value(0);
+        assert_eq!(store.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), Some(value));
+        // Ensure none of the writes in the unfinished atomic batch were persisted.
+        for i in 1..3 {
+            let (key, _) = sample_key_value(i);
+            assert!(!store.program_store().contains_key(&program_id, &mapping_name, &key).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_crash_after_finish_atomic() {
+        // If this is the child process, write to the ledger and crash.
+        if let Some(directory) = std::env::var_os(CRASH_DIRECTORY) {
+            write_and_crash(Path::new(&directory), true);
+        }
+
+        // Crash a child process right after `finish_atomic`.
+        let directory = tempfile::tempdir().unwrap();
+        run_crashing_child("test_crash_after_finish_atomic", directory.path());
+
+        // Reopen the ledger, and ensure all of the writes were persisted.
+        let (_database, store) = open_store(directory.path());
+        let (program_id, mapping_name) = sample_mapping();
+        for i in 0..3 {
+            let (key, value) = sample_key_value(i);
+            assert_eq!(store.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), Some(value));
+        }
+    }
+}
@@ -0,0 +1,465 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::network::prelude::*;
+
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+/// The number of bytes in the header of a batch, as `(payload length, length checksum, payload checksum)`.
+const HEADER_SIZE: usize = 12;
+/// The minimum size (in bytes) of the write log, before it is considered for compaction.
+pub(super) const COMPACTION_THRESHOLD: u64 = 64 * 1024 * 1024;
+/// The maximum size (in bytes) of the entries in each batch of a compacted log.
+const COMPACTION_BATCH_SIZE: usize = 16 * 1024 * 1024;
+
+/// The operations of a batch, as `(key, (optional) value)` pairs.
+/// Note: A value of `None` removes the key.
+pub(super) type Batch = [(Vec<u8>, Option<Vec<u8>>)];
+
+/// The outcome of reading a batch from the write log.
+enum BatchRead {
+    /// An intact batch, as `(operations, batch size)`.
+    Intact(Vec<(Vec<u8>, Option<Vec<u8>>)>, u64),
+    /// A batch that extends past the end of the log, i.e. it was not fully written.
+    Torn,
+    /// A complete batch that fails its checksum or cannot be deserialized, as `(batch size)`.
+    Damaged(u64),
+    /// A batch whose header fails its checksum, so that the end of the batch is unknown.
+    Unreadable,
+}
+
+/// An append-only write log, consisting of a sequence of checksummed batches.
+pub(super) struct Log {
+    /// The path of the log file.
+    path: PathBuf,
+    /// The log file.
+    file: File,
+    /// The length of the log, in bytes, up to the end of the last intact batch.
+    length: u64,
+    /// The size of the live entries, in bytes, i.e. the size of the log once it is compacted.
+    live_size: u64,
+}
+
+impl Log {
+    /// Opens the write log at the given path, and replays every intact batch into a map.
+    ///
+    /// A crash can only tear the batch that was being appended, so any torn or damaged batches at the
+    /// end of the log are discarded. However, if a damaged batch is followed by an intact batch, then
+    /// committed batches were lost, and an error is returned instead of silently dropping them.
+    /// Likewise, if the header of a batch is corrupted, the batches after it cannot be located,
+    /// and an error is returned, unless the header is part of a zeroed tail that was never written.
+    pub(super) fn open(path: &Path) -> Result<(Self, BTreeMap<Vec<u8>, Vec<u8>>)> {
+        // Open the log file, creating it if it does not exist.
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)?;
+        // Ensure the log file itself survives a crash.
+        sync_directory(path);
+
+        // Replay each intact batch, in order.
+        let file_length = file.metadata()?.len();
+        let mut reader = BufReader::new(&file);
+        let mut map = BTreeMap::new();
+        let mut offset = 0;
+        // The offset of the first damaged batch, if any.
+        let mut damaged_offset = None;
+        while offset < file_length {
+            match Self::read_batch(&mut reader, file_length - offset)? {
+                BatchRead::Intact(operations, batch_size) => {
+                    // Ensure no damaged batch precedes this batch.
+                    if let Some(damaged_offset) = damaged_offset {
+                        bail!("Found a corrupted batch at byte {damaged_offset} of the storage log '{}'", path.display())
+                    }
+                    for (key, value) in operations {
+                        match value {
+                            Some(value) => map.insert(key, value),
+                            None => map.remove(&key),
+                        };
+                    }
+                    offset += batch_size;
+                }
+                BatchRead::Torn => break,
+                BatchRead::Unreadable => {
+                    bail!("Found a corrupted batch header at byte {offset} of the storage log '{}'", path.display())
+                }
+                BatchRead::Damaged(batch_size) => {
+                    // Record the first damaged batch, and check the rest of the log for intact batches.
+                    damaged_offset.get_or_insert(offset);
+                    offset += batch_size;
+                }
+            }
+        }
+        drop(reader);
+
+        // Discard the remainder of the log, which was not fully written.
+        let length = damaged_offset.unwrap_or(offset);
+        if length < file_length {
+            file.set_len(length)?;
+            file.sync_all()?;
+        }
+        file.seek(SeekFrom::Start(length))?;
+
+        let live_size = map.iter().map(|(key, value)| (key.len() + value.len()) as u64).sum();
+        let mut log = Self { path: path.to_path_buf(), file, length, live_size };
+        // If the log consists mostly of overwritten entries, rewrite it from the live entries.
+        if log.is_compaction_due() {
+            log.compact(&map)?;
+        }
+        Ok((log, map))
+    }
+
+    /// Updates the size of the live entries, once a batch is applied to the entries,
+    /// given the size of the inserted entries and the size of the entries they replaced or removed.
+    pub(super) fn update_live_size(&mut self, inserted_size: u64, removed_size: u64) {
+        self.live_size = self.live_size.saturating_add(inserted_size).saturating_sub(removed_size);
+    }
+
+    /// Returns `true` if the log should be compacted, as it consists mostly of overwritten entries.
+    pub(super) fn is_compaction_due(&self) -> bool {
+        self.length > COMPACTION_THRESHOLD && self.length > self.live_size.saturating_mul(2)
+    }
+
+    /// Appends the given batch to the log, and syncs it to disk.
+    pub(super) fn append(&mut self, operations: &Batch) -> Result<()> {
+        // Prepare the batch.
+        let batch = Self::write_batch(operations)?;
+
+        // Write the batch, and sync it to disk.
+        if let Err(error) = self.file.write_all(&batch).and_then(|_| self.file.sync_data()) {
+            // Roll back any partially-written batch, so that subsequent batches remain readable.
+            let _ = self.file.set_len(self.length);
+            let _ = self.file.seek(SeekFrom::Start(self.length));
+            bail!("Failed to write a batch to the storage log - {error}")
+        }
+        self.length += batch.len() as u64;
+        Ok(())
+    }
+
+    /// Rewrites the log from the given entries, in batches of at most `COMPACTION_BATCH_SIZE` bytes.
+    /// Note: On failure, the log is left unchanged, and remains usable.
+    pub(super) fn compact(&mut self, map: &BTreeMap<Vec<u8>, Vec<u8>>) -> Result<()> {
+        // Write the batches to a temporary file.
+        let compacted_path = self.path.with_extension("compact");
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(&compacted_path)?;
+        let mut writer = BufWriter::new(file);
+        let mut operations = Vec::new();
+        let mut operations_size = 0;
+        for (index, (key, value)) in map.iter().enumerate() {
+            operations.push((key.clone(), Some(value.clone())));
+            operations_size += key.len() + value.len();
+            // Write the batch, once it is full or there are no entries left.
+            if operations_size >= COMPACTION_BATCH_SIZE || index + 1 == map.len() {
+                writer.write_all(&Self::write_batch(&operations)?)?;
+                operations.clear();
+                operations_size = 0;
+            }
+        }
+        let mut file = writer.into_inner().map_err(|error| error.into_error())?;
+        file.sync_all()?;
+        let length = file.seek(SeekFrom::End(0))?;
+
+        // Atomically replace the log with the temporary file.
+        // Note: The file is kept open across the rename, so the log cannot be left without an open file.
+        std::fs::rename(&compacted_path, &self.path)?;
+        sync_directory(&self.path);
+
+        // Switch to the compacted log.
+        self.file = file;
+        self.length = length;
+        Ok(())
+    }
+
+    /// Returns the serialized batch, as `(payload length, length checksum, payload checksum, payload)`.
+    fn write_batch(operations: &Batch) -> Result<Vec<u8>> {
+        // Serialize the operations.
+        let payload = bincode::serialize(operations)?;
+        // Ensure the payload length fits in the header.
+        let payload_length = u32::try_from(payload.len())
+            .map_err(|_| anyhow!("Failed to write a batch of {} bytes to the storage log", payload.len()))?;
+
+        // Construct the batch.
+        let mut batch = Vec::with_capacity(HEADER_SIZE + payload.len());
+        batch.extend_from_slice(&payload_length.to_le_bytes());
+        batch.extend_from_slice(&crc32fast::hash(&payload_length.to_le_bytes()).to_le_bytes());
+        batch.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+        batch.extend_from_slice(&payload);
+        Ok(batch)
+    }
+
+    /// Reads the next batch from the given reader, which has `remaining` bytes left in the log.
+    /// Note: Unless the batch is torn, the reader is advanced to the start of the following batch.
+    fn read_batch<R: Read>(reader: &mut R, remaining: u64) -> Result<BatchRead> {
+        // Read the header.
+        if remaining < HEADER_SIZE as u64 {
+            return Ok(BatchRead::Torn);
+        }
+        let mut header = [0u8; HEADER_SIZE];
+        reader.read_exact(&mut header)?;
+        let payload_length = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        let length_checksum = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        let checksum = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+
+        // Ensure the payload length matches its checksum, as the end of the batch cannot be trusted otherwise.
+        if crc32fast::hash(&header[..4]) != length_checksum {
+            // If the header and the rest of the log are zeros, then the log grew before the batch was written.
+            let mut tail = Vec::new();
+            reader.read_to_end(&mut tail)?;
+            return match header.iter().chain(&tail).all(|byte| *byte == 0) {
+                true => Ok(BatchRead::Torn),
+                false => Ok(BatchRead::Unreadable),
+            };
+        }
+        // Ensure the payload was fully written.
+        let batch_size = HEADER_SIZE as u64 + payload_length as u64;
+        if batch_size > remaining {
+            return Ok(BatchRead::Torn);
+        }
+        // Read the payload, and ensure it matches the checksum.
+        let mut payload = vec![0u8; payload_length as usize];
+        reader.read_exact(&mut payload)?;
+        if crc32fast::hash(&payload) != checksum {
+            return Ok(BatchRead::Damaged(batch_size));
+        }
+        // Deserialize the operations.
+        match bincode::deserialize(&payload) {
+            Ok(operations) => Ok(BatchRead::Intact(operations, batch_size)),
+            Err(_) => Ok(BatchRead::Damaged(batch_size)),
+        }
+    }
+}
+
+/// Syncs the directory containing the given path, so that the creation or renaming of a file is durable.
+/// Note: This is a best-effort operation, as not every platform supports syncing a directory.
+fn sync_directory(path: &Path) {
+    if let Some(directory) = path.parent() {
+        if let Ok(directory) = File::open(directory) {
+            let _ = directory.sync_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the log at the given path, along with its replayed entries.
+    fn open(path: &Path) -> (Log, BTreeMap<Vec<u8>, Vec<u8>>) {
+        Log::open(path).unwrap()
+    }
+
+    #[test]
+    fn test_replay() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Append a few batches.
+        let (mut log, map) = open(&path);
+        assert!(map.is_empty());
+        log.append(&[(vec![1], Some(vec![1])), (vec![2], Some(vec![2]))]).unwrap();
+        log.append(&[(vec![1], None), (vec![3], Some(vec![3]))]).unwrap();
+        log.append(&[(vec![2], Some(vec![4]))]).unwrap();
+        drop(log);
+
+        // Ensure the batches are replayed in order.
+        let (_, map) = open(&path);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(vec![2], vec![4]), (vec![3], vec![3])]);
+    }
+
+    #[test]
+    fn test_torn_batch_is_discarded() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Append two batches.
+        let (mut log, _) = open(&path);
+        log.append(&[(vec![1], Some(vec![1]))]).unwrap();
+        let intact_length = log.length;
+        log.append(&[(vec![2], Some(vec![2])), (vec![3], Some(vec![3]))]).unwrap();
+        drop(log);
+
+        // Truncate the log partway through the second batch, as if the process crashed while writing it.
+        let file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(intact_length + 5).unwrap();
+        drop(file);
+
+        // Ensure only the first batch is replayed, and the torn batch is removed from the log.
+        let (mut log, map) = open(&path);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(vec![1], vec![1])]);
+        assert_eq!(log.length, intact_length);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), intact_length);
+
+        // Ensure new batches are appended after the last intact batch.
+        log.append(&[(vec![4], Some(vec![4]))]).unwrap();
+        drop(log);
+        let (_, map) = open(&path);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(vec![1], vec![1]), (vec![4], vec![4])]);
+    }
+
+    #[test]
+    fn test_corrupted_batch_is_discarded() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Append two batches.
+        let (mut log, _) = open(&path);
+        log.append(&[(vec![1], Some(vec![1]))]).unwrap();
+        let intact_length = log.length;
+        log.append(&[(vec![2], Some(vec![2]))]).unwrap();
+        drop(log);
+
+        // Corrupt the last byte of the second batch.
+        let mut bytes = std::fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        std::fs::write(&path, bytes).unwrap();
+
+        // Ensure only the first batch is replayed.
+        let (log, map) = open(&path);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(vec![1], vec![1])]);
+        assert_eq!(log.length, intact_length);
+    }
+
+    #[test]
+    fn test_corrupted_batch_before_intact_batch_fails() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Append three batches.
+        let (mut log, _) = open(&path);
+        log.append(&[(vec![1], Some(vec![1]))]).unwrap();
+        let intact_length = log.length;
+        log.append(&[(vec![2], Some(vec![2]))]).unwrap();
+        let corrupted_length = log.length;
+        log.append(&[(vec![3], Some(vec![3]))]).unwrap();
+        drop(log);
+
+        // Corrupt the last byte of the second batch.
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[corrupted_length as usize - 1] ^= 1;
+        std::fs::write(&path, &bytes).unwrap();
+
+        // Ensure the log fails to open, as the third batch was committed after the corrupted batch.
+        let error = Log::open(&path).err().unwrap();
+        assert!(error.to_string().contains(&format!("byte {intact_length}")));
+        // Ensure the log is left untouched.
+        assert_eq!(std::fs::read(&path).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_corrupted_length_before_intact_batch_fails() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Append three batches.
+        let (mut log, _) = open(&path);
+        log.append(&[(vec![1], Some(vec![1]))]).unwrap();
+        let intact_length = log.length;
+        log.append(&[(vec![2], Some(vec![2]))]).unwrap();
+        log.append(&[(vec![3], Some(vec![3]))]).unwrap();
+        drop(log);
+
+        // Increase the payload length of the second batch, so that it appears to extend past the end of the log.
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[intact_length as usize + 3] = 0xff;
+        std::fs::write(&path, &bytes).unwrap();
+
+        // Ensure the log fails to open, instead of discarding the second and third batches as torn.
+        let error = Log::open(&path).err().unwrap();
+        assert!(error.to_string().contains(&format!("byte {intact_length}")));
+        // Ensure the log is left untouched.
+        assert_eq!(std::fs::read(&path).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_zeroed_tail_is_discarded() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Append a batch.
+        let (mut log, _) = open(&path);
+        log.append(&[(vec![1], Some(vec![1]))]).unwrap();
+        let intact_length = log.length;
+        drop(log);
+
+        // Extend the log with zeros, as if the file grew before the batch being appended was written.
+        let file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(intact_length + 64).unwrap();
+        drop(file);
+
+        // Ensure only the first batch is replayed, and the zeros are removed from the log.
+        let (log, map) = open(&path);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(vec![1], vec![1])]);
+        assert_eq!(log.length, intact_length);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), intact_length);
+    }
+
+    #[test]
+    fn test_compact() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Append a few batches, overwriting the same key.
+        let (mut log, _) = open(&path);
+        for i in 0..10u8 {
+            log.append(&[(vec![0], Some(vec![i])), (vec![i], Some(vec![i]))]).unwrap();
+        }
+        drop(log);
+        let (_, mut expected) = open(&path);
+
+        // Compact the log, and ensure it replays to the same entries.
+        let (mut log, _) = open(&path);
+        log.compact(&expected).unwrap();
+        assert_eq!(log.length, std::fs::metadata(&path).unwrap().len());
+        // Ensure batches can be appended to the compacted log.
+        log.append(&[(vec![0], Some(vec![10]))]).unwrap();
+        drop(log);
+        expected.insert(vec![0], vec![10]);
+        let (_, candidate) = open(&path);
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_compact_in_batches() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("test.log");
+
+        // Prepare entries that exceed the size of a single compacted batch.
+        let value_size = COMPACTION_BATCH_SIZE / 4;
+        let expected = (0..10u8).map(|i| (vec![i], vec![i; value_size])).collect::<BTreeMap<_, _>>();
+
+        // Compact the entries, and ensure they are written in several batches.
+        let (mut log, _) = open(&path);
+        log.compact(&expected).unwrap();
+        drop(log);
+        let bytes = std::fs::read(&path).unwrap();
+        let mut offset = 0;
+        let mut num_batches = 0;
+        while offset < bytes.len() {
+            match Log::read_batch(&mut &bytes[offset..], (bytes.len() - offset) as u64).unwrap() {
+                BatchRead::Intact(_, batch_size) => offset += batch_size as usize,
+                _ => panic!("Found an invalid batch in the compacted log"),
+            }
+            num_batches += 1;
+        }
+        assert_eq!(num_batches, 3);
+
+        // Ensure the log replays to the same entries.
+        let (_, candidate) = open(&path);
+        assert_eq!(expected, candidate);
+    }
+}
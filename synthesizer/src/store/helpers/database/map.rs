@@ -0,0 +1,563 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Database, Entries, MapID};
+use crate::store::helpers::{Map, MapRead};
+use console::network::prelude::*;
+
+use core::{borrow::Borrow, hash::Hash, marker::PhantomData};
+use std::{borrow::Cow, ops::Bound};
+
+/// A map in the persistent database.
+/// Note: Each key is prefixed with the map ID, so the maps of a ledger share one keyspace.
+#[derive(Clone)]
+pub struct DataMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    /// The database.
+    database: Database,
+    /// The key prefix of the map.
+    context: Vec<u8>,
+    /// PhantomData.
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> DataMap<K, V>
+{
+    /// Initializes a new map with the given ID, in the given database.
+    pub(super) fn new(database: Database, map_id: MapID) -> Self {
+        // Note: The map ID is big-endian, so that the entries of each map are contiguous and in key order.
+        Self { database, context: u16::from(map_id).to_be_bytes().to_vec(), _phantom: PhantomData }
+    }
+
+    /// Returns the database key for the given map key.
+    fn create_key<Q: Serialize + ?Sized>(&self, key: &Q) -> Result<Vec<u8>> {
+        let mut raw_key = self.context.clone();
+        bincode::serialize_into(&mut raw_key, key)?;
+        Ok(raw_key)
+    }
+
+    /// Returns the deserialized map key for the given database key.
+    /// Note: The 'unwrap' is safe here, because the keys are defined by us.
+    fn deserialize_key(&self, raw_key: &[u8]) -> K {
+        bincode::deserialize(&raw_key[self.context.len()..]).unwrap()
+    }
+
+    /// Returns the deserialized map value for the given database value.
+    /// Note: The 'unwrap' is safe here, because the values are defined by us.
+    fn deserialize_value(raw_value: &[u8]) -> V {
+        bincode::deserialize(raw_value).unwrap()
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Map<'a, K, V> for DataMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        self.database.put(self.create_key(&key)?, Some(bincode::serialize(&value)?))
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        self.database.put(self.create_key(key)?, None)
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        self.database.start_atomic();
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.database.is_atomic_in_progress()
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        self.database.abort_atomic();
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        self.database.finish_atomic()
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MapRead<'a, K, V> for DataMap<K, V>
+{
+    type Iterator = DataMapIter<'a, K, V>;
+    type Keys = DataMapKeys<'a, K, V>;
+    type Values = DataMapValues<'a, K, V>;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.database.contains_key(&self.create_key(key)?))
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        match self.database.get(&self.create_key(key)?) {
+            Some(raw_value) => Ok(Some(Cow::Owned(bincode::deserialize(&raw_value)?))),
+            None => Ok(None),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        let raw_key = self.create_key(key).ok()?;
+        self.database
+            .get_batched(&raw_key)
+            .map(|raw_value| raw_value.map(|raw_value| Self::deserialize_value(&raw_value)))
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        DataMapIter { map: self, entries: self.database.entries(&self.context) }
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        DataMapKeys(self.iter())
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values(&'a self) -> Self::Values {
+        DataMapValues(self.iter())
+    }
+
+    ///
+    /// Returns up to `limit` key-value pairs whose keys start with the given `prefix`, in key order.
+    /// The pairs start after the key `after`, or at the first key with the prefix if `after` is `None`.
+    ///
+    fn get_prefix_range<P: Serialize + ?Sized>(
+        &'a self,
+        prefix: &P,
+        after: Option<&K>,
+        limit: usize,
+    ) -> Result<Vec<(Cow<'a, K>, Cow<'a, V>)>> {
+        // Determine the bounds of the range.
+        let prefix = self.create_key(prefix)?;
+        let start = match after {
+            Some(after) => Bound::Excluded(self.create_key(after)?),
+            None => Bound::Included(prefix.clone()),
+        };
+        // Retrieve the key-value pairs in the range.
+        let entries = DataMapIter { map: self, entries: self.database.entries_from(&prefix, start) };
+        Ok(entries.take(limit).collect())
+    }
+}
+
+/// An iterator over the entries of a map in the persistent database, in key order.
+pub struct DataMapIter<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    /// The map.
+    map: &'a DataMap<K, V>,
+    /// The entries of the map in the database.
+    entries: Entries,
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Iterator for DataMapIter<'a, K, V>
+{
+    type Item = (Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.entries.next()?;
+        Some((Cow::Owned(self.map.deserialize_key(&key)), Cow::Owned(DataMap::<K, V>::deserialize_value(&value))))
+    }
+}
+
+/// An iterator over the keys of a map in the persistent database, in key order.
+pub struct DataMapKeys<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+>(DataMapIter<'a, K, V>);
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Iterator for DataMapKeys<'a, K, V>
+{
+    type Item = Cow<'a, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, _)| key)
+    }
+}
+
+/// An iterator over the values of a map in the persistent database, in key order.
+pub struct DataMapValues<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+>(DataMapIter<'a, K, V>);
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Iterator for DataMapValues<'a, K, V>
+{
+    type Item = Cow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::{
+        database::{test_helpers::open_temporary_database, ProgramMap, ENTRIES_PAGE_SIZE},
+        memory_map::MemoryMap,
+    };
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// The map ID used in these tests.
+    const MAP_ID: MapID = MapID::Program(ProgramMap::KeyValueID);
+
+    #[test]
+    fn test_contains_key() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize an address.
+        let address =
+            Address::<CurrentNetwork>::from_str("aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8")
+                .unwrap();
+
+        // Initialize a map.
+        let map: DataMap<Address<CurrentNetwork>, ()> = DataMap::new(database, MAP_ID);
+        map.insert(address, ()).unwrap();
+        assert!(map.contains_key(&address).unwrap());
+    }
+
+    #[test]
+    fn test_insert_and_get_speculative() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize a map.
+        let map: DataMap<usize, String> = DataMap::new(database, MAP_ID);
+
+        // Sanity check.
+        assert!(map.iter().next().is_none());
+
+        /* test atomic insertions */
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Insert an item into the map.
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Check that the item is not yet in the map.
+        assert!(map.get(&0).unwrap().is_none());
+        // Check that the item is in the batch.
+        assert_eq!(map.get_batched(&0), Some(Some("0".to_string())));
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 1..10 {
+            // Update the item in the map.
+            map.insert(0, i.to_string()).unwrap();
+
+            // Check that the item is not yet in the map.
+            assert!(map.get(&0).unwrap().is_none());
+            // Check that the updated item is in the batch.
+            assert_eq!(map.get_batched(&0), Some(Some(i.to_string())));
+            // Check that the updated item can be speculatively retrieved.
+            assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned(i.to_string())));
+        }
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is present in the map now.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("9".to_string())));
+        // Check that the item is not in the batch.
+        assert_eq!(map.get_batched(&0), None);
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("9".to_string())));
+    }
+
+    #[test]
+    fn test_remove_and_get_speculative() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize a map.
+        let map: DataMap<usize, String> = DataMap::new(database, MAP_ID);
+
+        // Insert an item into the map.
+        map.insert(0, "0".to_string()).unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        /* test atomic removals */
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Remove the item from the map.
+        map.remove(&0).unwrap();
+
+        // Check that the item still exists in the map.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        // Check that the item is removed in the batch.
+        assert_eq!(map.get_batched(&0), Some(None));
+        // Check that the item is removed when speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), None);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is not present in the map now.
+        assert!(map.get(&0).unwrap().is_none());
+        // Check that the item is not in the batch.
+        assert_eq!(map.get_batched(&0), None);
+        // Check that the map is empty now.
+        assert!(map.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_abort_atomic() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize a map.
+        let map: DataMap<usize, String> = DataMap::new(database, MAP_ID);
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Queue an insertion and a removal, and abort the batch.
+        map.start_atomic();
+        map.insert(1, "1".to_string()).unwrap();
+        map.remove(&0).unwrap();
+        map.abort_atomic();
+
+        // Ensure the map is unchanged, and the batch is discarded.
+        assert!(!map.is_atomic_in_progress());
+        assert_eq!(map.get_batched(&0), None);
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert!(map.get(&1).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_nested_atomic_batches_share_one_write() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize two maps in the same database.
+        let map_a: DataMap<usize, String> = DataMap::new(database.clone(), MapID::Program(ProgramMap::Key));
+        let map_b: DataMap<usize, String> = DataMap::new(database, MapID::Program(ProgramMap::Value));
+
+        // Start a batch on each map, and queue an insertion into each.
+        map_a.start_atomic();
+        map_b.start_atomic();
+        map_a.insert(0, "a".to_string()).unwrap();
+        map_b.insert(0, "b".to_string()).unwrap();
+
+        // Ensure the batch is not written until the outermost batch finishes.
+        map_a.finish_atomic().unwrap();
+        assert!(map_a.is_atomic_in_progress());
+        assert!(map_a.get(&0).unwrap().is_none());
+        map_b.finish_atomic().unwrap();
+        assert!(!map_a.is_atomic_in_progress());
+
+        // Ensure the maps are disjoint.
+        assert_eq!(map_a.get(&0).unwrap(), Some(Cow::Owned("a".to_string())));
+        assert_eq!(map_b.get(&0).unwrap(), Some(Cow::Owned("b".to_string())));
+        assert_eq!(map_a.iter().count(), 1);
+        assert_eq!(map_b.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_atomic_batch_is_scoped_to_its_thread() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize a map, and start a batch on this thread.
+        let map: DataMap<usize, String> = DataMap::new(database, MAP_ID);
+        map.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Write to the map from another thread, while the batch is in progress.
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                // Ensure the batch is neither observed nor joined by the other thread.
+                assert!(!map.is_atomic_in_progress());
+                assert_eq!(map.get_batched(&0), None);
+                map.insert(1, "1".to_string()).unwrap();
+            });
+        });
+
+        // Ensure the write from the other thread is committed directly, and is not part of the batch.
+        assert_eq!(map.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+        assert_eq!(map.get_batched(&1), None);
+        map.abort_atomic();
+        assert!(map.get(&0).unwrap().is_none());
+        assert_eq!(map.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+    }
+
+    #[test]
+    fn test_atomic_batch_scope_differs_from_memory_map() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize a database map and a memory map, and start a batch on each, on this thread.
+        let data_map: DataMap<usize, String> = DataMap::new(database, MAP_ID);
+        let memory_map: MemoryMap<usize, String> = Default::default();
+        data_map.start_atomic();
+        memory_map.start_atomic();
+
+        // Write to both maps from another thread, while the batches are in progress.
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                data_map.insert(0, "0".to_string()).unwrap();
+                memory_map.insert(0, "0".to_string()).unwrap();
+            });
+        });
+
+        // Ensure the database map commits the write directly, while the memory map adds it to its batch.
+        assert_eq!(data_map.get_batched(&0), None);
+        assert_eq!(memory_map.get_batched(&0), Some(Some("0".to_string())));
+
+        // Abort the batches, and ensure the write is only discarded from the memory map.
+        data_map.abort_atomic();
+        memory_map.abort_atomic();
+        assert_eq!(data_map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert!(memory_map.get(&0).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_persistence() {
+        let (directory, database, dev) = open_temporary_database::<CurrentNetwork>();
+
+        // Insert a few items, directly and in a batch.
+        let map: DataMap<usize, String> = DataMap::new(database, MAP_ID);
+        map.insert(0, "0".to_string()).unwrap();
+        map.start_atomic();
+        map.insert(1, "1".to_string()).unwrap();
+        map.insert(2, "2".to_string()).unwrap();
+        map.remove(&0).unwrap();
+        map.finish_atomic().unwrap();
+        // Start a batch that is never finished.
+        map.start_atomic();
+        map.insert(3, "3".to_string()).unwrap();
+        drop(map);
+
+        // Reopen the database.
+        let database = Database::open_at(directory.path(), CurrentNetwork::ID, dev).unwrap();
+        let map: DataMap<usize, String> = DataMap::new(database, MAP_ID);
+
+        // Ensure only the committed items were persisted.
+        assert!(!map.is_atomic_in_progress());
+        let expected = vec![(1, "1".to_string()), (2, "2".to_string())];
+        let candidate = map.iter().map(|(k, v)| (*k, v.into_owned())).collect::<Vec<_>>();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_iter_across_pages() {
+        let (_directory, database, _) = open_temporary_database::<CurrentNetwork>();
+
+        // Initialize a map with more entries than fit in two pages, along with an adjacent map.
+        let map: DataMap<[u8; 2], u16> = DataMap::new(database.clone(), MAP_ID);
+        let next_map: DataMap<[u8; 2], u16> = DataMap::new(database, MapID::Program(ProgramMap::Key));
+        let num_entries = 2 * ENTRIES_PAGE_SIZE as u16 + 1;
+        map.start_atomic();
+        for i in (0..num_entries).rev() {
+            map.insert(i.to_be_bytes(), i).unwrap();
+        }
+        map.finish_atomic().unwrap();
+        next_map.insert([0, 0], u16::MAX).unwrap();
+
+        // Ensure every entry of the map is returned exactly once, in key order.
+        let expected = (0..num_entries).map(|i| (i.to_be_bytes(), i)).collect::<Vec<_>>();
+        let candidate = map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>();
+        assert_eq!(expected, candidate);
+        assert_eq!(map.keys().map(|k| *k).collect::<Vec<_>>(), expected.iter().map(|(k, _)| *k).collect::<Vec<_>>());
+        assert_eq!(map.values().map(|v| *v).collect::<Vec<_>>(), expected.iter().map(|(_, v)| *v).collect::<Vec<_>>());
+
+        // Ensure a range across pages returns the entries after the given key, up to the limit.
+        let candidate = map.get_prefix_range(&(), Some(&[0, 10]), ENTRIES_PAGE_SIZE).unwrap();
+        let candidate = candidate.into_iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>();
+        assert_eq!(&expected[11..11 + ENTRIES_PAGE_SIZE], &candidate[..]);
+    }
+}
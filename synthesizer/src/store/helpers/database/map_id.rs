@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The ID of a map in the database.
+/// Note: The IDs are part of the on-disk format, and must never be reordered or reused.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MapID {
+    Block(BlockMap),
+    Transaction(TransactionMap),
+    Deployment(DeploymentMap),
+    Execution(ExecutionMap),
+    Transition(TransitionMap),
+    TransitionInput(TransitionInputMap),
+    TransitionOutput(TransitionOutputMap),
+    Program(ProgramMap),
+    Consensus(ConsensusMap),
+}
+
+impl From<MapID> for u16 {
+    fn from(map_id: MapID) -> u16 {
+        match map_id {
+            MapID::Block(id) => id as u16,
+            MapID::Transaction(id) => id as u16,
+            MapID::Deployment(id) => id as u16,
+            MapID::Execution(id) => id as u16,
+            MapID::Transition(id) => id as u16,
+            MapID::TransitionInput(id) => id as u16,
+            MapID::TransitionOutput(id) => id as u16,
+            MapID::Program(id) => id as u16,
+            MapID::Consensus(id) => id as u16,
+        }
+    }
+}
+
+/// The block maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum BlockMap {
+    StateRoot = 0x0100,
+    ReverseStateRoot = 0x0101,
+    ID = 0x0102,
+    ReverseID = 0x0103,
+    Header = 0x0104,
+    Transactions = 0x0105,
+    ReverseTransactions = 0x0106,
+    CoinbaseSolution = 0x0107,
+    CoinbasePuzzleCommitment = 0x0108,
+    Signature = 0x0109,
+    Pruned = 0x010A,
+    /// Note: This map is only read to migrate storage from schema version 6, as it is superseded by `PrunedPath`.
+    StatePath = 0x010B,
+    BlockIndex = 0x010C,
+    TransactionStatus = 0x010D,
+    LedgerDigest = 0x010E,
+    PrunedPath = 0x010F,
+}
+
+/// The transaction maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TransactionMap {
+    ID = 0x0200,
+    Aborted = 0x0201,
+    AbortedFee = 0x0202,
+}
+
+/// The deployment maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum DeploymentMap {
+    ID = 0x0300,
+    Edition = 0x0301,
+    ReverseID = 0x0302,
+    Owner = 0x0303,
+    Program = 0x0304,
+    VerifyingKey = 0x0305,
+    Certificate = 0x0306,
+    Fee = 0x0307,
+    ReverseFee = 0x0308,
+}
+
+/// The execution maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ExecutionMap {
+    ID = 0x0400,
+    ReverseID = 0x0401,
+    Inclusion = 0x0402,
+    Fee = 0x0403,
+}
+
+/// The transition maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TransitionMap {
+    Locator = 0x0500,
+    Finalize = 0x0501,
+    Proof = 0x0502,
+    TPK = 0x0503,
+    ReverseTPK = 0x0504,
+    TCM = 0x0505,
+    ReverseTCM = 0x0506,
+    FunctionIndex = 0x0507,
+}
+
+/// The transition input maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TransitionInputMap {
+    ID = 0x0600,
+    ReverseID = 0x0601,
+    Constant = 0x0602,
+    Public = 0x0603,
+    Private = 0x0604,
+    Record = 0x0605,
+    RecordTag = 0x0606,
+    ExternalRecord = 0x0607,
+}
+
+/// The transition output maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TransitionOutputMap {
+    ID = 0x0700,
+    ReverseID = 0x0701,
+    Constant = 0x0702,
+    Public = 0x0703,
+    Private = 0x0704,
+    Record = 0x0705,
+    RecordNonce = 0x0706,
+    ExternalRecord = 0x0707,
+}
+
+/// The program maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum ProgramMap {
+    ProgramID = 0x0800,
+    ProgramIndex = 0x0801,
+    MappingID = 0x0802,
+    KeyValueID = 0x0803,
+    Key = 0x0804,
+    Value = 0x0805,
+    ChangeSet = 0x0806,
+    KeyHistory = 0x0807,
+    KeyIndex = 0x0808,
+}
+
+/// The consensus maps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum ConsensusMap {
+    Schema = 0x0900,
+}
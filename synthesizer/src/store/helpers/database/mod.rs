@@ -0,0 +1,465 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod log;
+use log::*;
+
+mod map;
+pub use map::*;
+
+mod map_id;
+pub use map_id::*;
+
+mod block;
+pub use block::*;
+
+mod consensus;
+pub use consensus::*;
+
+mod program;
+pub use program::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod transition;
+pub use transition::*;
+
+use console::network::prelude::*;
+use indexmap::IndexMap;
+
+use core::hash::Hash;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock, RwLockWriteGuard};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Bound,
+    path::{Path, PathBuf},
+    sync::{Arc, Weak},
+    thread::ThreadId,
+};
+
+/// The file name of the write log, inside the ledger directory.
+const LOG_FILE_NAME: &str = "ledger.log";
+/// The number of entries that are read from the database at a time, while iterating over a prefix.
+const ENTRIES_PAGE_SIZE: usize = 1024;
+
+/// The databases that are currently open, keyed by `(network ID, development ID)`.
+/// Note: All of the maps of a ledger share a single database, so that an atomic batch
+/// spanning several stores is committed (or discarded) as a whole.
+static DATABASES: Lazy<Mutex<HashMap<(u16, Option<u16>), Weak<InnerDatabase>>>> = Lazy::new(Default::default);
+
+/// A persistent key-value database, backed by an append-only write log.
+///
+/// Every committed write is appended to the log as a single checksummed batch, and synced to disk
+/// before it becomes visible to readers. On open, the log is replayed into memory; a batch at the end
+/// of the log that was only partially written (e.g. due to a crash) is discarded, while a corrupted
+/// batch in the middle of the log fails the open. As a result, an atomic batch is either persisted
+/// in full or not at all.
+///
+/// Note: Every entry of the ledger is held in memory. To bound the size of the log, and thereby the time
+/// to replay it on open, the log is compacted once it consists mostly of overwritten entries, both on open
+/// and as batches are written.
+#[derive(Clone)]
+pub struct Database {
+    inner: Arc<InnerDatabase>,
+}
+
+struct InnerDatabase {
+    /// The ledger directory.
+    path: PathBuf,
+    /// The key-value entries.
+    map: RwLock<BTreeMap<Vec<u8>, Vec<u8>>>,
+    /// The write log.
+    log: Mutex<Log>,
+    /// The atomic batches in progress, keyed by the thread that started each batch.
+    /// Note: A batch is scoped to its thread, so that a write from another thread neither joins nor
+    /// observes the batch, and is written to the database directly instead. This differs from `MemoryMap`,
+    /// whose batch is scoped to the map, and is joined by a write from any thread while it is in progress.
+    atomic_batches: Mutex<HashMap<ThreadId, AtomicBatch>>,
+}
+
+/// An atomic batch in progress.
+#[derive(Default)]
+struct AtomicBatch {
+    /// The number of nested atomic operations in progress.
+    depth: usize,
+    /// The queued operations.
+    operations: IndexMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl Database {
+    /// Opens the database for the given network and optional development ID.
+    /// If the database is already open, the existing instance is returned.
+    /// Otherwise, the database is opened in the default ledger directory.
+    pub fn open(network: u16, dev: Option<u16>) -> Result<Self> {
+        Self::open_inner(network, dev, None)
+    }
+
+    /// Opens the database for the given network and optional development ID, in the given directory.
+    /// If the database is already open, the existing instance is returned.
+    pub fn open_at<P: AsRef<Path>>(path: P, network: u16, dev: Option<u16>) -> Result<Self> {
+        Self::open_inner(network, dev, Some(path.as_ref()))
+    }
+
+    /// Opens a map with the given ID, in the database for the given network and optional development ID.
+    pub fn open_map<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    >(
+        network: u16,
+        dev: Option<u16>,
+        map_id: MapID,
+    ) -> Result<DataMap<K, V>> {
+        Ok(DataMap::new(Self::open(network, dev)?, map_id))
+    }
+
+    /// Returns the ledger directory.
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Opens the database, reusing the existing instance if it is already open.
+    fn open_inner(network: u16, dev: Option<u16>, path: Option<&Path>) -> Result<Self> {
+        // Acquire the lock on the open databases.
+        let mut databases = DATABASES.lock();
+
+        // If the database is already open, return the existing instance.
+        if let Some(inner) = databases.get(&(network, dev)).and_then(Weak::upgrade) {
+            if let Some(path) = path {
+                ensure!(
+                    inner.path == path,
+                    "The ledger for network {network} is already open in '{}'",
+                    inner.path.display()
+                );
+            }
+            return Ok(Self { inner });
+        }
+
+        // Determine the ledger directory.
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => aleo_std::aleo_ledger_dir(network, dev),
+        };
+        // Ensure the ledger directory is not in use by another open database.
+        if databases.values().filter_map(Weak::upgrade).any(|inner| inner.path == path) {
+            bail!("The ledger directory '{}' is already in use", path.display())
+        }
+
+        // Open the write log, and replay it into memory.
+        std::fs::create_dir_all(&path)?;
+        let (log, map) = Log::open(&path.join(LOG_FILE_NAME))?;
+
+        // Initialize the database.
+        let inner = Arc::new(InnerDatabase {
+            path,
+            map: RwLock::new(map),
+            log: Mutex::new(log),
+            atomic_batches: Default::default(),
+        });
+        // Register the database, dropping any entries of closed databases.
+        databases.retain(|_, inner| inner.strong_count() > 0);
+        databases.insert((network, dev), Arc::downgrade(&inner));
+
+        Ok(Self { inner })
+    }
+}
+
+impl Database {
+    /// Returns `true` if the given key exists in the database.
+    fn contains_key(&self, key: &[u8]) -> bool {
+        self.inner.map.read().contains_key(key)
+    }
+
+    /// Returns the value for the given key, if it exists.
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.inner.map.read().get(key).cloned()
+    }
+
+    /// Returns an iterator over the entries whose keys start with the given prefix, in key order.
+    fn entries(&self, prefix: &[u8]) -> Entries {
+        self.entries_from(prefix, Bound::Included(prefix.to_vec()))
+    }
+
+    /// Returns an iterator over the entries whose keys start with the given prefix, in key order,
+    /// starting from the given bound.
+    fn entries_from(&self, prefix: &[u8], start: Bound<Vec<u8>>) -> Entries {
+        Entries { database: self.clone(), prefix: prefix.to_vec(), next: Some(start), page: Vec::new().into_iter() }
+    }
+
+    /// Writes the given operations to the database, as a single durable batch.
+    fn write(&self, operations: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<()> {
+        // Acquire the lock on the write log, to order the batches in the log and in memory alike.
+        let mut log = self.inner.log.lock();
+        // Persist the batch, before it becomes visible to readers.
+        log.append(&operations)?;
+
+        // Apply the batch in memory, tracking the size of the live entries.
+        let mut map = self.inner.map.write();
+        let (mut inserted_size, mut removed_size) = (0u64, 0u64);
+        for (key, value) in operations {
+            let key_size = key.len() as u64;
+            let previous = match value {
+                Some(value) => {
+                    inserted_size += key_size + value.len() as u64;
+                    map.insert(key, value)
+                }
+                None => map.remove(&key),
+            };
+            if let Some(previous) = previous {
+                removed_size += key_size + previous.len() as u64;
+            }
+        }
+        log.update_live_size(inserted_size, removed_size);
+
+        // If the log consists mostly of overwritten entries, compact it.
+        // Note: The batch is already committed, so a failed compaction does not fail the write,
+        // and is retried on the next batch.
+        if log.is_compaction_due() {
+            let map = RwLockWriteGuard::downgrade(map);
+            if let Err(error) = log.compact(&map) {
+                warn!("Failed to compact the storage log - {error}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts (or removes, if `value` is `None`) the given key, either directly or
+    /// by queueing the operation in the atomic batch, if one is in progress.
+    fn put(&self, key: Vec<u8>, value: Option<Vec<u8>>) -> Result<()> {
+        // If a batch is in progress on this thread, add the operation to the batch.
+        if let Some(batch) = self.inner.atomic_batches.lock().get_mut(&std::thread::current().id()) {
+            batch.operations.insert(key, value);
+            return Ok(());
+        }
+        // Otherwise, write the operation directly to the database.
+        self.write(vec![(key, value)])
+    }
+
+    /// Returns the queued operation for the given key, if an atomic batch is in progress on this thread.
+    fn get_batched(&self, key: &[u8]) -> Option<Option<Vec<u8>>> {
+        self.inner.atomic_batches.lock().get(&std::thread::current().id())?.operations.get(key).cloned()
+    }
+
+    /// Begins an atomic operation on this thread. If one is already in progress, it is nested into it.
+    fn start_atomic(&self) {
+        // Increment the atomic depth, starting a new (empty) atomic batch if none is in progress.
+        self.inner.atomic_batches.lock().entry(std::thread::current().id()).or_default().depth += 1;
+    }
+
+    /// Checks whether an atomic operation is currently in progress on this thread.
+    fn is_atomic_in_progress(&self) -> bool {
+        self.inner.atomic_batches.lock().contains_key(&std::thread::current().id())
+    }
+
+    /// Aborts the current atomic operation on this thread, including any operations it is nested in.
+    fn abort_atomic(&self) {
+        // Discard the atomic batch.
+        self.inner.atomic_batches.lock().remove(&std::thread::current().id());
+    }
+
+    /// Finishes an atomic operation on this thread. Once the outermost atomic operation finishes,
+    /// all of the queued operations are written to the database as a single batch.
+    fn finish_atomic(&self) -> Result<()> {
+        let operations = {
+            let mut batches = self.inner.atomic_batches.lock();
+            // Decrement the atomic depth, if an atomic batch is in progress.
+            let thread_id = std::thread::current().id();
+            let batch = match batches.get_mut(&thread_id) {
+                Some(batch) => batch,
+                None => return Ok(()),
+            };
+            batch.depth -= 1;
+            // If this is not the outermost atomic operation, the batch remains in progress.
+            if batch.depth > 0 {
+                return Ok(());
+            }
+            // Otherwise, retrieve the atomic batch.
+            batches.remove(&thread_id).map(|batch| batch.operations).unwrap_or_default()
+        };
+
+        // Write the atomic batch.
+        if !operations.is_empty() {
+            self.write(operations.into_iter().collect())?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the entries of the database whose keys start with a given prefix, in key order.
+/// Note: The entries are read in pages, so that iterating over a prefix neither copies all of its entries
+/// up front nor holds the lock on the database throughout. As such, the iterator is not a snapshot;
+/// writes that are committed while it is in progress are observed if they belong to a later page.
+struct Entries {
+    /// The database.
+    database: Database,
+    /// The key prefix.
+    prefix: Vec<u8>,
+    /// The lower bound of the next page, or `None` if there are no pages left.
+    next: Option<Bound<Vec<u8>>>,
+    /// The remaining entries of the current page.
+    page: std::vec::IntoIter<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Iterator for Entries {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // Return the next entry of the current page.
+            if let Some(entry) = self.page.next() {
+                return Some(entry);
+            }
+            // Otherwise, read the next page.
+            let page = self
+                .database
+                .inner
+                .map
+                .read()
+                .range((self.next.take()?, Bound::Unbounded))
+                .take_while(|(key, _)| key.starts_with(&self.prefix))
+                .take(ENTRIES_PAGE_SIZE)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>();
+            // If the page is full, resume after its last key.
+            if page.len() == ENTRIES_PAGE_SIZE {
+                self.next = page.last().map(|(key, _)| Bound::Excluded(key.clone()));
+            }
+            // If the page is empty, the iteration is complete.
+            if page.is_empty() {
+                return None;
+            }
+            self.page = page.into_iter();
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    use std::sync::atomic::{AtomicU16, Ordering};
+
+    /// The next development ID to assign to a temporary database.
+    static NEXT_DEV: AtomicU16 = AtomicU16::new(0);
+
+    /// Returns a development ID that is unique within this process.
+    pub(crate) fn sample_dev() -> Option<u16> {
+        Some(NEXT_DEV.fetch_add(1, Ordering::SeqCst))
+    }
+
+    /// Opens a database in a new temporary directory, and returns the directory, the database, and its development ID.
+    /// Note: The database must be kept alive while stores are opened with the returned development ID.
+    pub(crate) fn open_temporary_database<N: Network>() -> (tempfile::TempDir, Database, Option<u16>) {
+        let directory = tempfile::tempdir().unwrap();
+        let dev = sample_dev();
+        let database = Database::open_at(directory.path(), N::ID, dev).unwrap();
+        (directory, database, dev)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_helpers::open_temporary_database, *};
+    use crate::{
+        atomic_write_batch,
+        store::{
+            helpers::{Map, MapRead},
+            BlockStorage,
+            ConsensusStore,
+        },
+    };
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext, ProgramID, Value},
+        types::Field,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Commits a batch that spans the program store and the block storage.
+    fn commit_batch(
+        store: &ConsensusStore<CurrentNetwork, ConsensusDB<CurrentNetwork>>,
+        block_storage: &BlockDB<CurrentNetwork>,
+    ) -> Result<()> {
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let mapping_name = Identifier::from_str("account")?;
+        atomic_write_batch!(store, {
+            store.program_store().initialize_mapping(&program_id, &mapping_name)?;
+            let (key, value) = (Plaintext::from_str("1field")?, Value::from_str("1u64")?);
+            store.program_store().insert_key_value(&program_id, &mapping_name, key, value)?;
+            block_storage.reverse_id_map().insert(Field::<CurrentNetwork>::from_u64(1).into(), 1)
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_compaction_while_writing() {
+        let (directory, database, dev) = open_temporary_database::<CurrentNetwork>();
+        let log_path = directory.path().join(LOG_FILE_NAME);
+
+        // Overwrite the same key, so that the log consists mostly of overwritten entries.
+        let value_size = COMPACTION_THRESHOLD as usize / 2;
+        for i in 0..4u8 {
+            database.write(vec![(vec![0], Some(vec![i; value_size]))]).unwrap();
+            // Ensure the log is compacted, once it exceeds the compaction threshold.
+            assert!(std::fs::metadata(&log_path).unwrap().len() <= COMPACTION_THRESHOLD);
+        }
+        drop(database);
+
+        // Reopen the database, and ensure the latest value is replayed.
+        let database = Database::open_at(directory.path(), CurrentNetwork::ID, dev).unwrap();
+        assert_eq!(database.get(&[0]), Some(vec![3; value_size]));
+    }
+
+    #[test]
+    fn test_cross_store_batch_recovery() {
+        let (directory, database, dev) = open_temporary_database::<CurrentNetwork>();
+        let log_path = directory.path().join(LOG_FILE_NAME);
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let block_hash: <CurrentNetwork as Network>::BlockHash = Field::<CurrentNetwork>::from_u64(1).into();
+
+        // Commit a batch that spans several stores.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        let block_storage = BlockDB::<CurrentNetwork>::open(dev).unwrap();
+        let log_size = std::fs::metadata(&log_path).unwrap().len();
+        commit_batch(&store, &block_storage).unwrap();
+        let batch_size = std::fs::metadata(&log_path).unwrap().len() - log_size;
+        drop((store, block_storage, database));
+
+        // Simulate a crash partway through writing the batch, by truncating the write log.
+        std::fs::OpenOptions::new().write(true).open(&log_path).unwrap().set_len(log_size + batch_size / 2).unwrap();
+
+        // Reopen the database, and ensure the torn batch is rolled back in every store.
+        let database = Database::open_at(directory.path(), CurrentNetwork::ID, dev).unwrap();
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        let block_storage = BlockDB::<CurrentNetwork>::open(dev).unwrap();
+        assert_eq!(log_size, std::fs::metadata(&log_path).unwrap().len());
+        assert!(!store.program_store().contains_program(&program_id).unwrap());
+        assert!(block_storage.reverse_id_map().get(&block_hash).unwrap().is_none());
+
+        // Commit the batch again, and ensure it is replayed into every store on reopen.
+        commit_batch(&store, &block_storage).unwrap();
+        let checksum = store.program_store().get_checksum().unwrap();
+        drop((store, block_storage, database));
+        let _database = Database::open_at(directory.path(), CurrentNetwork::ID, dev).unwrap();
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        let block_storage = BlockDB::<CurrentNetwork>::open(dev).unwrap();
+        assert!(store.program_store().contains_program(&program_id).unwrap());
+        assert_eq!(checksum, store.program_store().get_checksum().unwrap());
+        assert!(block_storage.reverse_id_map().get(&block_hash).unwrap().is_some());
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::{
+    helpers::database::{DataMap, Database, MapID, ProgramMap},
+    MappingChange,
+    ProgramStorage,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A database program state storage.
+#[derive(Clone)]
+pub struct ProgramDB<N: Network> {
+    /// The program ID map.
+    program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
+    /// The program index map.
+    program_index_map: DataMap<ProgramID<N>, u32>,
+    /// The mapping ID map.
+    mapping_id_map: DataMap<(ProgramID<N>, Identifier<N>), Field<N>>,
+    /// The key-value ID map.
+    key_value_id_map: DataMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
+    /// The key map.
+    key_map: DataMap<Field<N>, Plaintext<N>>,
+    /// The key index map.
+    key_index_map: DataMap<(Field<N>, Field<N>), ()>,
+    /// The value map.
+    value_map: DataMap<Field<N>, Value<N>>,
+    /// The change set map.
+    change_set_map: DataMap<u32, Vec<MappingChange<N>>>,
+    /// The key history map.
+    key_history_map: DataMap<Field<N>, Vec<u32>>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ProgramStorage<N> for ProgramDB<N> {
+    type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
+    type ProgramIndexMap = DataMap<ProgramID<N>, u32>;
+    type MappingIDMap = DataMap<(ProgramID<N>, Identifier<N>), Field<N>>;
+    type KeyValueIDMap = DataMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
+    type KeyMap = DataMap<Field<N>, Plaintext<N>>;
+    type KeyIndexMap = DataMap<(Field<N>, Field<N>), ()>;
+    type ValueMap = DataMap<Field<N>, Value<N>>;
+    type ChangeSetMap = DataMap<u32, Vec<MappingChange<N>>>;
+    type KeyHistoryMap = DataMap<Field<N>, Vec<u32>>;
+
+    /// Initializes the program state storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            program_id_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::ProgramID))?,
+            program_index_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::ProgramIndex))?,
+            mapping_id_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::MappingID))?,
+            key_value_id_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::Key))?,
+            key_index_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyIndex))?,
+            value_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::Value))?,
+            change_set_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::ChangeSet))?,
+            key_history_map: Database::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyHistory))?,
+            dev,
+        })
+    }
+
+    /// Returns a read-only view of the program state storage.
+    /// Note: Read views are not supported by the database storage.
+    fn read_view(&self) -> Result<Self> {
+        bail!("Read views are not supported by the database storage")
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        &self.program_id_map
+    }
+    
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap {
+        &self.program_index_map
+    }
+
+    /// Returns the mapping ID map.
+    fn mapping_id_map(&self) -> &Self::MappingIDMap {
+        &self.mapping_id_map
+    }
+
+    /// Returns the key-value ID map.
+    fn key_value_id_map(&self) -> &Self::KeyValueIDMap {
+        &self.key_value_id_map
+    }
+
+    /// Returns the key map.
+    fn key_map(&self) -> &Self::KeyMap {
+        &self.key_map
+    }
+
+    /// Returns the key index map.
+    fn key_index_map(&self) -> &Self::KeyIndexMap {
+        &self.key_index_map
+    }
+
+    /// Returns the value map.
+    fn value_map(&self) -> &Self::ValueMap {
+        &self.value_map
+    }
+
+    /// Returns the change set map.
+    fn change_set_map(&self) -> &Self::ChangeSetMap {
+        &self.change_set_map
+    }
+
+    /// Returns the key history map.
+    fn key_history_map(&self) -> &Self::KeyHistoryMap {
+        &self.key_history_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
@@ -18,28 +18,98 @@ use crate::store::helpers::{Map, MapRead};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, cell::RefCell, hash::Hash};
 use parking_lot::{Mutex, RwLock};
 use std::{
     borrow::Cow,
     collections::{btree_map, BTreeMap},
+    ops::Bound,
+    panic::{self, AssertUnwindSafe},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
 };
 
+thread_local! {
+    /// The journal of the atomic batch that is being finished on this thread.
+    static JOURNAL: RefCell<Journal> = RefCell::new(Journal::default());
+}
+
+/// A write-ahead journal of the queued writes of a multi-map atomic batch.
+///
+/// While a batch is being finished, each map records its writes in the journal, instead of applying them.
+/// Once every map has finished, the journal is replayed into the maps; if any map fails to finish,
+/// the journal is discarded instead, so that no map applies its part of an incomplete batch.
+/// Note: The journal is kept in memory, on the thread that finishes the batch, as the memory maps do not
+/// outlive the process, and so there is nothing to recover after a crash. This replay is the whole recovery
+/// routine: a map fails to finish only while preparing its writes (e.g. serializing its keys), which happens
+/// before anything is journaled, and the replay itself only inserts into and removes from the maps, which
+/// cannot fail. So a batch is either discarded before any map applies it, or applied by every map. A panic
+/// while finishing is caught, and discards the journal as well. The database-backed maps do not
+/// use the journal: they share one database, which appends a batch that spans several stores to its write
+/// log as a single record, before applying it. On open, the log is replayed, and a record that was torn
+/// by a crash is rolled back, so that no store observes a part of the batch (see `Database`).
+#[derive(Default)]
+struct Journal {
+    /// The number of nested journaled calls to `finish_atomic` in progress.
+    depth: usize,
+    /// Whether any of the nested calls failed.
+    is_failed: bool,
+    /// The writes of each finished map, in the order the maps finished.
+    entries: Vec<Box<dyn FnOnce()>>,
+}
+
+/// Finishes an atomic batch with the given `finish` function, which may finish several maps.
+/// The writes of every `MemoryMap` that finishes in the meantime are journaled, and applied
+/// once the outermost journaled call succeeds. If any call fails, the writes are discarded.
+pub fn finish_atomic_journaled(finish: impl FnOnce() -> Result<()>) -> Result<()> {
+    // Begin the journal, or join the journal in progress.
+    JOURNAL.with(|journal| journal.borrow_mut().depth += 1);
+
+    // Finish the batch.
+    // Note: A panic is caught here, so that it cannot leave the journal open on this thread.
+    let result = panic::catch_unwind(AssertUnwindSafe(finish));
+
+    // Close the journal, and take it if this is the outermost call.
+    let journal = JOURNAL.with(|journal| {
+        let mut journal = journal.borrow_mut();
+        journal.is_failed |= !matches!(result, Ok(Ok(())));
+        journal.depth -= 1;
+        match journal.depth {
+            0 => Some(core::mem::take(&mut *journal)),
+            _ => None,
+        }
+    });
+
+    // Propagate the result of the batch.
+    let result = result.unwrap_or_else(|payload| panic::resume_unwind(payload));
+    result?;
+
+    // If this is the outermost call, replay the journal.
+    if let Some(journal) = journal {
+        ensure!(!journal.is_failed, "Failed to finish a nested atomic batch");
+        for apply in journal.entries {
+            apply();
+        }
+    }
+    Ok(())
+}
+
 #[derive(Clone)]
 pub struct MemoryMap<
     K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
     V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
 > {
     // The reason for using BTreeMap with binary keys is for the order of items to be the same as
-    // the one in the RocksDB-backed DataMap; if not for that, it could be any map
+    // the one in the database-backed DataMap; if not for that, it could be any map
     // with fast lookups and the keys could be typed (i.e. just `K` instead of `Vec<u8>`).
-    map: Arc<RwLock<BTreeMap<Vec<u8>, V>>>,
+    // The map is shared with any read views, and is copied on the first write after a view is taken.
+    map: Arc<RwLock<Arc<BTreeMap<Vec<u8>, V>>>>,
     batch_in_progress: Arc<AtomicBool>,
     atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+    // Whether the map is a read-only view of another map.
+    read_only: bool,
 }
 
 impl<
@@ -48,7 +118,12 @@ impl<
 > Default for MemoryMap<K, V>
 {
     fn default() -> Self {
-        Self { map: Default::default(), batch_in_progress: Default::default(), atomic_batch: Default::default() }
+        Self {
+            map: Default::default(),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            read_only: false,
+        }
     }
 }
 
@@ -64,23 +139,26 @@ impl<
         let map = iter.into_iter().map(|(k, v)| (bincode::serialize(&k).unwrap(), v)).collect();
         // Return the new map.
         Self {
-            map: Arc::new(RwLock::new(map)),
+            map: Arc::new(RwLock::new(Arc::new(map))),
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            read_only: false,
         }
     }
 }
 
 impl<
     'a,
-    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
-    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    K: 'static + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'static + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
 > Map<'a, K, V> for MemoryMap<K, V>
 {
     ///
     /// Inserts the given key-value pair into the map.
     ///
     fn insert(&self, key: K, value: V) -> Result<()> {
+        // Ensure the map is not a read view.
+        ensure!(!self.read_only, "Cannot insert into a read view of the map");
         // Determine if an atomic batch is in progress.
         let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
 
@@ -91,7 +169,7 @@ impl<
             }
             // Otherwise, insert the key-value pair directly into the map.
             false => {
-                self.map.write().insert(bincode::serialize(&key)?, value);
+                Arc::make_mut(&mut *self.map.write()).insert(bincode::serialize(&key)?, value);
             }
         }
 
@@ -102,6 +180,8 @@ impl<
     /// Removes the key-value pair for the given key from the map.
     ///
     fn remove(&self, key: &K) -> Result<()> {
+        // Ensure the map is not a read view.
+        ensure!(!self.read_only, "Cannot remove from a read view of the map");
         // Determine if an atomic batch is in progress.
         let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
 
@@ -112,7 +192,7 @@ impl<
             }
             // Otherwise, remove the key-value pair directly from the map.
             false => {
-                self.map.write().remove(&bincode::serialize(&key)?);
+                Arc::make_mut(&mut *self.map.write()).remove(&bincode::serialize(&key)?);
             }
         }
 
@@ -157,9 +237,6 @@ impl<
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
         if !operations.is_empty() {
-            // Acquire a write lock on the map.
-            let mut locked_map = self.map.write();
-
             // Prepare the key for each queued operation.
             // Note: This step is taken to ensure (with 100% certainty) that there will be
             // no chance to fail partway through committing the queued operations.
@@ -168,12 +245,34 @@ impl<
                 .map(|(key, value)| Ok((bincode::serialize(&key)?, value)))
                 .collect::<Result<Vec<_>>>()?;
 
-            // Perform all the queued operations.
-            for (key, value) in prepared_operations {
-                match value {
-                    Some(value) => locked_map.insert(key, value),
-                    None => locked_map.remove(&key),
-                };
+            // Prepare the queued operations.
+            let map = self.map.clone();
+            let apply = move || {
+                // Acquire a write lock on the map, copying it if it is shared with a read view.
+                let mut locked_map = map.write();
+                let locked_map = Arc::make_mut(&mut *locked_map);
+                // Perform all the queued operations.
+                for (key, value) in prepared_operations {
+                    match value {
+                        Some(value) => locked_map.insert(key, value),
+                        None => locked_map.remove(&key),
+                    };
+                }
+            };
+
+            // If a journal is in progress, record the operations in it. Otherwise, perform them now.
+            let apply = JOURNAL.with(|journal| {
+                let mut journal = journal.borrow_mut();
+                match journal.depth {
+                    0 => Some(apply),
+                    _ => {
+                        journal.entries.push(Box::new(apply));
+                        None
+                    }
+                }
+            });
+            if let Some(apply) = apply {
+                apply();
             }
         }
 
@@ -182,6 +281,24 @@ impl<
 
         Ok(())
     }
+
+    ///
+    /// Returns a read-only view of the map, as of the last finished atomic operation.
+    /// The view is unaffected by any later writes to the map, and any writes to the view fail.
+    ///
+    fn read_view(&self) -> Result<Self> {
+        // Share the map, which excludes the queued writes of any atomic batch in progress.
+        // Note: If the view is still alive at the next write to the map, the map is copied before it is written,
+        // so that the view is unaffected by later writes. Taking a view is therefore constant-time.
+        let map = self.map.read().clone();
+        // Return the read view.
+        Ok(Self {
+            map: Arc::new(RwLock::new(map)),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            read_only: true,
+        })
+    }
 }
 
 impl<
@@ -238,7 +355,10 @@ impl<
     ///
     fn iter(&'a self) -> Self::Iterator {
         // Note: The 'unwrap' is safe here, because the keys are defined by us.
-        self.map.read().clone().into_iter().map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v)))
+        (**self.map.read())
+            .clone()
+            .into_iter()
+            .map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v)))
     }
 
     ///
@@ -246,14 +366,42 @@ impl<
     ///
     fn keys(&'a self) -> Self::Keys {
         // Note: The 'unwrap' is safe here, because the keys are defined by us.
-        self.map.read().clone().into_keys().map(|k| Cow::Owned(bincode::deserialize(&k).unwrap()))
+        (**self.map.read()).clone().into_keys().map(|k| Cow::Owned(bincode::deserialize(&k).unwrap()))
     }
 
     ///
     /// Returns an iterator over each value in the map.
     ///
     fn values(&'a self) -> Self::Values {
-        self.map.read().clone().into_values().map(Cow::Owned)
+        (**self.map.read()).clone().into_values().map(Cow::Owned)
+    }
+
+    ///
+    /// Returns up to `limit` key-value pairs whose keys start with the given `prefix`, in key order.
+    /// The pairs start after the key `after`, or at the first key with the prefix if `after` is `None`.
+    ///
+    fn get_prefix_range<P: Serialize + ?Sized>(
+        &'a self,
+        prefix: &P,
+        after: Option<&K>,
+        limit: usize,
+    ) -> Result<Vec<(Cow<'a, K>, Cow<'a, V>)>> {
+        // Determine the bounds of the range.
+        let prefix = bincode::serialize(prefix)?;
+        let start = match after {
+            Some(after) => Bound::Excluded(bincode::serialize(after)?),
+            None => Bound::Included(prefix.clone()),
+        };
+        // Retrieve the key-value pairs in the range.
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        Ok(self
+            .map
+            .read()
+            .range((start, Bound::Unbounded))
+            .take_while(|(k, _)| k.starts_with(&prefix))
+            .take(limit)
+            .map(|(k, v)| (Cow::Owned(bincode::deserialize(k).unwrap()), Cow::Owned(v.clone())))
+            .collect())
     }
 }
 
@@ -262,13 +410,176 @@ impl<
     V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
 > Deref for MemoryMap<K, V>
 {
-    type Target = Arc<RwLock<BTreeMap<Vec<u8>, V>>>;
+    type Target = Arc<RwLock<Arc<BTreeMap<Vec<u8>, V>>>>;
 
     fn deref(&self) -> &Self::Target {
         &self.map
     }
 }
 
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    use core::cell::Cell;
+
+    thread_local! {
+        /// The number of calls to `FailingMap::finish_atomic` on this thread that succeed before one fails, if set.
+        static FINISHES_BEFORE_FAILURE: Cell<Option<usize>> = const { Cell::new(None) };
+    }
+
+    /// Injects a failure into a call to `FailingMap::finish_atomic` on this thread, after the given number of
+    /// calls succeed. If `None` is given, any injected failure that has not happened yet is cleared.
+    pub(crate) fn fail_finish_atomic_after(num_finishes: Option<usize>) {
+        FINISHES_BEFORE_FAILURE.with(|cell| cell.set(num_finishes));
+    }
+
+    /// Runs the given operation with a failure injected into each of its calls to `finish_atomic` in turn,
+    /// until the operation succeeds. After each failure, `check_unchanged` must ensure that none of the
+    /// operation was applied.
+    pub(crate) fn fail_each_finish_atomic(
+        mut operation: impl FnMut() -> Result<()>,
+        mut check_unchanged: impl FnMut(),
+    ) {
+        for num_finishes in 0.. {
+            fail_finish_atomic_after(Some(num_finishes));
+            let result = operation();
+            fail_finish_atomic_after(None);
+            if result.is_ok() {
+                // Ensure the failures covered several maps.
+                assert!(num_finishes > 2, "Expected the operation to finish more than two maps");
+                return;
+            }
+            check_unchanged();
+        }
+    }
+
+    /// Returns an error if a failure was injected into this call to `FailingMap::finish_atomic`.
+    fn check_injected_failure() -> Result<()> {
+        FINISHES_BEFORE_FAILURE.with(|cell| match cell.get() {
+            Some(0) => {
+                cell.set(None);
+                bail!("Injected a failure into 'finish_atomic'")
+            }
+            Some(num_finishes) => {
+                cell.set(Some(num_finishes - 1));
+                Ok(())
+            }
+            None => Ok(()),
+        })
+    }
+
+    /// A `MemoryMap` that fails to finish an atomic batch when a failure is injected on this thread.
+    /// Note: Only the failing storages of the tests that inject failures use this map, e.g. `FailingBlockMemory`.
+    #[derive(Clone)]
+    pub struct FailingMap<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    >(MemoryMap<K, V>);
+
+    impl<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > Default for FailingMap<K, V>
+    {
+        fn default() -> Self {
+            Self(MemoryMap::default())
+        }
+    }
+
+    impl<
+        'a,
+        K: 'static + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: 'static + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > Map<'a, K, V> for FailingMap<K, V>
+    {
+        fn insert(&self, key: K, value: V) -> Result<()> {
+            self.0.insert(key, value)
+        }
+
+        fn remove(&self, key: &K) -> Result<()> {
+            self.0.remove(key)
+        }
+
+        fn start_atomic(&self) {
+            self.0.start_atomic()
+        }
+
+        fn is_atomic_in_progress(&self) -> bool {
+            self.0.is_atomic_in_progress()
+        }
+
+        fn abort_atomic(&self) {
+            self.0.abort_atomic()
+        }
+
+        fn finish_atomic(&self) -> Result<()> {
+            check_injected_failure()?;
+            self.0.finish_atomic()
+        }
+
+        fn read_view(&self) -> Result<Self> {
+            Ok(Self(self.0.read_view()?))
+        }
+    }
+
+    impl<
+        'a,
+        K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > MapRead<'a, K, V> for FailingMap<K, V>
+    {
+        type Iterator = <MemoryMap<K, V> as MapRead<'a, K, V>>::Iterator;
+        type Keys = <MemoryMap<K, V> as MapRead<'a, K, V>>::Keys;
+        type Values = <MemoryMap<K, V> as MapRead<'a, K, V>>::Values;
+
+        fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+        where
+            K: Borrow<Q>,
+            Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+        {
+            self.0.contains_key(key)
+        }
+
+        fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+        where
+            K: Borrow<Q>,
+            Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+        {
+            self.0.get(key)
+        }
+
+        fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+        where
+            K: Borrow<Q>,
+            Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+        {
+            self.0.get_batched(key)
+        }
+
+        fn iter(&'a self) -> Self::Iterator {
+            self.0.iter()
+        }
+
+        fn keys(&'a self) -> Self::Keys {
+            self.0.keys()
+        }
+
+        fn values(&'a self) -> Self::Values {
+            self.0.values()
+        }
+
+        fn get_prefix_range<P: Serialize + ?Sized>(
+            &'a self,
+            prefix: &P,
+            after: Option<&K>,
+            limit: usize,
+        ) -> Result<Vec<(Cow<'a, K>, Cow<'a, V>)>> {
+            self.0.get_prefix_range(prefix, after, limit)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,4 +810,140 @@ mod tests {
         // The map should contain NUM_ITEMS items now.
         assert_eq!(map.iter().count(), NUM_ITEMS);
     }
+
+    #[test]
+    fn test_read_view() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string())].into_iter().collect();
+
+        // Start an atomic write batch, and queue an insertion.
+        map.start_atomic();
+        map.insert(1, "1".to_string()).unwrap();
+
+        // Take a read view while the batch is in progress.
+        let view = map.read_view().unwrap();
+
+        // Finish the current atomic write batch, and write to the map directly.
+        map.finish_atomic().unwrap();
+        map.insert(2, "2".to_string()).unwrap();
+        map.remove(&0).unwrap();
+
+        // The map should contain the latest items.
+        assert_eq!(map.keys().map(|k| *k).collect::<Vec<_>>(), vec![1, 2]);
+        // The view should only contain the items as of its creation, excluding the queued insertion.
+        assert_eq!(view.iter().map(|(k, v)| (*k, v.to_string())).collect::<Vec<_>>(), vec![(0, "0".to_string())]);
+        assert!(!view.contains_key(&1).unwrap());
+
+        // The view should not be writable, even in an atomic write batch.
+        assert!(view.insert(3, "3".to_string()).is_err());
+        assert!(view.remove(&0).is_err());
+        view.start_atomic();
+        assert!(view.insert(3, "3".to_string()).is_err());
+        view.abort_atomic();
+        assert_eq!(view.iter().count(), 1);
+
+        // A view of a view should match the view.
+        let nested = view.read_view().unwrap();
+        assert_eq!(nested.keys().map(|k| *k).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn test_finish_atomic_journaled() {
+        use test_helpers::FailingMap;
+
+        // Initialize two maps.
+        let map_a: FailingMap<usize, String> = Default::default();
+        let map_b: FailingMap<usize, String> = Default::default();
+        map_a.insert(0, "a".to_string()).unwrap();
+
+        // Queue a write to each map.
+        let queue_writes = |value: &str| {
+            map_a.start_atomic();
+            map_b.start_atomic();
+            map_a.insert(0, value.to_string()).unwrap();
+            map_b.insert(0, value.to_string()).unwrap();
+        };
+        let finish = || -> Result<()> {
+            map_a.finish_atomic()?;
+            map_b.finish_atomic()
+        };
+
+        // Fail to finish the second map, after the first map finished.
+        queue_writes("x");
+        test_helpers::fail_finish_atomic_after(Some(1));
+        assert!(finish_atomic_journaled(finish).is_err());
+        map_b.abort_atomic();
+
+        // Ensure neither map applied its part of the batch.
+        assert_eq!(map_a.get(&0).unwrap(), Some(Cow::Owned("a".to_string())));
+        assert!(map_b.get(&0).unwrap().is_none());
+        assert!(!map_a.is_atomic_in_progress());
+        assert!(!map_b.is_atomic_in_progress());
+
+        // Fail to finish a nested call, and ensure the outermost call discards the journal.
+        queue_writes("y");
+        test_helpers::fail_finish_atomic_after(Some(1));
+        let result = finish_atomic_journaled(|| {
+            map_a.finish_atomic()?;
+            let _ = finish_atomic_journaled(|| map_b.finish_atomic());
+            Ok(())
+        });
+        assert!(result.is_err());
+        map_b.abort_atomic();
+        assert_eq!(map_a.get(&0).unwrap(), Some(Cow::Owned("a".to_string())));
+        assert!(map_b.get(&0).unwrap().is_none());
+
+        // Finish both maps, and ensure the writes are applied once the outermost call succeeds.
+        queue_writes("z");
+        finish_atomic_journaled(|| {
+            finish_atomic_journaled(|| map_a.finish_atomic())?;
+            // The writes of the first map are journaled until the outermost call succeeds.
+            assert_eq!(map_a.get(&0).unwrap(), Some(Cow::Owned("a".to_string())));
+            map_b.finish_atomic()
+        })
+        .unwrap();
+        assert_eq!(map_a.get(&0).unwrap(), Some(Cow::Owned("z".to_string())));
+        assert_eq!(map_b.get(&0).unwrap(), Some(Cow::Owned("z".to_string())));
+
+        // Ensure a panic does not leave the journal open.
+        map_a.start_atomic();
+        map_a.insert(1, "1".to_string()).unwrap();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _ = finish_atomic_journaled(|| -> Result<()> {
+                map_a.finish_atomic()?;
+                panic!("Panicked while finishing the batch")
+            });
+        }));
+        assert!(result.is_err());
+        assert!(map_a.get(&1).unwrap().is_none());
+        map_a.insert(2, "2".to_string()).unwrap();
+        map_a.start_atomic();
+        map_a.insert(3, "3".to_string()).unwrap();
+        map_a.finish_atomic().unwrap();
+        assert_eq!(map_a.keys().map(|k| *k).collect::<Vec<_>>(), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_get_prefix_range() {
+        // Initialize a map with composite keys under two prefixes.
+        let map: MemoryMap<(u8, [u8; 2]), ()> = Default::default();
+        for i in 0..5u8 {
+            map.insert((0, [0, i]), ()).unwrap();
+            map.insert((1, [0, i]), ()).unwrap();
+        }
+        let get_range = |after: Option<&(u8, [u8; 2])>, limit| {
+            map.get_prefix_range(&1u8, after, limit).unwrap().into_iter().map(|(k, _)| *k).collect::<Vec<_>>()
+        };
+
+        // Ensure the range only returns the keys with the prefix, in key order, up to the limit.
+        assert_eq!(get_range(None, 2), vec![(1, [0, 0]), (1, [0, 1])]);
+        assert_eq!(get_range(None, 10), (0..5).map(|i| (1, [0, i])).collect::<Vec<_>>());
+        // Ensure the range starts after the given key, even if it does not exist.
+        assert_eq!(get_range(Some(&(1, [0, 3])), 10), vec![(1, [0, 4])]);
+        map.remove(&(1, [0, 3])).unwrap();
+        assert_eq!(get_range(Some(&(1, [0, 3])), 10), vec![(1, [0, 4])]);
+        assert!(get_range(Some(&(1, [0, 4])), 10).is_empty());
+        // Ensure a prefix without keys returns an empty range.
+        assert!(map.get_prefix_range(&2u8, None, 10).unwrap().is_empty());
+    }
 }
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod database;
 pub mod memory_map;
 
 use console::network::prelude::*;
@@ -60,6 +61,14 @@ pub trait Map<
     /// Finishes an atomic operation, performing all the queued writes.
     ///
     fn finish_atomic(&self) -> Result<()>;
+
+    ///
+    /// Returns a read-only view of the map, as of the last finished atomic operation.
+    /// The view is unaffected by any later writes to the map, and any writes to the view fail.
+    ///
+    fn read_view(&self) -> Result<Self> {
+        bail!("Read views are not supported by this map")
+    }
 }
 
 /// A trait representing map-like storage operations with read-only capabilities.
@@ -139,15 +148,28 @@ pub trait MapRead<
     /// Returns an iterator over each value in the map.
     ///
     fn values(&'a self) -> Self::Values;
+
+    ///
+    /// Returns up to `limit` key-value pairs whose keys start with the given `prefix`, in key order.
+    /// The pairs start after the key `after`, or at the first key with the prefix if `after` is `None`.
+    /// Note: The serialized `prefix` must be a prefix of the serialized keys, e.g. the leading fields of a tuple key.
+    ///
+    fn get_prefix_range<P: Serialize + ?Sized>(
+        &'a self,
+        prefix: &P,
+        after: Option<&K>,
+        limit: usize,
+    ) -> Result<Vec<(Cow<'a, K>, Cow<'a, V>)>>;
 }
 
 /// This macro executes the given block of operations as a new atomic write batch IFF there is no
 /// atomic write batch in progress yet. This ensures that complex atomic operations consisting of
 /// multiple lower-level operations - which might also need to be atomic if executed individually -
 /// are executed as a single large atomic operation regardless.
+/// The macro evaluates to the output of the operations, once the batch is finished.
 #[macro_export]
 macro_rules! atomic_write_batch {
-    ($self:expr, $ops:block) => {
+    ($self:expr, $ops:block) => {{
         // Check if an atomic batch write is already in progress. If there isn't one, this means
         // this operation is a "top-level" one and is the one to start and finalize the batch.
         let is_part_of_atomic_batch = $self.is_atomic_in_progress();
@@ -159,19 +181,26 @@ macro_rules! atomic_write_batch {
 
         // Wrap the operations that should be batched in a closure to be able to abort the entire
         // write batch if any of them fails.
-        let run_atomic_ops = || -> Result<()> { $ops };
+        let run_atomic_ops = || -> Result<_> { $ops };
 
         // Abort the batch if any of the associated operations has failed. It's crucial that there
         // is an early return (via `?`) here, in order for any higher-level atomic write batch to
         // also abort, cascading to all the owned storage objects.
-        run_atomic_ops().map_err(|err| {
+        let output = run_atomic_ops().map_err(|err| {
             $self.abort_atomic();
             err
         })?;
 
         // Finish an atomic batch write operation IFF it's not already part of a larger one.
+        // The writes are journaled, so that a failure partway through finishing the batch is
+        // not left half-applied across the maps; the batch is then aborted, as above.
         if !is_part_of_atomic_batch {
-            $self.finish_atomic()?;
+            $crate::store::helpers::memory_map::finish_atomic_journaled(|| $self.finish_atomic()).map_err(|err| {
+                $self.abort_atomic();
+                err
+            })?;
         }
-    };
+
+        output
+    }};
 }
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A page of items from a paginated query, such as `ProgramStore::get_mapping_page`,
+/// `TransitionStore::get_transition_ids_for_function`, or `BlockStore::get_block_hashes_for_program`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page<T, C> {
+    /// The items, in the order of the query.
+    items: Vec<T>,
+    /// The cursor for the next page, if there are more items.
+    next_cursor: Option<C>,
+}
+
+/// A page of IDs from a secondary index, where the cursor is the last ID in the page.
+pub type IndexPage<T> = Page<T, T>;
+
+impl<T, C: Copy> Page<T, C> {
+    /// Initializes a new page from the given items, and the cursor for the next page, if there are more items.
+    pub(crate) fn new(items: Vec<T>, next_cursor: Option<C>) -> Self {
+        Self { items, next_cursor }
+    }
+
+    /// Returns the items, in the order of the query.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the cursor for the next page, if there are more items.
+    pub fn next_cursor(&self) -> Option<C> {
+        self.next_cursor
+    }
+
+    /// Returns the items, in the order of the query.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T: Copy> IndexPage<T> {
+    /// Initializes a new page from the given IDs, which hold up to one ID more than the `limit`.
+    /// If there is such an ID, it is dropped, and the last ID in the page becomes the cursor for the next page.
+    pub(crate) fn from_ids(mut ids: Vec<T>, limit: usize) -> Self {
+        let next_cursor = match ids.len() > limit {
+            true => {
+                ids.truncate(limit);
+                ids.last().copied()
+            }
+            false => None,
+        };
+        Self::new(ids, next_cursor)
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A change to the program state, which is recorded in the change set of a block, so that it can be reverted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub enum MappingChange<N: Network> {
+    /// The mapping was initialized, as `(program ID, mapping name)`.
+    InitializeMapping(ProgramID<N>, Identifier<N>),
+    /// The key was inserted, updated, or removed, as `(program ID, mapping name, key, previous entry)`,
+    /// where the previous entry is the `(index, value)` of the key before the change, if the key existed.
+    KeyValue(ProgramID<N>, Identifier<N>, Box<Plaintext<N>>, Option<(u32, Box<Value<N>>)>),
+}
+
+impl<N: Network> MappingChange<N> {
+    /// Returns the program ID of the change.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        match self {
+            Self::InitializeMapping(program_id, ..) => program_id,
+            Self::KeyValue(program_id, ..) => program_id,
+        }
+    }
+
+    /// Returns the mapping name of the change.
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        match self {
+            Self::InitializeMapping(_, mapping_name) => mapping_name,
+            Self::KeyValue(_, mapping_name, ..) => mapping_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("1field").unwrap();
+        let value = Value::from_str("2u64").unwrap();
+
+        for expected in [
+            MappingChange::InitializeMapping(program_id, mapping_name),
+            MappingChange::KeyValue(program_id, mapping_name, Box::new(key.clone()), None),
+            MappingChange::KeyValue(program_id, mapping_name, Box::new(key), Some((3, Box::new(value)))),
+        ] {
+            // Check the JSON serialization.
+            let candidate = serde_json::from_str(&serde_json::to_string(&expected).unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+            // Check the binary serialization.
+            let candidate = bincode::deserialize(&bincode::serialize(&expected).unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+}
@@ -0,0 +1,625 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::BTreeSet;
+
+/// The depth of the Merkle tree for the key-value pairs in a mapping.
+pub const MAPPING_DEPTH: u8 = 32;
+/// The depth of the Merkle tree for the mappings in the program state.
+pub const PROGRAM_STATE_DEPTH: u8 = 32;
+
+/// The Merkle tree for the key-value pairs in a mapping.
+pub type MappingTree<N> = BHPMerkleTree<N, MAPPING_DEPTH>;
+/// The Merkle path for a key-value pair in a mapping.
+pub type MappingPath<N> = MerklePath<N, MAPPING_DEPTH>;
+
+/// The Merkle tree for the mappings in the program state.
+pub type ProgramStateTree<N> = BHPMerkleTree<N, PROGRAM_STATE_DEPTH>;
+/// The Merkle path for a mapping in the program state.
+pub type ProgramStatePath<N> = MerklePath<N, PROGRAM_STATE_DEPTH>;
+
+/// The inclusion proof of a key-value pair in the program state, as returned by `ProgramStore::prove_mapping_value`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingValuePath<N: Network> {
+    /// The root of the mapping tree.
+    mapping_root: Field<N>,
+    /// The Merkle path for the key-value pair in the mapping tree.
+    mapping_path: MappingPath<N>,
+    /// The Merkle path for the mapping in the program state tree.
+    state_path: ProgramStatePath<N>,
+}
+
+impl<N: Network> MappingValuePath<N> {
+    /// Returns the root of the mapping tree.
+    pub const fn mapping_root(&self) -> Field<N> {
+        self.mapping_root
+    }
+
+    /// Returns the Merkle path for the key-value pair in the mapping tree.
+    pub const fn mapping_path(&self) -> &MappingPath<N> {
+        &self.mapping_path
+    }
+
+    /// Returns the Merkle path for the mapping in the program state tree.
+    pub const fn state_path(&self) -> &ProgramStatePath<N> {
+        &self.state_path
+    }
+
+    /// Returns `true` if the given `(key, value)` pair is in the mapping for the given `program ID` and `mapping name`,
+    /// in the program state with the given `checksum`.
+    pub fn verify(
+        &self,
+        checksum: &Field<N>,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        value: &Value<N>,
+    ) -> Result<bool> {
+        // Compute the mapping ID.
+        let mapping_id = N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())?;
+        // Compute the key ID.
+        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+        // Compute the value ID.
+        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+
+        // Ensure the value ID is in the mapping tree, and the mapping root is in the program state tree.
+        Ok(N::verify_merkle_path_bhp(&self.mapping_path, &self.mapping_root, &value_id.to_bits_le())
+            && N::verify_merkle_path_bhp(&self.state_path, checksum, &(mapping_id, self.mapping_root).to_bits_le()))
+    }
+}
+
+impl<N: Network> FromBytes for MappingValuePath<N> {
+    /// Reads the mapping value path from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping root.
+        let mapping_root = Field::read_le(&mut reader)?;
+        // Read the mapping path.
+        let mapping_path = MappingPath::read_le(&mut reader)?;
+        // Read the program state path.
+        let state_path = ProgramStatePath::read_le(&mut reader)?;
+        // Return the mapping value path.
+        Ok(Self { mapping_root, mapping_path, state_path })
+    }
+}
+
+impl<N: Network> ToBytes for MappingValuePath<N> {
+    /// Writes the mapping value path to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping root.
+        self.mapping_root.write_le(&mut writer)?;
+        // Write the mapping path.
+        self.mapping_path.write_le(&mut writer)?;
+        // Write the program state path.
+        self.state_path.write_le(&mut writer)
+    }
+}
+
+/// The Merkle tree over the program state, whose root is the program state checksum.
+///
+/// Each mapping has a mapping tree over its value IDs, in the order of its key-value IDs in storage, and the program
+/// state tree is built over the `(mapping ID, mapping root)` pairs, in ascending order of mapping ID.
+/// A removal mirrors `IndexMap::swap_remove`, so that every change to a mapping only updates a few leaves.
+///
+/// While a checkpoint is taken, every change is recorded in a journal, so that the program tree can be reverted
+/// without copying it. The mapping trees are reference-counted, so that a read view of the program tree is cheap
+/// to take, and a mapping tree is only copied when it is first updated while it is shared with a read view.
+#[derive(Clone)]
+pub(super) struct ProgramTree<N: Network> {
+    /// The mapping trees, keyed by mapping ID.
+    mapping_trees: BTreeMap<Field<N>, Arc<MappingLeaves<N>>>,
+    /// The program state tree.
+    state_tree: ProgramStateTree<N>,
+    /// The changes since the checkpoint, if one is taken.
+    journal: Option<Vec<TreeChange<N>>>,
+}
+
+/// The Merkle tree for the key-value pairs in a mapping, along with its leaves.
+#[derive(Clone)]
+struct MappingLeaves<N: Network> {
+    /// The mapping tree.
+    tree: MappingTree<N>,
+    /// The key-value IDs, whose value IDs are the leaves of the mapping tree.
+    key_value_ids: IndexMap<Field<N>, Field<N>>,
+}
+
+/// A change to the program tree, as recorded in the journal.
+#[derive(Clone)]
+enum TreeChange<N: Network> {
+    /// The mapping for the given mapping ID was initialized.
+    InitializeMapping(Field<N>),
+    /// The mapping for the given mapping ID was removed, along with its mapping tree.
+    RemoveMapping(Field<N>, Arc<MappingLeaves<N>>),
+    /// The value ID at the given index of the given mapping was replaced, and was the given value ID.
+    SetValue(Field<N>, usize, Field<N>),
+    /// The key-value IDs at the given indices of the given mapping were swapped.
+    SwapValues(Field<N>, usize, usize),
+    /// A key-value ID was appended to the given mapping.
+    PushValue(Field<N>),
+    /// The last key-value ID of the given mapping was removed, and was the given key-value ID.
+    PopValue(Field<N>, Field<N>, Field<N>),
+}
+
+impl<N: Network> ProgramTree<N> {
+    /// Initializes the program tree from the given program storage.
+    pub(super) fn new<P: ProgramStorage<N>>(storage: &P) -> Result<Self> {
+        // Compute the mapping trees.
+        let mapping_trees = storage
+            .key_value_id_map()
+            .iter()
+            .map(|(mapping_id, key_value_ids)| {
+                // Prepare the leaves of the mapping tree.
+                let leaves = key_value_ids.values().map(|value_id| value_id.to_bits_le()).collect::<Vec<_>>();
+                // Construct the mapping tree.
+                let tree = N::merkle_tree_bhp::<MAPPING_DEPTH>(&leaves)?;
+                let key_value_ids = cow_to_cloned!(key_value_ids);
+                Ok((*mapping_id, Arc::new(MappingLeaves { tree, key_value_ids })))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        // Compute the program state tree.
+        let state_tree = Self::state_tree(&mapping_trees)?;
+        // Return the program tree.
+        Ok(Self { mapping_trees, state_tree, journal: None })
+    }
+
+    /// Returns the program state checksum.
+    pub(super) const fn root(&self) -> &Field<N> {
+        self.state_tree.root()
+    }
+
+    /// Returns the index of the given `key ID` in the mapping for the given `mapping ID`, if it exists.
+    pub(super) fn get_index(&self, mapping_id: &Field<N>, key_id: &Field<N>) -> Result<Option<usize>> {
+        Ok(self.get_mapping_leaves(mapping_id)?.key_value_ids.get_index_of(key_id))
+    }
+
+    /// Takes a checkpoint of the program tree, and starts recording its changes in the journal.
+    /// If a checkpoint is already taken, it is kept.
+    pub(super) fn start_checkpoint(&mut self) {
+        if self.journal.is_none() {
+            self.journal = Some(Vec::new());
+        }
+    }
+
+    /// Discards the checkpoint, keeping the changes since it was taken.
+    pub(super) fn finish_checkpoint(&mut self) {
+        self.journal = None;
+    }
+
+    /// Reverts the changes since the checkpoint, in reverse order, and discards the checkpoint.
+    /// If no checkpoint is taken, this method does nothing.
+    pub(super) fn revert_checkpoint(&mut self) -> Result<()> {
+        // Take the journal, so that reverting the changes is not recorded.
+        let changes = match self.journal.take() {
+            Some(changes) => changes,
+            None => return Ok(()),
+        };
+        // Revert the changes, and track the mappings that are changed.
+        let mut is_state_changed = false;
+        let mut changed_mapping_ids = BTreeSet::new();
+        for change in changes.into_iter().rev() {
+            match change {
+                TreeChange::InitializeMapping(mapping_id) => {
+                    self.mapping_trees.remove(&mapping_id);
+                    is_state_changed = true;
+                }
+                TreeChange::RemoveMapping(mapping_id, mapping_leaves) => {
+                    self.mapping_trees.insert(mapping_id, mapping_leaves);
+                    is_state_changed = true;
+                }
+                TreeChange::SetValue(mapping_id, index, value_id) => {
+                    self.update_leaf(mapping_id, index, value_id)?;
+                    changed_mapping_ids.insert(mapping_id);
+                }
+                TreeChange::SwapValues(mapping_id, index_a, index_b) => {
+                    self.swap_leaves(mapping_id, index_a, index_b)?;
+                    changed_mapping_ids.insert(mapping_id);
+                }
+                TreeChange::PushValue(mapping_id) => {
+                    self.pop_leaf(mapping_id)?;
+                    changed_mapping_ids.insert(mapping_id);
+                }
+                TreeChange::PopValue(mapping_id, key_id, value_id) => {
+                    self.push_leaf(mapping_id, key_id, value_id)?;
+                    changed_mapping_ids.insert(mapping_id);
+                }
+            }
+        }
+        // Update the program state tree.
+        match is_state_changed {
+            true => self.state_tree = Self::state_tree(&self.mapping_trees)?,
+            false => {
+                for mapping_id in &changed_mapping_ids {
+                    self.update_state_tree(mapping_id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of the program tree as of the checkpoint, if one is taken, and the program tree otherwise.
+    pub(super) fn to_checkpoint(&self) -> Result<Self> {
+        let mut tree = self.clone();
+        tree.revert_checkpoint()?;
+        Ok(tree)
+    }
+
+    /// Adds an empty mapping tree for the given `mapping ID`.
+    /// Note: This recomputes the program state tree, as the new mapping may not be the last leaf.
+    pub(super) fn initialize_mapping(&mut self, mapping_id: Field<N>) -> Result<()> {
+        // Ensure the mapping ID does not already exist.
+        ensure!(!self.mapping_trees.contains_key(&mapping_id), "Mapping ID '{mapping_id}' already has a mapping tree");
+        // Add the empty mapping tree.
+        let tree = N::merkle_tree_bhp::<MAPPING_DEPTH>(&[])?;
+        self.mapping_trees.insert(mapping_id, Arc::new(MappingLeaves { tree, key_value_ids: IndexMap::new() }));
+        self.record(TreeChange::InitializeMapping(mapping_id));
+        // Update the program state tree.
+        self.state_tree = Self::state_tree(&self.mapping_trees)?;
+        Ok(())
+    }
+
+    /// Removes the mapping trees for the given `mapping IDs`.
+    /// Note: This recomputes the program state tree, as the removed mappings may not be the last leaves.
+    pub(super) fn remove_mappings(&mut self, mapping_ids: &[Field<N>]) -> Result<()> {
+        // Ensure the mapping IDs exist.
+        for mapping_id in mapping_ids {
+            ensure!(self.mapping_trees.contains_key(mapping_id), "Mapping ID '{mapping_id}' has no mapping tree");
+        }
+        // Remove the mapping trees.
+        for mapping_id in mapping_ids {
+            if let Some(mapping_leaves) = self.mapping_trees.remove(mapping_id) {
+                self.record(TreeChange::RemoveMapping(*mapping_id, mapping_leaves));
+            }
+        }
+        // Update the program state tree.
+        self.state_tree = Self::state_tree(&self.mapping_trees)?;
+        Ok(())
+    }
+
+    /// Sets the value ID for the given `key ID` in the mapping tree for the given `mapping ID`.
+    /// If the `key ID` does not exist, the key-value ID is appended.
+    pub(super) fn set_value(&mut self, mapping_id: Field<N>, key_id: Field<N>, value_id: Field<N>) -> Result<()> {
+        // Update the mapping tree.
+        match self.get_index(&mapping_id, &key_id)? {
+            Some(index) => self.update_leaf(mapping_id, index, value_id)?,
+            None => self.push_leaf(mapping_id, key_id, value_id)?,
+        }
+        // Update the program state tree.
+        self.update_state_tree(&mapping_id)
+    }
+
+    /// Removes the given `key ID` from the mapping tree for the given `mapping ID`,
+    /// by moving the last key-value ID into its position, as in `IndexMap::swap_remove`.
+    pub(super) fn swap_remove_value(&mut self, mapping_id: Field<N>, key_id: &Field<N>) -> Result<()> {
+        // Retrieve the index of the key ID.
+        let index = match self.get_index(&mapping_id, key_id)? {
+            Some(index) => index,
+            None => bail!("Key ID '{key_id}' is not in the mapping tree of '{mapping_id}'"),
+        };
+        // Move the key-value ID to the end, if it is not the last leaf.
+        let last_index = self.get_mapping_tree(&mapping_id)?.number_of_leaves().saturating_sub(1);
+        if index != last_index {
+            self.swap_leaves(mapping_id, index, last_index)?;
+        }
+        // Remove the last leaf.
+        self.pop_leaf(mapping_id)?;
+        // Update the program state tree.
+        self.update_state_tree(&mapping_id)
+    }
+
+    /// Inserts the given key-value ID at the given `index` of the mapping tree for the given `mapping ID`,
+    /// by moving the key-value ID at the given `index` to the end, as in `IndexMap::swap_indices` after an insertion.
+    /// This reverses `swap_remove_value`, and is used to restore a removed key-value pair to its original position.
+    pub(super) fn swap_insert_value(
+        &mut self,
+        mapping_id: Field<N>,
+        index: usize,
+        key_id: Field<N>,
+        value_id: Field<N>,
+    ) -> Result<()> {
+        // Ensure the key ID does not already exist.
+        ensure!(
+            self.get_index(&mapping_id, &key_id)?.is_none(),
+            "Key ID '{key_id}' is already in the mapping tree of '{mapping_id}'"
+        );
+        // Ensure the index is within bounds.
+        let number_of_leaves = self.get_mapping_tree(&mapping_id)?.number_of_leaves();
+        ensure!(
+            index <= number_of_leaves,
+            "Leaf index {index} is out of bounds for the mapping tree of '{mapping_id}'"
+        );
+        // Append the key-value ID.
+        self.push_leaf(mapping_id, key_id, value_id)?;
+        // Move the key-value ID into the given index, if it is not the last leaf.
+        if index != number_of_leaves {
+            self.swap_leaves(mapping_id, index, number_of_leaves)?;
+        }
+        // Update the program state tree.
+        self.update_state_tree(&mapping_id)
+    }
+
+    /// Returns the inclusion proof for the given `key ID` in the mapping tree for the given `mapping ID`.
+    pub(super) fn prove(&self, mapping_id: Field<N>, key_id: &Field<N>) -> Result<MappingValuePath<N>> {
+        // Retrieve the mapping tree and key-value IDs.
+        let MappingLeaves { tree: mapping_tree, key_value_ids } = self.get_mapping_leaves(&mapping_id)?;
+        // Retrieve the index and value ID of the key ID.
+        let (index, value_id) = match key_value_ids.get_full(key_id) {
+            Some((index, _, value_id)) => (index, value_id),
+            None => bail!("Key ID '{key_id}' is not in the mapping tree of '{mapping_id}'"),
+        };
+        // Compute the Merkle path for the value ID in the mapping tree.
+        let mapping_path = mapping_tree.prove(index, &value_id.to_bits_le())?;
+        // Compute the Merkle path for the mapping in the program state tree.
+        let mapping_root = *mapping_tree.root();
+        let state_path =
+            self.state_tree.prove(self.state_index(&mapping_id), &(mapping_id, mapping_root).to_bits_le())?;
+        // Return the mapping value path.
+        Ok(MappingValuePath { mapping_root, mapping_path, state_path })
+    }
+}
+
+impl<N: Network> ProgramTree<N> {
+    /// Returns the mapping tree and key-value IDs for the given `mapping ID`.
+    fn get_mapping_leaves(&self, mapping_id: &Field<N>) -> Result<&MappingLeaves<N>> {
+        match self.mapping_trees.get(mapping_id) {
+            Some(mapping_leaves) => Ok(mapping_leaves.as_ref()),
+            None => bail!("Mapping ID '{mapping_id}' has no mapping tree"),
+        }
+    }
+
+    /// Returns the mapping tree and key-value IDs for the given `mapping ID`,
+    /// copying them first if they are shared with a read view.
+    fn get_mapping_leaves_mut(&mut self, mapping_id: &Field<N>) -> Result<&mut MappingLeaves<N>> {
+        match self.mapping_trees.get_mut(mapping_id) {
+            Some(mapping_leaves) => Ok(Arc::make_mut(mapping_leaves)),
+            None => bail!("Mapping ID '{mapping_id}' has no mapping tree"),
+        }
+    }
+
+    /// Returns the mapping tree for the given `mapping ID`.
+    fn get_mapping_tree(&self, mapping_id: &Field<N>) -> Result<&MappingTree<N>> {
+        Ok(&self.get_mapping_leaves(mapping_id)?.tree)
+    }
+
+    /// Replaces the value ID at the given `index` of the mapping tree for the given `mapping ID`.
+    /// Note: This does not update the program state tree.
+    fn update_leaf(&mut self, mapping_id: Field<N>, index: usize, value_id: Field<N>) -> Result<()> {
+        let mapping_leaves = self.get_mapping_leaves_mut(&mapping_id)?;
+        // Retrieve the previous value ID.
+        let previous_value_id = match mapping_leaves.key_value_ids.get_index_mut(index) {
+            Some((_, previous_value_id)) => previous_value_id,
+            None => bail!("Leaf index {index} is out of bounds for the mapping tree of '{mapping_id}'"),
+        };
+        // Update the leaf.
+        mapping_leaves.tree.update(index, &value_id.to_bits_le())?;
+        let previous_value_id = core::mem::replace(previous_value_id, value_id);
+        self.record(TreeChange::SetValue(mapping_id, index, previous_value_id));
+        Ok(())
+    }
+
+    /// Swaps the key-value IDs at the given indices of the mapping tree for the given `mapping ID`.
+    /// Note: This does not update the program state tree.
+    fn swap_leaves(&mut self, mapping_id: Field<N>, index_a: usize, index_b: usize) -> Result<()> {
+        let mapping_leaves = self.get_mapping_leaves_mut(&mapping_id)?;
+        // Retrieve the value IDs at the given indices.
+        let (value_a, value_b) =
+            match (mapping_leaves.key_value_ids.get_index(index_a), mapping_leaves.key_value_ids.get_index(index_b)) {
+                (Some((_, value_a)), Some((_, value_b))) => (*value_a, *value_b),
+                _ => bail!(
+                    "Leaf indices {index_a} and {index_b} are out of bounds for the mapping tree of '{mapping_id}'"
+                ),
+            };
+        // Update the leaves.
+        mapping_leaves.tree.update(index_a, &value_b.to_bits_le())?;
+        if let Err(error) = mapping_leaves.tree.update(index_b, &value_a.to_bits_le()) {
+            // Restore the first leaf, so the mapping tree is not altered in case of failure.
+            mapping_leaves.tree.update(index_a, &value_a.to_bits_le())?;
+            return Err(error);
+        }
+        mapping_leaves.key_value_ids.swap_indices(index_a, index_b);
+        self.record(TreeChange::SwapValues(mapping_id, index_a, index_b));
+        Ok(())
+    }
+
+    /// Appends the given key-value ID to the mapping tree for the given `mapping ID`.
+    /// Note: This does not update the program state tree.
+    fn push_leaf(&mut self, mapping_id: Field<N>, key_id: Field<N>, value_id: Field<N>) -> Result<()> {
+        let mapping_leaves = self.get_mapping_leaves_mut(&mapping_id)?;
+        // Append the leaf.
+        mapping_leaves.tree.append(&[value_id.to_bits_le()])?;
+        mapping_leaves.key_value_ids.insert(key_id, value_id);
+        self.record(TreeChange::PushValue(mapping_id));
+        Ok(())
+    }
+
+    /// Removes the last key-value ID of the mapping tree for the given `mapping ID`.
+    /// Note: This does not update the program state tree.
+    fn pop_leaf(&mut self, mapping_id: Field<N>) -> Result<()> {
+        let mapping_leaves = self.get_mapping_leaves_mut(&mapping_id)?;
+        // Ensure the mapping tree has a leaf to remove.
+        ensure!(!mapping_leaves.key_value_ids.is_empty(), "The mapping tree of '{mapping_id}' has no leaves to remove");
+        // Remove the leaf.
+        mapping_leaves.tree.remove_last_n(1)?;
+        if let Some((key_id, value_id)) = mapping_leaves.key_value_ids.pop() {
+            self.record(TreeChange::PopValue(mapping_id, key_id, value_id));
+        }
+        Ok(())
+    }
+
+    /// Records the given change in the journal, if a checkpoint is taken.
+    fn record(&mut self, change: TreeChange<N>) {
+        if let Some(journal) = &mut self.journal {
+            journal.push(change);
+        }
+    }
+
+    /// Returns the leaf index of the given `mapping ID` in the program state tree.
+    fn state_index(&self, mapping_id: &Field<N>) -> usize {
+        self.mapping_trees.range(..mapping_id).count()
+    }
+
+    /// Updates the leaf for the given `mapping ID` in the program state tree.
+    fn update_state_tree(&mut self, mapping_id: &Field<N>) -> Result<()> {
+        // Compute the leaf of the mapping in the program state tree.
+        let leaf = (*mapping_id, *self.get_mapping_tree(mapping_id)?.root()).to_bits_le();
+        // Update the leaf in the program state tree.
+        self.state_tree.update(self.state_index(mapping_id), &leaf)
+    }
+
+    /// Returns the program state tree for the given mapping trees.
+    fn state_tree(mapping_trees: &BTreeMap<Field<N>, Arc<MappingLeaves<N>>>) -> Result<ProgramStateTree<N>> {
+        // Prepare the leaves of the program state tree.
+        let leaves = mapping_trees
+            .iter()
+            .map(|(mapping_id, mapping_leaves)| (*mapping_id, *mapping_leaves.tree.root()).to_bits_le())
+            .collect::<Vec<_>>();
+        // Construct the program state tree.
+        N::merkle_tree_bhp::<PROGRAM_STATE_DEPTH>(&leaves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the root of a mapping tree constructed from scratch over the given key-value IDs.
+    fn expected_root(key_value_ids: &IndexMap<Field<CurrentNetwork>, Field<CurrentNetwork>>) -> Field<CurrentNetwork> {
+        let leaves = key_value_ids.values().map(|value_id| value_id.to_bits_le()).collect::<Vec<_>>();
+        *CurrentNetwork::merkle_tree_bhp::<MAPPING_DEPTH>(&leaves).unwrap().root()
+    }
+
+    #[test]
+    fn test_swap_remove_matches_index_map() {
+        let rng = &mut TestRng::default();
+
+        // Sample a mapping ID and key-value IDs.
+        let mapping_id = Field::<CurrentNetwork>::rand(rng);
+        let mut key_value_ids = (0..5).map(|_| (Field::rand(rng), Field::rand(rng))).collect::<IndexMap<_, _>>();
+        let original_root = expected_root(&key_value_ids);
+
+        // Initialize a program tree with the key-value IDs.
+        let mut tree = ProgramTree::new(&ProgramMemory::<CurrentNetwork>::open(None).unwrap()).unwrap();
+        tree.initialize_mapping(mapping_id).unwrap();
+        for (key_id, value_id) in &key_value_ids {
+            tree.set_value(mapping_id, *key_id, *value_id).unwrap();
+        }
+        assert_eq!(original_root, *tree.get_mapping_tree(&mapping_id).unwrap().root());
+
+        // Remove each key-value ID in turn, from the middle, the front, and the back.
+        let mut removed = vec![];
+        for index in [2, 0, 2, 1, 0] {
+            let (key_id, value_id) = key_value_ids.swap_remove_index(index).unwrap();
+            removed.push((index, key_id, value_id));
+            tree.swap_remove_value(mapping_id, &key_id).unwrap();
+
+            // Ensure the mapping tree matches a mapping tree constructed from scratch.
+            assert_eq!(expected_root(&key_value_ids), *tree.get_mapping_tree(&mapping_id).unwrap().root());
+            assert_eq!(key_value_ids, tree.get_mapping_leaves(&mapping_id).unwrap().key_value_ids);
+        }
+
+        // Restore each key-value ID in reverse order, at its original index.
+        for (index, key_id, value_id) in removed.into_iter().rev() {
+            key_value_ids.insert(key_id, value_id);
+            key_value_ids.swap_indices(index, key_value_ids.len() - 1);
+            tree.swap_insert_value(mapping_id, index, key_id, value_id).unwrap();
+
+            // Ensure the mapping tree matches a mapping tree constructed from scratch.
+            assert_eq!(expected_root(&key_value_ids), *tree.get_mapping_tree(&mapping_id).unwrap().root());
+            assert_eq!(key_value_ids, tree.get_mapping_leaves(&mapping_id).unwrap().key_value_ids);
+        }
+        // Ensure the mapping tree matches the original mapping tree.
+        assert_eq!(original_root, *tree.get_mapping_tree(&mapping_id).unwrap().root());
+    }
+
+    #[test]
+    fn test_revert_checkpoint() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program tree with two mappings.
+        let mapping_ids = [Field::<CurrentNetwork>::rand(rng), Field::rand(rng)];
+        let mut tree = ProgramTree::new(&ProgramMemory::<CurrentNetwork>::open(None).unwrap()).unwrap();
+        let mut key_ids = vec![];
+        for mapping_id in mapping_ids {
+            tree.initialize_mapping(mapping_id).unwrap();
+            for _ in 0..3 {
+                let key_id = Field::rand(rng);
+                tree.set_value(mapping_id, key_id, Field::rand(rng)).unwrap();
+                key_ids.push(key_id);
+            }
+        }
+        let root = *tree.root();
+
+        // Take a checkpoint, and change every part of the program tree.
+        tree.start_checkpoint();
+        tree.set_value(mapping_ids[0], key_ids[1], Field::rand(rng)).unwrap();
+        // Ensure taking a checkpoint again keeps the first checkpoint.
+        tree.start_checkpoint();
+        tree.set_value(mapping_ids[0], Field::rand(rng), Field::rand(rng)).unwrap();
+        tree.swap_remove_value(mapping_ids[0], &key_ids[0]).unwrap();
+        tree.swap_insert_value(mapping_ids[0], 1, Field::rand(rng), Field::rand(rng)).unwrap();
+        tree.remove_mappings(&[mapping_ids[1]]).unwrap();
+        tree.initialize_mapping(Field::rand(rng)).unwrap();
+        let changed_root = *tree.root();
+        assert_ne!(root, changed_root);
+
+        // Ensure the copy as of the checkpoint matches the original root, and leaves the program tree unchanged.
+        assert_eq!(root, *tree.to_checkpoint().unwrap().root());
+        assert_eq!(changed_root, *tree.root());
+
+        // Revert the checkpoint, and ensure the root matches the original root.
+        tree.revert_checkpoint().unwrap();
+        assert_eq!(root, *tree.root());
+        assert_eq!(Some(0), tree.get_index(&mapping_ids[0], &key_ids[0]).unwrap());
+        // Ensure the changes after the checkpoint is finished are kept.
+        tree.start_checkpoint();
+        tree.set_value(mapping_ids[1], Field::rand(rng), Field::rand(rng)).unwrap();
+        tree.finish_checkpoint();
+        let updated_root = *tree.root();
+        tree.revert_checkpoint().unwrap();
+        assert_eq!(updated_root, *tree.root());
+        assert_ne!(root, updated_root);
+    }
+
+    #[test]
+    fn test_invalid_changes_fail() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program tree with an empty mapping.
+        let mapping_id = Field::<CurrentNetwork>::rand(rng);
+        let mut tree = ProgramTree::new(&ProgramMemory::<CurrentNetwork>::open(None).unwrap()).unwrap();
+        tree.initialize_mapping(mapping_id).unwrap();
+        let root = *tree.root();
+
+        // Ensure inserting a value past the end of the mapping tree fails, and does not change the root.
+        assert!(tree.swap_insert_value(mapping_id, 1, Field::rand(rng), Field::rand(rng)).is_err());
+        assert_eq!(root, *tree.root());
+        // Ensure removing a missing key ID fails, and does not change the root.
+        assert!(tree.swap_remove_value(mapping_id, &Field::rand(rng)).is_err());
+        assert_eq!(root, *tree.root());
+        // Ensure inserting an existing key ID fails, and does not change the root.
+        let key_id = Field::rand(rng);
+        tree.set_value(mapping_id, key_id, Field::rand(rng)).unwrap();
+        let root = *tree.root();
+        assert!(tree.swap_insert_value(mapping_id, 0, key_id, Field::rand(rng)).is_err());
+        assert_eq!(root, *tree.root());
+        // Ensure setting a value in a missing mapping fails.
+        assert!(tree.set_value(Field::rand(rng), key_id, Field::rand(rng)).is_err());
+    }
+}
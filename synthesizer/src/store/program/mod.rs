@@ -14,22 +14,42 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod change_set;
+pub use change_set::*;
+
+mod merkle;
+pub use merkle::*;
+
 use crate::{
     atomic_write_batch,
     cow_to_cloned,
     cow_to_copied,
-    store::helpers::{memory_map::MemoryMap, Map, MapRead},
+    store::{
+        helpers::{
+            memory_map::{finish_atomic_journaled, MemoryMap},
+            Map,
+            MapRead,
+        },
+        IndexPage,
+        Page,
+    },
 };
 use console::{
-    network::prelude::*,
+    collections::merkle_tree::MerklePath,
+    network::{prelude::*, BHPMerkleTree},
     program::{Identifier, Plaintext, ProgramID, Value},
     types::Field,
 };
 
 use anyhow::Result;
-use core::marker::PhantomData;
 use indexmap::{IndexMap, IndexSet};
-use std::collections::BTreeMap;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
+
+/// A page of key-value pairs from a mapping, as returned by `ProgramStore::get_mapping_page`,
+/// where the cursor is the key ID of the last key-value pair in the page.
+pub type MappingPage<N> = Page<(Plaintext<N>, Value<N>), Field<N>>;
 
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
@@ -53,12 +73,21 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyValueIDMap: for<'a> Map<'a, Field<N>, IndexMap<Field<N>, Field<N>>>;
     /// The mapping of `key ID` to `key`.
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
+    /// The mapping of `(mapping ID, key ID)` to `()`, which orders the keys of each mapping by key ID.
+    type KeyIndexMap: for<'a> Map<'a, (Field<N>, Field<N>), ()>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `block height` to `[mapping change]`.
+    type ChangeSetMap: for<'a> Map<'a, u32, Vec<MappingChange<N>>>;
+    /// The mapping of `key ID` to `[block height]`, for the blocks that changed the key.
+    type KeyHistoryMap: for<'a> Map<'a, Field<N>, Vec<u32>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
 
+    /// Returns a read-only view of the program state storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self>;
+
     /// Returns the program ID map.
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the program ID map.
@@ -69,8 +98,14 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_value_id_map(&self) -> &Self::KeyValueIDMap;
     /// Returns the key map.
     fn key_map(&self) -> &Self::KeyMap;
+    /// Returns the key index map.
+    fn key_index_map(&self) -> &Self::KeyIndexMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the change set map.
+    fn change_set_map(&self) -> &Self::ChangeSetMap;
+    /// Returns the key history map.
+    fn key_history_map(&self) -> &Self::KeyHistoryMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -82,7 +117,10 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().start_atomic();
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
+        self.key_index_map().start_atomic();
         self.value_map().start_atomic();
+        self.change_set_map().start_atomic();
+        self.key_history_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -92,7 +130,10 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.mapping_id_map().is_atomic_in_progress()
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
+            || self.key_index_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.change_set_map().is_atomic_in_progress()
+            || self.key_history_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -102,7 +143,10 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().abort_atomic();
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
+        self.key_index_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.change_set_map().abort_atomic();
+        self.key_history_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -112,7 +156,10 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.key_index_map().finish_atomic()?;
+        self.value_map().finish_atomic()?;
+        self.change_set_map().finish_atomic()?;
+        self.key_history_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -184,7 +231,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         // Ensure the key ID does not already exist.
-        if self.key_map().contains_key(&key_id)? {
+        if self.key_map().get_speculative(&key_id)?.is_some() {
             bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert again.")
         }
         // Retrieve the key-value IDs for the mapping ID.
@@ -204,6 +251,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
             // Insert the key.
             self.key_map().insert(key_id, key)?;
+            // Index the key.
+            self.key_index_map().insert((mapping_id, key_id), ())?;
             // Insert the value.
             self.value_map().insert(key_id, value)?;
 
@@ -241,7 +290,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             }
         };
         // If the key ID does not exist, insert it in the key-value ID map.
-        if !self.key_map().contains_key(&key_id)? {
+        if self.key_map().get_speculative(&key_id)?.is_none() {
             // Ensure the key ID does not already exist.
             // If this fails, then there is inconsistent state, and likely data corruption.
             if key_value_ids.contains_key(&key_id) {
@@ -256,6 +305,69 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
             // Insert the key.
             self.key_map().insert(key_id, key)?;
+            // Index the key.
+            self.key_index_map().insert((mapping_id, key_id), ())?;
+            // Insert the value.
+            self.value_map().insert(key_id, value)?;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Stores the given `(key, value)` pair at the given `index` of the `program ID` and `mapping name` in storage,
+    /// by moving the key-value pair at the given `index` to the end of the mapping.
+    /// This reverses `remove_key_value`, and is used to restore a removed `(key, value)` pair to its original position.
+    /// If the `key` already exists, the method returns an error.
+    fn restore_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+        index: usize,
+    ) -> Result<()> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot restore key-value."),
+        };
+        // Compute the key ID.
+        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+        // Compute the value ID.
+        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+
+        // Ensure the key ID does not already exist.
+        if self.key_map().get_speculative(&key_id)?.is_some() {
+            bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot restore again.")
+        }
+        // Retrieve the key-value IDs for the mapping ID.
+        let mut key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
+            Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+            None => {
+                bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot restore key-value.")
+            }
+        };
+        // Ensure the key ID does not already exist.
+        if key_value_ids.contains_key(&key_id) {
+            bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot restore key-value.");
+        }
+        // Ensure the index is within bounds.
+        if index > key_value_ids.len() {
+            bail!("Illegal operation: index {index} is out of bounds for mapping ID '{mapping_id}' - cannot restore.");
+        }
+        // Insert the new key-value ID, and move it into the given index.
+        key_value_ids.insert(key_id, value_id);
+        key_value_ids.swap_indices(index, key_value_ids.len() - 1);
+
+        atomic_write_batch!(self, {
+            // Update the key-value ID map with the new key-value ID.
+            self.key_value_id_map().insert(mapping_id, key_value_ids)?;
+            // Insert the key.
+            self.key_map().insert(key_id, key)?;
+            // Index the key.
+            self.key_index_map().insert((mapping_id, key_id), ())?;
             // Insert the value.
             self.value_map().insert(key_id, value)?;
 
@@ -266,6 +378,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
+    /// Note: The last key-value pair in the mapping is moved into the position of the removed key-value pair.
     fn remove_key_value(
         &self,
         program_id: &ProgramID<N>,
@@ -289,13 +402,16 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             bail!("Illegal operation: key ID '{key_id}' does not exist in storage - cannot remove key-value.");
         }
         // Remove the key ID.
-        key_value_ids.remove(&key_id);
+        // Note: `swap_remove` only moves the last key ID, so that the program tree only updates two of its leaves.
+        key_value_ids.swap_remove(&key_id);
 
         atomic_write_batch!(self, {
             // Update the key-value ID map with the new key ID.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
             // Remove the key.
             self.key_map().remove(&key_id)?;
+            // Unindex the key.
+            self.key_index_map().remove(&(mapping_id, key_id))?;
             // Remove the value.
             self.value_map().remove(&key_id)?;
 
@@ -341,6 +457,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             // Remove the keys.
             for key_id in key_value_ids.keys() {
                 self.key_map().remove(key_id)?;
+                self.key_index_map().remove(&(mapping_id, *key_id))?;
                 self.value_map().remove(key_id)?;
             }
 
@@ -405,6 +522,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
                 // Remove the keys.
                 for key_id in key_value_ids.keys() {
                     self.key_map().remove(key_id)?;
+                    self.key_index_map().remove(&(mapping_id, *key_id))?;
                     self.value_map().remove(key_id)?;
                 }
             }
@@ -439,8 +557,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         };
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
-        // Return whether the key ID exists.
-        self.key_map().contains_key(&key_id)
+        // Return whether the key ID exists, including any pending changes in the atomic batch.
+        Ok(self.key_map().get_speculative(&key_id)?.is_some())
     }
 
     /// Returns the mapping names for the given `program ID`.
@@ -474,8 +592,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         };
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
-        // Ensure the key ID exists.
-        match self.key_map().contains_key(&key_id)? {
+        // Ensure the key ID exists, including any pending changes in the atomic batch.
+        match self.key_map().get_speculative(&key_id)?.is_some() {
             true => Ok(Some(key_id)),
             false => Ok(None),
         }
@@ -512,26 +630,137 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
-    /// Returns the checksum.
-    fn get_checksum(&self) -> Result<Field<N>> {
-        // Compute all mapping checksums.
-        let preimage: BTreeMap<_, _> = self
-            .key_value_id_map()
+    /// Returns the key-value pair for the given `key ID`.
+    fn get_key_value_from_key_id(&self, key_id: &Field<N>) -> Result<Option<(Plaintext<N>, Value<N>)>> {
+        match (self.get_key(key_id)?, self.get_value_from_key_id(key_id)?) {
+            (Some(key), Some(value)) => Ok(Some((key, value))),
+            (None, None) => Ok(None),
+            _ => bail!("Key ID '{key_id}' is missing its key or value in storage - storage may be corrupt"),
+        }
+    }
+
+    /// Returns the key IDs for the given `program ID` and `mapping name`, in mapping order.
+    /// The mapping order is the insertion order, except that a removal moves the last key ID into its position.
+    /// If the mapping does not exist, returns `None`.
+    fn get_key_ids(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Option<Vec<Field<N>>>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Retrieve the key IDs for the mapping ID.
+        match self.key_value_id_map().get_speculative(&mapping_id)? {
+            Some(key_value_ids) => Ok(Some(key_value_ids.keys().copied().collect())),
+            None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot retrieve keys."),
+        }
+    }
+
+    /// Returns the number of key-value pairs for the given `program ID` and `mapping name`.
+    /// If the mapping does not exist, returns `None`.
+    fn get_mapping_count(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Option<usize>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Retrieve the number of key-value IDs for the mapping ID.
+        match self.key_value_id_map().get_speculative(&mapping_id)? {
+            Some(key_value_ids) => Ok(Some(key_value_ids.len())),
+            None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot count keys."),
+        }
+    }
+
+    /// Returns up to `limit` key-value pairs for the given `program ID` and `mapping name`, in key ID order.
+    /// The page starts after the key ID `cursor`, or at the first key-value pair if `cursor` is `None`.
+    /// Note: The cursor need not exist in the mapping, so a key-value pair that is removed between pages
+    /// does not affect the remaining pages. The pages exclude the pending changes of any atomic batch in progress.
+    fn get_mapping_page(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        cursor: Option<&Field<N>>,
+        limit: usize,
+    ) -> Result<MappingPage<N>> {
+        // Ensure the limit is nonzero.
+        ensure!(limit > 0, "The page limit must be greater than zero");
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => bail!("Mapping '{mapping_name}' does not exist in program '{program_id}'"),
+        };
+
+        // Retrieve the key IDs in the page, with one more to detect a next page.
+        let after = cursor.map(|cursor| (mapping_id, *cursor));
+        let entries = self.key_index_map().get_prefix_range(&mapping_id, after.as_ref(), limit.saturating_add(1))?;
+        let page = IndexPage::from_ids(entries.into_iter().map(|(key, _)| key.1).collect(), limit);
+
+        // Retrieve the key-value pairs in the page.
+        let entries = page
+            .items()
             .iter()
-            .map(|(mapping_id, key_value_ids)| {
-                // Convert the mapping ID and all value IDs to concatenated bits.
-                let preimage = mapping_id
-                    .to_bits_le()
-                    .into_iter()
-                    .chain(key_value_ids.values().flat_map(|value_id| value_id.to_bits_le()));
-                // Compute the mapping checksum as `Hash( mapping_id || all value IDs )`.
-                let mapping_checksum = N::hash_bhp1024(&preimage.collect::<Vec<_>>())?;
-                // Return the mapping ID and mapping checksum.
-                Ok::<_, Error>((mapping_id, mapping_checksum.to_bits_le()))
+            .map(|key_id| match self.get_key_value_from_key_id(key_id)? {
+                Some(entry) => Ok(entry),
+                None => bail!("Missing key-value pair for key ID '{key_id}' in mapping '{mapping_name}'"),
             })
-            .try_collect()?;
-        // Compute the checksum as `Hash( all mapping checksums )`.
-        N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(MappingPage::new(entries, page.next_cursor()))
+    }
+
+    /// Returns the change set for the given `block height`.
+    fn get_change_set(&self, block_height: u32) -> Result<Option<Vec<MappingChange<N>>>> {
+        match self.change_set_map().get_speculative(&block_height)? {
+            Some(change_set) => Ok(Some(cow_to_cloned!(change_set))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the end of the given `block height`.
+    fn get_value_at(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Compute the mapping ID.
+        // Note: The mapping ID is computed, as the mapping may no longer exist in storage.
+        let mapping_id = N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())?;
+        // Compute the key ID.
+        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+
+        // Retrieve the first block height after the given block height that changed the key.
+        let next_height = match self.key_history_map().get_speculative(&key_id)? {
+            Some(heights) => heights.get(heights.partition_point(|height| *height <= block_height)).copied(),
+            None => None,
+        };
+
+        match next_height {
+            // If the key changed after the given block height, return the value before the change.
+            Some(next_height) => {
+                // Retrieve the change set.
+                let change_set = match self.get_change_set(next_height)? {
+                    Some(change_set) => change_set,
+                    None => bail!("Missing change set for block {next_height} - storage may be corrupt"),
+                };
+                // Find the first change to the key in the change set.
+                change_set
+                    .into_iter()
+                    .find_map(|change| match change {
+                        MappingChange::KeyValue(change_program_id, change_mapping_name, change_key, previous)
+                            if change_program_id == *program_id
+                                && change_mapping_name == *mapping_name
+                                && *change_key == *key =>
+                        {
+                            Some(previous.map(|(_, value)| *value))
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| anyhow!("Missing key '{key}' in the change set for block {next_height}"))
+            }
+            // Otherwise, return the current value.
+            None => self.get_value(program_id, mapping_name, key),
+        }
     }
 }
 
@@ -548,8 +777,14 @@ pub struct ProgramMemory<N: Network> {
     key_value_id_map: MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
     /// The key map.
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
+    /// The key index map.
+    key_index_map: MemoryMap<(Field<N>, Field<N>), ()>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
+    /// The change set map.
+    change_set_map: MemoryMap<u32, Vec<MappingChange<N>>>,
+    /// The key history map.
+    key_history_map: MemoryMap<Field<N>, Vec<u32>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -561,7 +796,10 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
     type MappingIDMap = MemoryMap<(ProgramID<N>, Identifier<N>), Field<N>>;
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
+    type KeyIndexMap = MemoryMap<(Field<N>, Field<N>), ()>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type ChangeSetMap = MemoryMap<u32, Vec<MappingChange<N>>>;
+    type KeyHistoryMap = MemoryMap<Field<N>, Vec<u32>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -571,11 +809,30 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
             mapping_id_map: MemoryMap::default(),
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
+            key_index_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            change_set_map: MemoryMap::default(),
+            key_history_map: MemoryMap::default(),
             dev,
         })
     }
 
+    /// Returns a read-only view of the program state storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self> {
+        Ok(Self {
+            program_id_map: self.program_id_map.read_view()?,
+            program_index_map: self.program_index_map.read_view()?,
+            mapping_id_map: self.mapping_id_map.read_view()?,
+            key_value_id_map: self.key_value_id_map.read_view()?,
+            key_map: self.key_map.read_view()?,
+            key_index_map: self.key_index_map.read_view()?,
+            value_map: self.value_map.read_view()?,
+            change_set_map: self.change_set_map.read_view()?,
+            key_history_map: self.key_history_map.read_view()?,
+            dev: self.dev,
+        })
+    }
+
     /// Returns the program ID map.
     fn program_id_map(&self) -> &Self::ProgramIDMap {
         &self.program_id_map
@@ -601,11 +858,26 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
         &self.key_map
     }
 
+    /// Returns the key index map.
+    fn key_index_map(&self) -> &Self::KeyIndexMap {
+        &self.key_index_map
+    }
+
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap {
         &self.value_map
     }
 
+    /// Returns the change set map.
+    fn change_set_map(&self) -> &Self::ChangeSetMap {
+        &self.change_set_map
+    }
+
+    /// Returns the key history map.
+    fn key_history_map(&self) -> &Self::KeyHistoryMap {
+        &self.key_history_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -617,24 +889,56 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
 pub struct ProgramStore<N: Network, P: ProgramStorage<N>> {
     /// The program storage.
     storage: P,
-    /// PhantomData.
-    _phantom: PhantomData<N>,
+    /// The program tree, which journals its changes while an atomic batch is in progress.
+    tree: Arc<RwLock<ProgramTree<N>>>,
+    /// The block height and changes of the change set being recorded, if one is in progress.
+    change_set: Arc<Mutex<Option<(u32, Vec<MappingChange<N>>)>>>,
+    /// The number of most recent blocks to keep change sets for, if pruning is enabled.
+    history_depth: Arc<RwLock<Option<u32>>>,
 }
 
 impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     /// Initializes the program store.
     pub fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self { storage: P::open(dev)?, _phantom: PhantomData })
+        Self::from(P::open(dev)?)
     }
 
     /// Initializes a program store from storage.
-    pub fn from(storage: P) -> Self {
-        Self { storage, _phantom: PhantomData }
+    pub fn from(storage: P) -> Result<Self> {
+        // Compute the program tree.
+        let tree = Arc::new(RwLock::new(ProgramTree::new(&storage)?));
+        // Return the program store.
+        Ok(Self { storage, tree, change_set: Default::default(), history_depth: Default::default() })
+    }
+
+    /// Returns a read-only view of the program store, as of the last finished atomic batch.
+    /// The view is unaffected by any later writes to the program store, and any writes to the view fail.
+    pub fn read_view(&self) -> Result<Self> {
+        // Acquire the read lock on the program tree, so that no atomic batch finishes while the view is taken.
+        let tree = self.tree.read();
+        // Retrieve the program tree as of the last finished atomic batch.
+        // Note: If an atomic batch is in progress, its pending changes are reverted in the copy of the program tree.
+        let view_tree = tree.to_checkpoint()?;
+        // Return the view.
+        Ok(Self {
+            storage: self.storage.read_view()?,
+            tree: Arc::new(RwLock::new(view_tree)),
+            change_set: Default::default(),
+            history_depth: Arc::new(RwLock::new(self.history_depth())),
+        })
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
     pub fn initialize_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<()> {
-        self.storage.initialize_mapping(program_id, mapping_name)
+        atomic_write_batch!(self, {
+            // Initialize the mapping in storage.
+            self.storage.initialize_mapping(program_id, mapping_name)?;
+            // Add the mapping to the program tree.
+            self.tree.write().initialize_mapping(self.get_mapping_id(program_id, mapping_name)?)?;
+            // Record the change.
+            self.record_change(|| Ok(MappingChange::InitializeMapping(*program_id, *mapping_name)))
+        });
+        Ok(())
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -646,7 +950,17 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<()> {
-        self.storage.insert_key_value(program_id, mapping_name, key, value)
+        atomic_write_batch!(self, {
+            // Record the change.
+            self.record_key_value_change(program_id, mapping_name, &key)?;
+            // Compute the key-value ID, before the key-value pair is moved into storage.
+            let (mapping_id, key_id, value_id) = self.compute_key_value_id(program_id, mapping_name, &key, &value)?;
+            // Insert the key-value pair in storage.
+            self.storage.insert_key_value(program_id, mapping_name, key, value)?;
+            // Update the key-value pair in the program tree.
+            self.tree.write().set_value(mapping_id, key_id, value_id)
+        });
+        Ok(())
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -659,7 +973,17 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<()> {
-        self.storage.update_key_value(program_id, mapping_name, key, value)
+        atomic_write_batch!(self, {
+            // Record the change.
+            self.record_key_value_change(program_id, mapping_name, &key)?;
+            // Compute the key-value ID, before the key-value pair is moved into storage.
+            let (mapping_id, key_id, value_id) = self.compute_key_value_id(program_id, mapping_name, &key, &value)?;
+            // Update the key-value pair in storage.
+            self.storage.update_key_value(program_id, mapping_name, key, value)?;
+            // Update the key-value pair in the program tree.
+            self.tree.write().set_value(mapping_id, key_id, value_id)
+        });
+        Ok(())
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
@@ -669,23 +993,274 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         mapping_name: &Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<()> {
-        self.storage.remove_key_value(program_id, mapping_name, key)
+        atomic_write_batch!(self, {
+            // Retrieve the mapping ID and key ID, before the key-value pair is removed.
+            let mapping_id = self.get_mapping_id(program_id, mapping_name)?;
+            let key_id = match self.storage.get_key_id(program_id, mapping_name, key)? {
+                Some(key_id) => key_id,
+                None => bail!("Key '{key}' does not exist in mapping '{mapping_name}' of program '{program_id}'"),
+            };
+            // Record the change.
+            self.record_key_value_change(program_id, mapping_name, key)?;
+
+            // Remove the key-value pair from storage.
+            self.storage.remove_key_value(program_id, mapping_name, key)?;
+
+            // Remove the key-value pair from the program tree.
+            self.tree.write().swap_remove_value(mapping_id, &key_id)
+        });
+        Ok(())
     }
 
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
     /// along with all associated key-value pairs in storage.
     pub fn remove_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<()> {
-        self.storage.remove_mapping(program_id, mapping_name)
+        // Ensure a change set is not being recorded, as the removal of a mapping cannot be reverted.
+        ensure!(
+            self.change_set.lock().is_none(),
+            "Cannot remove mapping '{mapping_name}' while recording a change set"
+        );
+
+        atomic_write_batch!(self, {
+            // Retrieve the mapping ID, before it is removed.
+            let mapping_id = self.storage.get_mapping_id(program_id, mapping_name)?;
+            // Remove the mapping from storage.
+            self.storage.remove_mapping(program_id, mapping_name)?;
+            // Remove the mapping from the program tree.
+            match mapping_id {
+                Some(mapping_id) => self.tree.write().remove_mappings(&[mapping_id]),
+                None => bail!("Mapping '{mapping_name}' is missing its mapping ID - storage may be corrupt"),
+            }
+        });
+        Ok(())
     }
 
     /// Removes the program for the given `program ID` from storage,
     /// along with all associated mappings and key-value pairs in storage.
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
-        self.storage.remove_program(program_id)
+        // Ensure a change set is not being recorded, as the removal of a program cannot be reverted.
+        ensure!(self.change_set.lock().is_none(), "Cannot remove program '{program_id}' while recording a change set");
+
+        atomic_write_batch!(self, {
+            // Retrieve the mapping IDs, before they are removed.
+            let mapping_ids = match self.storage.get_mapping_names(program_id)? {
+                Some(mapping_names) => mapping_names
+                    .iter()
+                    .map(|mapping_name| self.get_mapping_id(program_id, mapping_name))
+                    .collect::<Result<Vec<_>>>()?,
+                None => vec![],
+            };
+            // Remove the program from storage.
+            self.storage.remove_program(program_id)?;
+            // Remove the mappings from the program tree.
+            self.tree.write().remove_mappings(&mapping_ids)
+        });
+        Ok(())
+    }
+
+    /// Starts recording the changes to the program state for the given `block height`.
+    /// Every subsequent `initialize_mapping`, `insert_key_value`, `update_key_value`, and `remove_key_value`
+    /// is recorded in the change set, until `finish_change_set` is called, or the atomic batch is aborted.
+    pub fn start_change_set(&self, block_height: u32) -> Result<()> {
+        // Acquire the lock on the change set.
+        let mut change_set = self.change_set.lock();
+        // Ensure a change set is not already being recorded.
+        if let Some((height, _)) = &*change_set {
+            bail!("Cannot start a change set for block {block_height}, as block {height} is still being recorded")
+        }
+        // Ensure the change set does not already exist.
+        if self.storage.change_set_map().contains_key(&block_height)? {
+            bail!("The change set for block {block_height} already exists in storage")
+        }
+        *change_set = Some((block_height, Vec::new()));
+        Ok(())
+    }
+
+    /// Finishes recording the change set, and stores it in storage.
+    pub fn finish_change_set(&self) -> Result<()> {
+        // Retrieve the change set.
+        let (block_height, changes) = match self.change_set.lock().take() {
+            Some(change_set) => change_set,
+            None => bail!("Cannot finish a change set, as no change set is being recorded"),
+        };
+        // If there are no changes, there is nothing to store.
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        atomic_write_batch!(self, {
+            // Append the block height to the history of each changed key.
+            for key_id in Self::changed_key_ids(&changes)? {
+                let mut heights = match self.storage.key_history_map().get_speculative(&key_id)? {
+                    Some(heights) => cow_to_cloned!(heights),
+                    None => Vec::new(),
+                };
+                // Ensure the block heights are in ascending order.
+                if let Some(height) = heights.last() {
+                    ensure!(*height < block_height, "Key ID '{key_id}' already changed in block {height}");
+                }
+                heights.push(block_height);
+                self.storage.key_history_map().insert(key_id, heights)?;
+            }
+            // Store the change set.
+            self.storage.change_set_map().insert(block_height, changes)?;
+            // Prune the change sets that are more than `history depth` blocks below the block height.
+            self.prune_change_sets(block_height)
+        });
+        Ok(())
+    }
+
+    /// Removes the change set of every block that is more than `history depth` blocks below the given `block height`,
+    /// along with its block height in the history of each changed key.
+    fn prune_change_sets(&self, block_height: u32) -> Result<()> {
+        // Retrieve the history depth.
+        let history_depth = match self.history_depth() {
+            Some(history_depth) => history_depth,
+            None => return Ok(()),
+        };
+        // Determine the block heights to prune, in ascending order.
+        let mut heights = self
+            .storage
+            .change_set_map()
+            .keys()
+            .map(|height| cow_to_copied!(height))
+            .filter(|height| height.saturating_add(history_depth) < block_height)
+            .collect::<Vec<_>>();
+        heights.sort_unstable();
+
+        for height in heights {
+            // Retrieve the change set.
+            let changes = match self.storage.get_change_set(height)? {
+                Some(changes) => changes,
+                None => bail!("Missing change set for block {height}"),
+            };
+            // Remove the block height from the history of each changed key.
+            for key_id in Self::changed_key_ids(&changes)? {
+                let mut heights = match self.storage.key_history_map().get_speculative(&key_id)? {
+                    Some(heights) => cow_to_cloned!(heights),
+                    None => bail!("Missing history for key ID '{key_id}' - storage may be corrupt"),
+                };
+                ensure!(heights.first() == Some(&height), "Invalid history for key ID '{key_id}'");
+                heights.remove(0);
+                match heights.is_empty() {
+                    true => self.storage.key_history_map().remove(&key_id)?,
+                    false => self.storage.key_history_map().insert(key_id, heights)?,
+                }
+            }
+            // Remove the change set.
+            self.storage.change_set_map().remove(&height)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of most recent blocks that change sets are kept for, if pruning is enabled.
+    pub fn history_depth(&self) -> Option<u32> {
+        *self.history_depth.read()
+    }
+
+    /// Sets the number of most recent blocks to keep change sets for, or disables pruning if `None`.
+    /// When a change set is stored, the change set of every block that is more than `history depth` blocks
+    /// below it is removed, and can no longer be reverted or used to look up past values.
+    pub fn set_history_depth(&self, history_depth: Option<u32>) {
+        *self.history_depth.write() = history_depth;
+    }
+
+    /// Returns `true` if the change set for the given `block height` may have been pruned.
+    /// Note: This assumes the history depth was not increased after the change set would have been pruned.
+    pub fn is_change_set_pruned(&self, block_height: u32) -> Result<bool> {
+        // Retrieve the history depth.
+        let history_depth = match self.history_depth() {
+            Some(history_depth) => history_depth,
+            None => return Ok(false),
+        };
+        // Retrieve the latest block height with a change set.
+        match self.storage.change_set_map().keys().max() {
+            Some(height) => Ok(block_height.saturating_add(history_depth) < cow_to_copied!(height)),
+            None => Ok(false),
+        }
+    }
+
+    /// Reverts the program state to the start of the given `block height`, using its change set,
+    /// and removes the change set from storage. The change set must be the latest one in storage.
+    pub fn revert_change_set(&self, block_height: u32) -> Result<()> {
+        // Ensure a change set is not being recorded.
+        ensure!(self.change_set.lock().is_none(), "Cannot revert block {block_height} while recording a change set");
+        // Ensure the change set is the latest one in storage.
+        match self.storage.change_set_map().keys().max() {
+            Some(height) if cow_to_copied!(height) == block_height => (),
+            Some(height) => bail!("Cannot revert block {block_height}, as block {} must be reverted first", *height),
+            None => bail!("Cannot revert block {block_height}, as there are no change sets in storage"),
+        }
+        // Retrieve the change set.
+        let changes = match self.storage.get_change_set(block_height)? {
+            Some(changes) => changes,
+            None => bail!("Missing change set for block {block_height}"),
+        };
+
+        atomic_write_batch!(self, {
+            // Revert the changes.
+            self.revert_changes(&changes)?;
+            // Remove the block height from the history of each changed key.
+            for key_id in Self::changed_key_ids(&changes)? {
+                let mut heights = match self.storage.key_history_map().get_speculative(&key_id)? {
+                    Some(heights) => cow_to_cloned!(heights),
+                    None => bail!("Missing history for key ID '{key_id}' - storage may be corrupt"),
+                };
+                ensure!(heights.pop() == Some(block_height), "Invalid history for key ID '{key_id}'");
+                match heights.is_empty() {
+                    true => self.storage.key_history_map().remove(&key_id)?,
+                    false => self.storage.key_history_map().insert(key_id, heights)?,
+                }
+            }
+            // Remove the change set.
+            self.storage.change_set_map().remove(&block_height)
+        });
+        Ok(())
+    }
+
+    /// Returns the number of changes in the change set being recorded, if one is in progress.
+    pub fn num_recorded_changes(&self) -> Option<usize> {
+        self.change_set.lock().as_ref().map(|(_, changes)| changes.len())
+    }
+
+    /// Returns the changes recorded after the first `num_changes` changes of the change set being recorded,
+    /// if one is in progress.
+    pub fn get_recorded_changes(&self, num_changes: usize) -> Option<Vec<MappingChange<N>>> {
+        self.change_set.lock().as_ref().map(|(_, changes)| changes.iter().skip(num_changes).cloned().collect())
+    }
+
+    /// Reverts the changes recorded after the first `num_changes` changes of the change set being recorded,
+    /// and removes them from the change set. This reverts the changes of a transaction that failed to finalize,
+    /// while keeping the changes of the preceding transactions in the block.
+    pub fn rewind_change_set(&self, num_changes: usize) -> Result<()> {
+        // Split off the changes to revert from the change set.
+        let reverted = match &mut *self.change_set.lock() {
+            Some((block_height, changes)) => {
+                ensure!(
+                    num_changes <= changes.len(),
+                    "Cannot rewind the change set for block {block_height} to {num_changes} changes, as it has {}",
+                    changes.len()
+                );
+                changes.split_off(num_changes)
+            }
+            None => bail!("Cannot rewind a change set, as no change set is being recorded"),
+        };
+
+        // Pause recording the change set, so that reverting the changes is not recorded.
+        let change_set = self.change_set.lock().take();
+        // Revert the changes.
+        atomic_write_batch!(self, { self.revert_changes(&reverted) });
+        // Resume recording the change set.
+        *self.change_set.lock() = change_set;
+        Ok(())
     }
 
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
+        // If this is the top-level batch, checkpoint the program tree.
+        if !self.storage.is_atomic_in_progress() {
+            self.tree.write().start_checkpoint();
+        }
         self.storage.start_atomic();
     }
 
@@ -697,17 +1272,49 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+        // Revert the program tree to the checkpoint.
+        let mut tree = self.tree.write();
+        if tree.revert_checkpoint().is_err() {
+            // If the program tree fails to revert, recompute it from the aborted storage instead.
+            match ProgramTree::new(&self.storage) {
+                Ok(recomputed_tree) => *tree = recomputed_tree,
+                Err(error) => error!("Failed to recompute the program tree after aborting an atomic batch: {error}"),
+            }
+        }
+        // Discard the change set being recorded, as its changes were aborted.
+        *self.change_set.lock() = None;
     }
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        // Hold the write lock on the program tree, so that a read view never observes a partially-finished batch.
+        let mut tree = self.tree.write();
+        finish_atomic_journaled(|| self.storage.finish_atomic())?;
+        // If this was the top-level batch, discard the checkpoint.
+        if !self.storage.is_atomic_in_progress() {
+            tree.finish_checkpoint();
+        }
+        Ok(())
     }
 
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Builds the key index from the key-value IDs of each mapping in storage.
+    /// Note: This migrates storage from schema version 5, which predates the key index.
+    pub fn build_key_index(&self) -> Result<()> {
+        atomic_write_batch!(self, {
+            for (mapping_id, key_value_ids) in self.storage.key_value_id_map().iter() {
+                for key_id in key_value_ids.keys() {
+                    self.storage.key_index_map().insert((*mapping_id, *key_id), ())?;
+                }
+            }
+            Ok(())
+        });
+        Ok(())
+    }
 }
 
 impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
@@ -738,6 +1345,11 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.get_mapping_names(program_id)
     }
 
+    /// Returns an iterator over the program IDs, for all programs with initialized mappings.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys()
+    }
+
     /// Returns the value for the given `program ID`, `mapping name`, and `key`.
     pub fn get_value(
         &self,
@@ -747,11 +1359,226 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value(program_id, mapping_name, key)
     }
+
+    /// Returns the number of key-value pairs for the given `program ID` and `mapping name`.
+    /// If the mapping does not exist, returns `None`.
+    pub fn get_mapping_count(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Option<usize>> {
+        self.storage.get_mapping_count(program_id, mapping_name)
+    }
+
+    /// Returns an iterator over the key-value pairs for the given `program ID` and `mapping name`, in mapping order.
+    /// Note: The key IDs are read upfront, while each key-value pair is read from storage as it is iterated,
+    /// so any key-value pair that is removed during iteration is skipped.
+    pub fn get_mapping(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<impl '_ + Iterator<Item = Result<(Plaintext<N>, Value<N>)>>> {
+        // Retrieve the key IDs.
+        let key_ids = match self.storage.get_key_ids(program_id, mapping_name)? {
+            Some(key_ids) => key_ids,
+            None => bail!("Mapping '{mapping_name}' does not exist in program '{program_id}'"),
+        };
+        // Retrieve each key-value pair.
+        Ok(key_ids.into_iter().filter_map(|key_id| self.storage.get_key_value_from_key_id(&key_id).transpose()))
+    }
+
+    /// Returns up to `limit` key-value pairs for the given `program ID` and `mapping name`, in mapping order.
+    /// The page starts after the key ID `cursor`, or at the first key-value pair if `cursor` is `None`.
+    /// To retrieve the next page, pass in the `next_cursor` of the returned page.
+    pub fn get_mapping_page(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        cursor: Option<&Field<N>>,
+        limit: usize,
+    ) -> Result<MappingPage<N>> {
+        self.storage.get_mapping_page(program_id, mapping_name, cursor, limit)
+    }
+
+    /// Returns the change set for the given `block height`.
+    pub fn get_change_set(&self, block_height: u32) -> Result<Option<Vec<MappingChange<N>>>> {
+        self.storage.get_change_set(block_height)
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the end of the given `block height`.
+    /// Note: Only the changes that are recorded in a change set are tracked by block height,
+    /// and the value is unavailable if a later change set is pruned.
+    pub fn get_value_at(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the change sets after the given block height are not pruned.
+        if self.is_change_set_pruned(block_height.saturating_add(1))? {
+            bail!("Cannot retrieve the value as of block {block_height}, as its later change sets are pruned")
+        }
+        self.storage.get_value_at(program_id, mapping_name, key, block_height)
+    }
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
+    /// Returns the checksum of the program state, which is the root of the program tree.
+    pub fn get_checksum(&self) -> Result<Field<N>> {
+        Ok(*self.tree.read().root())
+    }
+
+    /// Returns the inclusion proof of the value for the given `program ID`, `mapping name`, and `key`,
+    /// which can be verified against the checksum of the program state.
+    pub fn prove_mapping_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingValuePath<N>> {
+        // Acquire the read lock on the program tree, to ensure the proof is consistent with storage.
+        let tree = self.tree.read();
+        // Retrieve the mapping ID.
+        let mapping_id = self.get_mapping_id(program_id, mapping_name)?;
+        // Retrieve the key ID.
+        let key_id = match self.storage.get_key_id(program_id, mapping_name, key)? {
+            Some(key_id) => key_id,
+            None => bail!("Key '{key}' does not exist in mapping '{mapping_name}' of program '{program_id}'"),
+        };
+        // Compute the inclusion proof of the key-value pair.
+        tree.prove(mapping_id, &key_id)
+    }
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
+    /// Returns the mapping ID for the given `program ID` and `mapping name`.
+    fn get_mapping_id(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
+        match self.storage.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => Ok(mapping_id),
+            None => bail!("Mapping '{mapping_name}' does not exist in program '{program_id}'"),
+        }
+    }
+
+    /// Returns the mapping ID, key ID, and value ID for the given `program ID`, `mapping name`, `key`, and `value`.
+    fn compute_key_value_id(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        value: &Value<N>,
+    ) -> Result<(Field<N>, Field<N>, Field<N>)> {
+        // Retrieve the mapping ID.
+        let mapping_id = self.get_mapping_id(program_id, mapping_name)?;
+        // Compute the key ID.
+        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+        // Compute the value ID.
+        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        Ok((mapping_id, key_id, value_id))
+    }
+
+    /// Restores the given `(key, value)` pair at the given `index` of the `program ID` and `mapping name`.
+    fn restore_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        value: &Value<N>,
+        index: usize,
+    ) -> Result<()> {
+        atomic_write_batch!(self, {
+            // Compute the key-value ID.
+            let (mapping_id, key_id, value_id) = self.compute_key_value_id(program_id, mapping_name, key, value)?;
+            // Restore the key-value pair in storage.
+            self.storage.restore_key_value(program_id, mapping_name, key.clone(), value.clone(), index)?;
+            // Restore the key-value pair in the program tree.
+            self.tree.write().swap_insert_value(mapping_id, index, key_id, value_id)
+        });
+        Ok(())
+    }
+
+    /// Reverts the given changes to the program state, in reverse order.
+    /// Note: This method must be called while a change set is not being recorded.
+    fn revert_changes(&self, changes: &[MappingChange<N>]) -> Result<()> {
+        for change in changes.iter().rev() {
+            match change {
+                MappingChange::InitializeMapping(program_id, mapping_name) => {
+                    self.remove_mapping(program_id, mapping_name)?;
+                    // If the program has no remaining mappings, remove the program.
+                    if matches!(self.storage.get_mapping_names(program_id)?, Some(names) if names.is_empty()) {
+                        self.remove_program(program_id)?;
+                    }
+                }
+                MappingChange::KeyValue(program_id, mapping_name, key, None) => {
+                    self.remove_key_value(program_id, mapping_name, key)?;
+                }
+                MappingChange::KeyValue(program_id, mapping_name, key, Some((index, value))) => {
+                    match self.storage.contains_key(program_id, mapping_name, key)? {
+                        true => self.update_key_value(program_id, mapping_name, *key.clone(), *value.clone())?,
+                        false => self.restore_key_value(program_id, mapping_name, key, value, *index as usize)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the change returned by the given closure, if a change set is being recorded.
+    fn record_change(&self, change: impl FnOnce() -> Result<MappingChange<N>>) -> Result<()> {
+        if let Some((_, changes)) = &mut *self.change_set.lock() {
+            changes.push(change()?);
+        }
+        Ok(())
+    }
+
+    /// Records the change to the given `program ID`, `mapping name`, and `key`, if a change set is being recorded.
+    /// This method must be called before the key-value pair is changed in storage.
+    fn record_key_value_change(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<()> {
+        self.record_change(|| {
+            // Retrieve the index and value of the key, if it exists.
+            let previous = match self.storage.get_key_id(program_id, mapping_name, key)? {
+                Some(key_id) => {
+                    // Retrieve the index of the key.
+                    let mapping_id = self.get_mapping_id(program_id, mapping_name)?;
+                    let index = match self.tree.read().get_index(&mapping_id, &key_id)? {
+                        Some(index) => u32::try_from(index)?,
+                        None => bail!("Key ID for '{key}' is missing from mapping '{mapping_name}'"),
+                    };
+                    // Retrieve the value of the key.
+                    match self.storage.get_value_from_key_id(&key_id)? {
+                        Some(value) => Some((index, Box::new(value))),
+                        None => bail!("Key '{key}' is missing its value in mapping '{mapping_name}'"),
+                    }
+                }
+                None => None,
+            };
+            Ok(MappingChange::KeyValue(*program_id, *mapping_name, Box::new(key.clone()), previous))
+        })
+    }
+
+    /// Returns the key IDs that are changed in the given change set.
+    fn changed_key_ids(changes: &[MappingChange<N>]) -> Result<IndexSet<Field<N>>> {
+        changes
+            .iter()
+            .filter_map(|change| match change {
+                MappingChange::KeyValue(program_id, mapping_name, key, _) => Some((program_id, mapping_name, key)),
+                MappingChange::InitializeMapping(..) => None,
+            })
+            .map(|(program_id, mapping_name, key)| {
+                // Compute the mapping ID.
+                let mapping_id = N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())?;
+                // Compute the key ID.
+                N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::store::helpers::memory_map::test_helpers::FailingMap;
     use console::network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -1219,4 +2046,706 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_remove_key_value_checksum() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Prepare the keys and values.
+        let entries = (0..3)
+            .map(|item| {
+                let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+                let value = Value::from_str(&format!("{item}u64")).unwrap();
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+
+        // Initialize a new program store.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        // Retrieve the checksum of the empty mapping.
+        let empty_checksum = program_store.get_checksum().unwrap();
+
+        // Ensure removing a missing key fails, and does not change the checksum.
+        assert!(program_store.remove_key_value(&program_id, &mapping_name, &entries[0].0).is_err());
+        assert_eq!(empty_checksum, program_store.get_checksum().unwrap());
+
+        // Insert all of the keys and values.
+        for (key, value) in &entries {
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        }
+        let full_checksum = program_store.get_checksum().unwrap();
+        assert_ne!(empty_checksum, full_checksum);
+
+        // Remove the middle key.
+        program_store.remove_key_value(&program_id, &mapping_name, &entries[1].0).unwrap();
+        let removed_checksum = program_store.get_checksum().unwrap();
+        assert_ne!(full_checksum, removed_checksum);
+
+        // Ensure the checksum matches a program store that never contained the removed key.
+        let expected_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        expected_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        for (key, value) in [&entries[0], &entries[2]] {
+            expected_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        }
+        assert_eq!(expected_store.get_checksum().unwrap(), removed_checksum);
+
+        // Remove the remaining keys, and ensure the checksum matches the empty mapping.
+        for (key, _) in [&entries[0], &entries[2]] {
+            program_store.remove_key_value(&program_id, &mapping_name, key).unwrap();
+        }
+        assert_eq!(empty_checksum, program_store.get_checksum().unwrap());
+    }
+
+    /// Checks `get_mapping`, `get_mapping_count`, and `get_mapping_page`.
+    fn check_get_mapping<N: Network, P: ProgramStorage<N>>(program_store: &ProgramStore<N, P>) {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<N>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Ensure a missing mapping has no count, and cannot be enumerated.
+        assert_eq!(program_store.get_mapping_count(&program_id, &mapping_name).unwrap(), None);
+        assert!(program_store.get_mapping(&program_id, &mapping_name).is_err());
+        assert!(program_store.get_mapping_page(&program_id, &mapping_name, None, 2).is_err());
+
+        // Initialize the mapping, and ensure it is empty.
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        assert_eq!(program_store.get_mapping_count(&program_id, &mapping_name).unwrap(), Some(0));
+        assert_eq!(program_store.get_mapping(&program_id, &mapping_name).unwrap().count(), 0);
+        let page = program_store.get_mapping_page(&program_id, &mapping_name, None, 2).unwrap();
+        assert!(page.items().is_empty());
+        assert_eq!(page.next_cursor(), None);
+
+        // Insert the key-value pairs.
+        let entries = (0..5)
+            .map(|i| (Plaintext::from_str(&format!("{i}field")).unwrap(), Value::from_str(&format!("{i}u64")).unwrap()))
+            .collect::<Vec<(Plaintext<N>, Value<N>)>>();
+        for (key, value) in &entries {
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        }
+        assert_eq!(program_store.get_mapping_count(&program_id, &mapping_name).unwrap(), Some(5));
+
+        // Ensure the iterator returns every key-value pair, in mapping order.
+        let candidate = program_store.get_mapping(&program_id, &mapping_name).unwrap().collect::<Result<Vec<_>>>();
+        assert_eq!(entries, candidate.unwrap());
+
+        // Ensure a single page returns every key-value pair, and has no next cursor.
+        let page = program_store.get_mapping_page(&program_id, &mapping_name, None, 5).unwrap();
+        assert_eq!(page.next_cursor(), None);
+        let ordered = page.into_items();
+        assert_eq!(ordered.len(), entries.len());
+        assert!(entries.iter().all(|entry| ordered.contains(entry)));
+        // Ensure the pages return every key-value pair, in the same order.
+        let first = program_store.get_mapping_page(&program_id, &mapping_name, None, 2).unwrap();
+        assert_eq!(first.items(), &ordered[0..2]);
+        let second =
+            program_store.get_mapping_page(&program_id, &mapping_name, first.next_cursor().as_ref(), 2).unwrap();
+        assert_eq!(second.items(), &ordered[2..4]);
+        let third =
+            program_store.get_mapping_page(&program_id, &mapping_name, second.next_cursor().as_ref(), 2).unwrap();
+        assert_eq!(third.items(), &ordered[4..5]);
+        assert_eq!(third.next_cursor(), None);
+        // Ensure a zero limit is rejected.
+        assert!(program_store.get_mapping_page(&program_id, &mapping_name, None, 0).is_err());
+
+        // Remove the last key-value pair in the first page, i.e. the cursor, and a key-value pair in a later page.
+        program_store.remove_key_value(&program_id, &mapping_name, &ordered[1].0).unwrap();
+        program_store.remove_key_value(&program_id, &mapping_name, &ordered[3].0).unwrap();
+        assert_eq!(program_store.get_mapping_count(&program_id, &mapping_name).unwrap(), Some(3));
+        // Ensure the next page still resumes after the cursor, and returns each remaining key-value pair exactly once.
+        let second =
+            program_store.get_mapping_page(&program_id, &mapping_name, first.next_cursor().as_ref(), 2).unwrap();
+        assert_eq!(second.items(), [ordered[2].clone(), ordered[4].clone()]);
+        assert_eq!(second.next_cursor(), None);
+        // Restore the removed key-value pairs.
+        for (key, value) in [&ordered[1], &ordered[3]] {
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        }
+
+        // Remove the second key-value pair.
+        program_store.remove_key_value(&program_id, &mapping_name, &entries[1].0).unwrap();
+        assert_eq!(program_store.get_mapping_count(&program_id, &mapping_name).unwrap(), Some(4));
+        // Ensure the removed key-value pair is no longer enumerated.
+        let candidate = program_store.get_mapping(&program_id, &mapping_name).unwrap().collect::<Result<Vec<_>>>();
+        assert!(!candidate.unwrap().contains(&entries[1]));
+
+        // Ensure pending changes in an atomic batch are enumerated.
+        program_store.start_atomic();
+        program_store.insert_key_value(&program_id, &mapping_name, entries[1].0.clone(), entries[1].1.clone()).unwrap();
+        assert_eq!(program_store.get_mapping_count(&program_id, &mapping_name).unwrap(), Some(5));
+        let candidate = program_store.get_mapping(&program_id, &mapping_name).unwrap().collect::<Result<Vec<_>>>();
+        assert_eq!(candidate.unwrap().last(), Some(&entries[1]));
+        program_store.abort_atomic();
+        assert_eq!(program_store.get_mapping_count(&program_id, &mapping_name).unwrap(), Some(4));
+    }
+
+    #[test]
+    fn test_get_mapping() {
+        // Initialize a new program store.
+        let program_store = ProgramStore::<CurrentNetwork, ProgramMemory<CurrentNetwork>>::open(None).unwrap();
+        // Check the enumeration.
+        check_get_mapping(&program_store);
+    }
+
+    #[test]
+    fn test_get_mapping_persistent() {
+        use crate::store::helpers::database::{test_helpers::open_temporary_database, ProgramDB};
+
+        // Initialize a new program store, in a temporary database.
+        let (_directory, _database, dev) = open_temporary_database::<CurrentNetwork>();
+        let program_store = ProgramStore::<CurrentNetwork, ProgramDB<CurrentNetwork>>::open(dev).unwrap();
+        // Check the enumeration.
+        check_get_mapping(&program_store);
+    }
+
+    #[test]
+    fn test_remove_key_value_atomic() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Prepare a key and value.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let value = Value::from_str("987654321u128").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        // Retrieve the checksum of the empty mapping.
+        let empty_checksum = program_store.get_checksum().unwrap();
+
+        // Start an atomic batch.
+        program_store.start_atomic();
+        // Update the key, and ensure it is visible from within the batch.
+        program_store.update_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        assert!(program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(value, program_store.get_value(&program_id, &mapping_name, &key).unwrap().unwrap());
+        // Remove the key, and ensure the removal is visible from within the batch.
+        program_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert!(program_store.get_value(&program_id, &mapping_name, &key).unwrap().is_none());
+        // Ensure removing the key again fails.
+        assert!(program_store.remove_key_value(&program_id, &mapping_name, &key).is_err());
+        // Finish the atomic batch.
+        program_store.finish_atomic().unwrap();
+
+        // Ensure the key does not exist, and the checksum is unchanged.
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(empty_checksum, program_store.get_checksum().unwrap());
+
+        // Insert the key outside of a batch.
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        let checksum = program_store.get_checksum().unwrap();
+
+        // Remove the key in an atomic batch, and then abort it.
+        program_store.start_atomic();
+        program_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        program_store.abort_atomic();
+
+        // Ensure the key still exists, and the checksum is unchanged.
+        assert!(program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(value, program_store.get_value(&program_id, &mapping_name, &key).unwrap().unwrap());
+        assert_eq!(checksum, program_store.get_checksum().unwrap());
+    }
+
+    #[test]
+    fn test_prove_mapping_value() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_names = [Identifier::from_str("account").unwrap(), Identifier::from_str("supply").unwrap()];
+
+        // Prepare the keys and values.
+        let entries = (0..4)
+            .map(|item| {
+                let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+                let value = Value::from_str(&format!("{item}u64")).unwrap();
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+
+        // Initialize a new program store, and insert the keys and values into both mappings.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        for mapping_name in &mapping_names {
+            program_store.initialize_mapping(&program_id, mapping_name).unwrap();
+            for (key, value) in &entries {
+                program_store.insert_key_value(&program_id, mapping_name, key.clone(), value.clone()).unwrap();
+            }
+        }
+        let checksum = program_store.get_checksum().unwrap();
+
+        // Ensure every key-value pair can be proven against the checksum.
+        for mapping_name in &mapping_names {
+            for (key, value) in &entries {
+                let path = program_store.prove_mapping_value(&program_id, mapping_name, key).unwrap();
+                assert!(path.verify(&checksum, &program_id, mapping_name, key, value).unwrap());
+                // Ensure the path does not verify for a different value.
+                assert!(
+                    !path
+                        .verify(&checksum, &program_id, mapping_name, key, &Value::from_str("999u64").unwrap())
+                        .unwrap()
+                );
+                // Ensure the path does not verify for a different checksum.
+                assert!(!path.verify(&Field::zero(), &program_id, mapping_name, key, value).unwrap());
+                // Ensure the path serializes correctly.
+                assert_eq!(path, MappingValuePath::from_bytes_le(&path.to_bytes_le().unwrap()).unwrap());
+            }
+        }
+        // Ensure the path does not verify for the other mapping.
+        let path = program_store.prove_mapping_value(&program_id, &mapping_names[0], &entries[1].0).unwrap();
+        assert!(!path.verify(&checksum, &program_id, &mapping_names[1], &entries[1].0, &entries[1].1).unwrap());
+        // Ensure a missing key cannot be proven.
+        let missing_key = Plaintext::from_str("100field").unwrap();
+        assert!(program_store.prove_mapping_value(&program_id, &mapping_names[0], &missing_key).is_err());
+
+        // Update a value, and ensure the old path no longer verifies against the new checksum.
+        let (key, old_value) = &entries[2];
+        let new_value = Value::from_str("100u64").unwrap();
+        program_store.update_key_value(&program_id, &mapping_names[1], key.clone(), new_value.clone()).unwrap();
+        let updated_checksum = program_store.get_checksum().unwrap();
+        assert_ne!(checksum, updated_checksum);
+        let old_path = program_store.prove_mapping_value(&program_id, &mapping_names[0], key).unwrap();
+        assert!(old_path.verify(&updated_checksum, &program_id, &mapping_names[0], key, old_value).unwrap());
+        let new_path = program_store.prove_mapping_value(&program_id, &mapping_names[1], key).unwrap();
+        assert!(new_path.verify(&updated_checksum, &program_id, &mapping_names[1], key, &new_value).unwrap());
+        assert!(!new_path.verify(&updated_checksum, &program_id, &mapping_names[1], key, old_value).unwrap());
+
+        // Remove a key, and ensure the remaining key-value pairs can still be proven.
+        program_store.remove_key_value(&program_id, &mapping_names[0], &entries[1].0).unwrap();
+        let removed_checksum = program_store.get_checksum().unwrap();
+        for (key, value) in [&entries[0], &entries[2], &entries[3]] {
+            let path = program_store.prove_mapping_value(&program_id, &mapping_names[0], key).unwrap();
+            assert!(path.verify(&removed_checksum, &program_id, &mapping_names[0], key, value).unwrap());
+        }
+
+        // Ensure the checksum matches a program store that is reloaded from the same storage.
+        let reloaded_store = ProgramStore::<_, ProgramMemory<_>>::from(program_store.storage.clone()).unwrap();
+        assert_eq!(removed_checksum, reloaded_store.get_checksum().unwrap());
+
+        // Remove a mapping and the program, and ensure the checksum matches the empty program store.
+        program_store.remove_mapping(&program_id, &mapping_names[0]).unwrap();
+        assert_ne!(removed_checksum, program_store.get_checksum().unwrap());
+        program_store.remove_program(&program_id).unwrap();
+        let empty_store = ProgramStore::<CurrentNetwork, ProgramMemory<_>>::open(None).unwrap();
+        assert_eq!(empty_store.get_checksum().unwrap(), program_store.get_checksum().unwrap());
+    }
+
+    #[test]
+    fn test_change_set_revert() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Prepare the keys and values.
+        let entries = (0..5)
+            .map(|item| {
+                let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+                let value = Value::from_str(&format!("{item}u64")).unwrap();
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+
+        // Initialize a new program store.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        let empty_checksum = program_store.get_checksum().unwrap();
+
+        // Record the first block, which initializes the mapping and inserts the first four keys.
+        program_store.start_change_set(0).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        for (key, value) in &entries[..4] {
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        }
+        program_store.finish_change_set().unwrap();
+        let checksum_0 = program_store.get_checksum().unwrap();
+        let mapping_0 =
+            program_store.get_mapping(&program_id, &mapping_name).unwrap().collect::<Result<Vec<_>>>().unwrap();
+
+        // Ensure a change set cannot be recorded twice for the same block height.
+        assert!(program_store.start_change_set(0).is_err());
+
+        // Record the second block, which updates a key, removes a key in the middle, and inserts a new key.
+        let new_value = Value::from_str("100u64").unwrap();
+        program_store.start_change_set(1).unwrap();
+        // Ensure a change set cannot be started while one is already being recorded.
+        assert!(program_store.start_change_set(2).is_err());
+        program_store.update_key_value(&program_id, &mapping_name, entries[1].0.clone(), new_value.clone()).unwrap();
+        program_store.remove_key_value(&program_id, &mapping_name, &entries[2].0).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, entries[4].0.clone(), entries[4].1.clone()).unwrap();
+        // Ensure a change set cannot be reverted while one is being recorded.
+        assert!(program_store.revert_change_set(0).is_err());
+        program_store.finish_change_set().unwrap();
+        assert_ne!(checksum_0, program_store.get_checksum().unwrap());
+        assert_eq!(3, program_store.get_change_set(1).unwrap().unwrap().len());
+
+        // Record an empty block, and ensure it is not stored.
+        program_store.start_change_set(2).unwrap();
+        program_store.finish_change_set().unwrap();
+        assert!(program_store.get_change_set(2).unwrap().is_none());
+
+        // Ensure the historical values are correct.
+        let value_at =
+            |key: &Plaintext<_>, height| program_store.get_value_at(&program_id, &mapping_name, key, height).unwrap();
+        assert_eq!(Some(entries[0].1.clone()), value_at(&entries[0].0, 0));
+        assert_eq!(Some(entries[1].1.clone()), value_at(&entries[1].0, 0));
+        assert_eq!(Some(new_value.clone()), value_at(&entries[1].0, 1));
+        assert_eq!(Some(new_value), value_at(&entries[1].0, 2));
+        assert_eq!(Some(entries[2].1.clone()), value_at(&entries[2].0, 0));
+        assert_eq!(None, value_at(&entries[2].0, 1));
+        assert_eq!(None, value_at(&entries[4].0, 0));
+        assert_eq!(Some(entries[4].1.clone()), value_at(&entries[4].0, 1));
+
+        // Ensure only the latest change set can be reverted.
+        assert!(program_store.revert_change_set(0).is_err());
+
+        // Revert the second block, and ensure the mapping is restored, including the order of its keys.
+        program_store.revert_change_set(1).unwrap();
+        assert!(program_store.get_change_set(1).unwrap().is_none());
+        assert_eq!(checksum_0, program_store.get_checksum().unwrap());
+        let mapping =
+            program_store.get_mapping(&program_id, &mapping_name).unwrap().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(mapping_0, mapping);
+        assert_eq!(None, value_at(&entries[4].0, 1));
+
+        // Revert the first block, and ensure the program store is empty.
+        program_store.revert_change_set(0).unwrap();
+        assert!(!program_store.contains_program(&program_id).unwrap());
+        assert_eq!(empty_checksum, program_store.get_checksum().unwrap());
+        assert!(program_store.revert_change_set(0).is_err());
+    }
+
+    #[test]
+    fn test_change_set_pruning() {
+        // Initialize a program ID, mapping name, and key.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let value = |height: u32| Value::from_str(&format!("{height}u64")).unwrap();
+
+        // Initialize a new program store, which keeps the change sets of the latest two blocks.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.set_history_depth(Some(1));
+
+        // Record the first block, which initializes the mapping and inserts the key.
+        program_store.start_change_set(0).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value(0)).unwrap();
+        program_store.finish_change_set().unwrap();
+
+        // Record the next blocks, which update the key.
+        for height in 1..4 {
+            program_store.start_change_set(height).unwrap();
+            program_store.update_key_value(&program_id, &mapping_name, key.clone(), value(height)).unwrap();
+            program_store.finish_change_set().unwrap();
+        }
+
+        // Ensure only the change sets of the latest two blocks are kept.
+        assert!(program_store.get_change_set(0).unwrap().is_none());
+        assert!(program_store.get_change_set(1).unwrap().is_none());
+        assert!(program_store.get_change_set(2).unwrap().is_some());
+        assert!(program_store.get_change_set(3).unwrap().is_some());
+        assert!(program_store.is_change_set_pruned(1).unwrap());
+        assert!(!program_store.is_change_set_pruned(2).unwrap());
+
+        // Ensure the history of the key only contains the kept block heights.
+        let mapping_id = CurrentNetwork::hash_bhp1024(&(program_id, mapping_name).to_bits_le()).unwrap();
+        let key_hash = CurrentNetwork::hash_bhp1024(&key.to_bits_le()).unwrap();
+        let key_id = CurrentNetwork::hash_bhp1024(&(mapping_id, key_hash).to_bits_le()).unwrap();
+        let history = program_store.storage.key_history_map().get(&key_id).unwrap().unwrap();
+        assert_eq!(vec![2, 3], *history);
+
+        // Ensure the historical values are available only within the history depth.
+        let value_at = |height| program_store.get_value_at(&program_id, &mapping_name, &key, height);
+        assert_eq!(Some(value(1)), value_at(1).unwrap());
+        assert_eq!(Some(value(3)), value_at(3).unwrap());
+        assert!(value_at(0).is_err());
+
+        // Ensure the kept change sets can be reverted, and the pruned change sets cannot.
+        program_store.revert_change_set(3).unwrap();
+        program_store.revert_change_set(2).unwrap();
+        assert_eq!(Some(value(1)), program_store.get_value(&program_id, &mapping_name, &key).unwrap());
+        assert!(program_store.revert_change_set(1).is_err());
+    }
+
+    #[test]
+    fn test_rewind_change_set() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("other").unwrap();
+
+        // Prepare the keys and values.
+        let entries = (0..3)
+            .map(|item| {
+                let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+                let value = Value::from_str(&format!("{item}u64")).unwrap();
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+
+        // Initialize a new program store.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        // Ensure a change set cannot be rewound while one is not being recorded.
+        assert_eq!(None, program_store.num_recorded_changes());
+        assert!(program_store.rewind_change_set(0).is_err());
+
+        // Record the changes of a first transaction, which initializes the mapping and inserts two keys.
+        program_store.start_change_set(0).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        for (key, value) in &entries[..2] {
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        }
+        assert_eq!(Some(3), program_store.num_recorded_changes());
+        let checksum = program_store.get_checksum().unwrap();
+        let mapping =
+            program_store.get_mapping(&program_id, &mapping_name).unwrap().collect::<Result<Vec<_>>>().unwrap();
+
+        // Record the changes of a second transaction, which updates, removes, and inserts keys,
+        // and initializes another mapping.
+        let new_value = Value::from_str("100u64").unwrap();
+        program_store.update_key_value(&program_id, &mapping_name, entries[0].0.clone(), new_value).unwrap();
+        program_store.remove_key_value(&program_id, &mapping_name, &entries[1].0).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, entries[2].0.clone(), entries[2].1.clone()).unwrap();
+        program_store.initialize_mapping(&program_id, &other_mapping_name).unwrap();
+        assert_eq!(Some(7), program_store.num_recorded_changes());
+
+        // Ensure the change set cannot be rewound beyond its length.
+        assert!(program_store.rewind_change_set(8).is_err());
+
+        // Rewind the second transaction, and ensure the changes of the first transaction are kept.
+        program_store.rewind_change_set(3).unwrap();
+        assert_eq!(Some(3), program_store.num_recorded_changes());
+        assert_eq!(checksum, program_store.get_checksum().unwrap());
+        assert_eq!(
+            mapping,
+            program_store.get_mapping(&program_id, &mapping_name).unwrap().collect::<Result<Vec<_>>>().unwrap()
+        );
+        assert!(!program_store.contains_mapping(&program_id, &other_mapping_name).unwrap());
+
+        // Ensure only the changes of the first transaction are stored.
+        program_store.finish_change_set().unwrap();
+        assert_eq!(3, program_store.get_change_set(0).unwrap().unwrap().len());
+        assert!(program_store.rewind_change_set(0).is_err());
+    }
+
+    #[test]
+    fn test_read_view() {
+        use crate::store::helpers::database::{test_helpers::open_temporary_database, ProgramDB};
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("1field").unwrap();
+        let (value_0, value_1) = (Value::from_str("0u64").unwrap(), Value::from_str("1u64").unwrap());
+
+        // Initialize a new program store, with a key-value pair.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value_0.clone()).unwrap();
+        let checksum_0 = program_store.get_checksum().unwrap();
+
+        // Start an atomic batch, and update the key-value pair.
+        program_store.start_atomic();
+        program_store.update_key_value(&program_id, &mapping_name, key.clone(), value_1.clone()).unwrap();
+        assert_ne!(checksum_0, program_store.get_checksum().unwrap());
+
+        // Take a read view while the batch is in progress, and ensure it excludes the pending changes.
+        let view = program_store.read_view().unwrap();
+        assert_eq!(Some(value_0.clone()), view.get_value(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(checksum_0, view.get_checksum().unwrap());
+
+        // Finish the batch, and insert another key-value pair.
+        program_store.finish_atomic().unwrap();
+        let other_key = Plaintext::from_str("2field").unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, other_key.clone(), value_1.clone()).unwrap();
+
+        // Ensure the program store contains the latest changes.
+        assert_eq!(Some(value_1.clone()), program_store.get_value(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(Some(2), program_store.get_mapping_count(&program_id, &mapping_name).unwrap());
+
+        // Ensure the view is unchanged, and its proofs verify against its own checksum.
+        assert_eq!(Some(value_0.clone()), view.get_value(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(Some(1), view.get_mapping_count(&program_id, &mapping_name).unwrap());
+        assert!(!view.contains_key(&program_id, &mapping_name, &other_key).unwrap());
+        assert_eq!(checksum_0, view.get_checksum().unwrap());
+        let path = view.prove_mapping_value(&program_id, &mapping_name, &key).unwrap();
+        assert!(path.verify(&checksum_0, &program_id, &mapping_name, &key, &value_0).unwrap());
+
+        // Ensure the view cannot be written to.
+        assert!(view.update_key_value(&program_id, &mapping_name, key.clone(), value_1).is_err());
+        assert!(view.remove_key_value(&program_id, &mapping_name, &key).is_err());
+        assert_eq!(Some(value_0), view.get_value(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(checksum_0, view.get_checksum().unwrap());
+
+        // Ensure a database-backed program store does not support read views.
+        let (_directory, _database, dev) = open_temporary_database::<CurrentNetwork>();
+        let program_store = ProgramStore::<CurrentNetwork, ProgramDB<CurrentNetwork>>::open(dev).unwrap();
+        assert!(program_store.read_view().is_err());
+    }
+
+    /// An in-memory program state storage, whose maps fail to finish an atomic batch when a failure is injected.
+    #[derive(Clone)]
+    struct FailingProgramMemory<N: Network> {
+        /// The program ID map.
+        program_id_map: FailingMap<ProgramID<N>, IndexSet<Identifier<N>>>,
+        /// The program index map.
+        program_index_map: FailingMap<ProgramID<N>, u32>,
+        /// The mapping ID map.
+        mapping_id_map: FailingMap<(ProgramID<N>, Identifier<N>), Field<N>>,
+        /// The key-value ID map.
+        key_value_id_map: FailingMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
+        /// The key map.
+        key_map: FailingMap<Field<N>, Plaintext<N>>,
+        /// The key index map.
+        key_index_map: FailingMap<(Field<N>, Field<N>), ()>,
+        /// The value map.
+        value_map: FailingMap<Field<N>, Value<N>>,
+        /// The change set map.
+        change_set_map: FailingMap<u32, Vec<MappingChange<N>>>,
+        /// The key history map.
+        key_history_map: FailingMap<Field<N>, Vec<u32>>,
+        /// The optional development ID.
+        dev: Option<u16>,
+    }
+
+    impl<N: Network> ProgramStorage<N> for FailingProgramMemory<N> {
+        type ProgramIDMap = FailingMap<ProgramID<N>, IndexSet<Identifier<N>>>;
+        type ProgramIndexMap = FailingMap<ProgramID<N>, u32>;
+        type MappingIDMap = FailingMap<(ProgramID<N>, Identifier<N>), Field<N>>;
+        type KeyValueIDMap = FailingMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
+        type KeyMap = FailingMap<Field<N>, Plaintext<N>>;
+        type KeyIndexMap = FailingMap<(Field<N>, Field<N>), ()>;
+        type ValueMap = FailingMap<Field<N>, Value<N>>;
+        type ChangeSetMap = FailingMap<u32, Vec<MappingChange<N>>>;
+        type KeyHistoryMap = FailingMap<Field<N>, Vec<u32>>;
+
+        /// Initializes the program state storage.
+        fn open(dev: Option<u16>) -> Result<Self> {
+            Ok(Self {
+                program_id_map: FailingMap::default(),
+                program_index_map: FailingMap::default(),
+                mapping_id_map: FailingMap::default(),
+                key_value_id_map: FailingMap::default(),
+                key_map: FailingMap::default(),
+                key_index_map: FailingMap::default(),
+                value_map: FailingMap::default(),
+                change_set_map: FailingMap::default(),
+                key_history_map: FailingMap::default(),
+                dev,
+            })
+        }
+
+        /// Returns a read-only view of the program state storage.
+        /// Note: Read views are not supported by the failing storage.
+        fn read_view(&self) -> Result<Self> {
+            bail!("Read views are not supported by the failing storage")
+        }
+
+        /// Returns the program ID map.
+        fn program_id_map(&self) -> &Self::ProgramIDMap {
+            &self.program_id_map
+        }
+
+        /// Returns the program index map.
+        fn program_index_map(&self) -> &Self::ProgramIndexMap {
+            &self.program_index_map
+        }
+
+        /// Returns the mapping ID map.
+        fn mapping_id_map(&self) -> &Self::MappingIDMap {
+            &self.mapping_id_map
+        }
+
+        /// Returns the key-value ID map.
+        fn key_value_id_map(&self) -> &Self::KeyValueIDMap {
+            &self.key_value_id_map
+        }
+
+        /// Returns the key map.
+        fn key_map(&self) -> &Self::KeyMap {
+            &self.key_map
+        }
+
+        /// Returns the key index map.
+        fn key_index_map(&self) -> &Self::KeyIndexMap {
+            &self.key_index_map
+        }
+
+        /// Returns the value map.
+        fn value_map(&self) -> &Self::ValueMap {
+            &self.value_map
+        }
+
+        /// Returns the change set map.
+        fn change_set_map(&self) -> &Self::ChangeSetMap {
+            &self.change_set_map
+        }
+
+        /// Returns the key history map.
+        fn key_history_map(&self) -> &Self::KeyHistoryMap {
+            &self.key_history_map
+        }
+
+        /// Returns the optional development ID.
+        fn dev(&self) -> Option<u16> {
+            self.dev
+        }
+    }
+
+    #[test]
+    fn test_finish_atomic_failure() {
+        use crate::store::helpers::memory_map::test_helpers::{fail_each_finish_atomic, fail_finish_atomic_after};
+
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_0 = Identifier::from_str("account").unwrap();
+        let mapping_1 = Identifier::from_str("supply").unwrap();
+        let (key_0, key_1) = (Plaintext::from_str("0field").unwrap(), Plaintext::from_str("1field").unwrap());
+        let value = Value::from_str("1u64").unwrap();
+
+        // Initialize a new program store, with one mapping.
+        let program_store = ProgramStore::<_, FailingProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_0).unwrap();
+        let checksum = program_store.get_checksum().unwrap();
+
+        // Fail each map of the batch in turn, until the batch finishes.
+        fail_each_finish_atomic(
+            || {
+                // Write to several maps in one atomic batch.
+                program_store.start_atomic();
+                program_store.initialize_mapping(&program_id, &mapping_1)?;
+                program_store.insert_key_value(&program_id, &mapping_0, key_0.clone(), value.clone())?;
+                program_store.insert_key_value(&program_id, &mapping_1, key_1.clone(), value.clone())?;
+                // Finish the batch, aborting it on failure.
+                let result = program_store.finish_atomic();
+                if result.is_err() {
+                    program_store.abort_atomic();
+                }
+                result
+            },
+            || {
+                // Ensure none of the writes were applied.
+                assert!(!program_store.is_atomic_in_progress());
+                assert!(!program_store.contains_mapping(&program_id, &mapping_1).unwrap());
+                assert!(!program_store.contains_key(&program_id, &mapping_0, &key_0).unwrap());
+                assert_eq!(checksum, program_store.get_checksum().unwrap());
+                assert_eq!(program_store.get_mapping_names(&program_id).unwrap().unwrap().len(), 1);
+            },
+        );
+
+        // Ensure the batch was applied in full.
+        assert!(program_store.contains_mapping(&program_id, &mapping_1).unwrap());
+        assert_eq!(value, program_store.get_value(&program_id, &mapping_0, &key_0).unwrap().unwrap());
+        assert_eq!(value, program_store.get_value(&program_id, &mapping_1, &key_1).unwrap().unwrap());
+        let checksum = program_store.get_checksum().unwrap();
+
+        // Ensure a failure in a single-operation batch is aborted as well.
+        fail_finish_atomic_after(Some(1));
+        assert!(program_store.remove_key_value(&program_id, &mapping_0, &key_0).is_err());
+        fail_finish_atomic_after(None);
+        assert!(!program_store.is_atomic_in_progress());
+        assert_eq!(value, program_store.get_value(&program_id, &mapping_0, &key_0).unwrap().unwrap());
+        assert_eq!(checksum, program_store.get_checksum().unwrap());
+    }
 }
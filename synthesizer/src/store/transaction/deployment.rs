@@ -23,7 +23,11 @@ use crate::{
     program::Program,
     snark::{Certificate, Proof, VerifyingKey},
     store::{
-        helpers::{memory_map::MemoryMap, Map, MapRead},
+        helpers::{
+            memory_map::{finish_atomic_journaled, MemoryMap},
+            Map,
+            MapRead,
+        },
         TransitionMemory,
         TransitionStorage,
         TransitionStore,
@@ -65,6 +69,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     /// Initializes the deployment storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
 
+    /// Returns a read-only view of the deployment storage, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    fn read_view(&self, transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
     /// Returns the edition map.
@@ -510,6 +518,23 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
         })
     }
 
+    /// Returns a read-only view of the deployment storage, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    fn read_view(&self, transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        Ok(Self {
+            id_map: self.id_map.read_view()?,
+            edition_map: self.edition_map.read_view()?,
+            reverse_id_map: self.reverse_id_map.read_view()?,
+            owner_map: self.owner_map.read_view()?,
+            program_map: self.program_map.read_view()?,
+            verifying_key_map: self.verifying_key_map.read_view()?,
+            certificate_map: self.certificate_map.read_view()?,
+            fee_map: self.fee_map.read_view()?,
+            reverse_fee_map: self.reverse_fee_map.read_view()?,
+            transition_store,
+        })
+    }
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap {
         &self.id_map
@@ -584,6 +609,12 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         Self { storage, _phantom: PhantomData }
     }
 
+    /// Returns a read-only view of the deployment store, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    pub fn read_view(&self, transition_store: TransitionStore<N, D::TransitionStorage>) -> Result<Self> {
+        Ok(Self::from(self.storage.read_view(transition_store)?))
+    }
+
     /// Stores the given `deployment transaction` into storage.
     pub fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         self.storage.insert(transaction)
@@ -611,7 +642,7 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
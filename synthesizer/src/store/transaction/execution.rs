@@ -22,7 +22,11 @@ use crate::{
     process::{Execution, Fee},
     snark::Proof,
     store::{
-        helpers::{memory_map::MemoryMap, Map, MapRead},
+        helpers::{
+            memory_map::{finish_atomic_journaled, MemoryMap},
+            Map,
+            MapRead,
+        },
         TransitionMemory,
         TransitionStorage,
         TransitionStore,
@@ -50,6 +54,10 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     /// Initializes the execution storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
 
+    /// Returns a read-only view of the execution storage, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    fn read_view(&self, transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
     /// Returns the reverse ID map.
@@ -194,6 +202,45 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the execution transaction for the given `transaction ID`, keeping the serial numbers and commitments.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs and fee transition ID.
+        let (transition_ids, fee_transition_id) = match self.id_map().get(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
+        };
+
+        atomic_write_batch!(self, {
+            // Remove the transition IDs.
+            self.id_map().remove(transaction_id)?;
+
+            // Prune the execution.
+            for transition_id in transition_ids {
+                // Remove the transition ID.
+                self.reverse_id_map().remove(&transition_id)?;
+                // Prune the transition.
+                self.transition_store().prune(&transition_id)?;
+            }
+
+            // Remove the global state root and inclusion proof.
+            self.inclusion_map().remove(transaction_id)?;
+
+            // Prune the fee.
+            if let Some(fee_transition_id) = fee_transition_id {
+                // Remove the fee transition ID.
+                self.reverse_id_map().remove(&fee_transition_id)?;
+                // Prune the fee transition.
+                self.transition_store().prune(&fee_transition_id)?;
+                // Remove the fee.
+                self.fee_map().remove(transaction_id)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -327,6 +374,18 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
         })
     }
 
+    /// Returns a read-only view of the execution storage, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    fn read_view(&self, transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        Ok(Self {
+            id_map: self.id_map.read_view()?,
+            reverse_id_map: self.reverse_id_map.read_view()?,
+            transition_store,
+            inclusion_map: self.inclusion_map.read_view()?,
+            fee_map: self.fee_map.read_view()?,
+        })
+    }
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap {
         &self.id_map
@@ -376,6 +435,12 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         Self { storage, _phantom: PhantomData }
     }
 
+    /// Returns a read-only view of the execution store, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    pub fn read_view(&self, transition_store: TransitionStore<N, E::TransitionStorage>) -> Result<Self> {
+        Ok(Self::from(self.storage.read_view(transition_store)?))
+    }
+
     /// Stores the given `execution transaction` into storage.
     pub fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         self.storage.insert(transaction)
@@ -386,6 +451,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the execution transaction for the given `transaction ID`, keeping the serial numbers and commitments.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, E::TransitionStorage> {
         self.storage.transition_store()
@@ -408,7 +478,7 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
@@ -23,12 +23,17 @@ pub use execution::*;
 use crate::{
     atomic_write_batch,
     block::Transaction,
+    cow_to_cloned,
     cow_to_copied,
     process::{Deployment, Execution},
     program::Program,
     snark::{Certificate, VerifyingKey},
     store::{
-        helpers::{memory_map::MemoryMap, Map, MapRead},
+        helpers::{
+            memory_map::{finish_atomic_journaled, MemoryMap},
+            Map,
+            MapRead,
+        },
         TransitionMemory,
         TransitionStorage,
         TransitionStore,
@@ -49,12 +54,18 @@ pub enum TransactionType {
     Deploy,
     /// A transaction that is an execution.
     Execute,
+    /// A transaction that is aborted, of which only the fee transition is stored.
+    Aborted,
 }
 
 /// A trait for transaction storage.
 pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `transaction type`.
     type IDMap: for<'a> Map<'a, N::TransactionID, TransactionType>;
+    /// The mapping of `transaction ID` to `aborted transaction`.
+    type AbortedMap: for<'a> Map<'a, N::TransactionID, Transaction<N>>;
+    /// The mapping of `fee transition ID` to `aborted transaction ID`.
+    type AbortedFeeMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The deployment storage.
     type DeploymentStorage: DeploymentStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The execution storage.
@@ -65,8 +76,16 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Initializes the transaction storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
 
+    /// Returns a read-only view of the transaction storage, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    fn read_view(&self, transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the aborted map.
+    fn aborted_map(&self) -> &Self::AbortedMap;
+    /// Returns the aborted fee map.
+    fn aborted_fee_map(&self) -> &Self::AbortedFeeMap;
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage>;
     /// Returns the execution store.
@@ -85,6 +104,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.aborted_map().start_atomic();
+        self.aborted_fee_map().start_atomic();
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
     }
@@ -92,6 +113,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.aborted_map().is_atomic_in_progress()
+            || self.aborted_fee_map().is_atomic_in_progress()
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
     }
@@ -99,6 +122,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.aborted_map().abort_atomic();
+        self.aborted_fee_map().abort_atomic();
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
     }
@@ -106,6 +131,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.aborted_map().finish_atomic()?;
+        self.aborted_fee_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()
     }
@@ -134,6 +161,29 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the given aborted `transaction` into storage.
+    /// Only the fee transition is stored in the transition store, so that the serial numbers
+    /// and commitments of the remaining transitions are neither spent nor created.
+    fn insert_aborted(&self, transaction: &Transaction<N>) -> Result<()> {
+        atomic_write_batch!(self, {
+            // Store the transaction type.
+            self.id_map().insert(transaction.id(), TransactionType::Aborted)?;
+            // Store the aborted transaction.
+            self.aborted_map().insert(transaction.id(), transaction.clone())?;
+            // Store the fee transition, if one exists.
+            if let Some(fee) = transaction.fee_transition() {
+                // Store the reverse fee ID.
+                self.aborted_fee_map().insert(*fee.transition_id(), transaction.id())?;
+                // Store the fee transition.
+                self.transition_store().insert(fee)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Removes the transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
         // Retrieve the transaction type.
@@ -151,6 +201,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                 TransactionType::Deploy => self.deployment_store().remove(transaction_id)?,
                 // Remove the execution transaction.
                 TransactionType::Execute => self.execution_store().remove(transaction_id)?,
+                // Remove the aborted transaction.
+                TransactionType::Aborted => self.remove_aborted(transaction_id, false)?,
             };
 
             Ok(())
@@ -159,12 +211,70 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the transaction for the given `transaction ID`, keeping the serial numbers and commitments.
+    /// Note: Deployment transactions can not be pruned, as they are required to load the programs.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+        };
+        // Ensure the transaction is not a deployment.
+        if transaction_type == TransactionType::Deploy {
+            bail!("Cannot prune the deployment transaction '{transaction_id}'")
+        }
+
+        atomic_write_batch!(self, {
+            // Remove the transaction type.
+            self.id_map().remove(transaction_id)?;
+            // Prune the transaction.
+            match transaction_type {
+                TransactionType::Aborted => self.remove_aborted(transaction_id, true),
+                _ => self.execution_store().prune(transaction_id),
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Removes the aborted transaction for the given `transaction ID`, along with its fee transition.
+    /// If `prune` is `true`, the fee transition is pruned instead, keeping its serial numbers and commitments.
+    fn remove_aborted(&self, transaction_id: &N::TransactionID, prune: bool) -> Result<()> {
+        // Retrieve the aborted transaction.
+        let transaction = match self.aborted_map().get(transaction_id)? {
+            Some(transaction) => cow_to_cloned!(transaction),
+            None => bail!("Failed to get the aborted transaction '{transaction_id}'"),
+        };
+
+        atomic_write_batch!(self, {
+            // Remove the aborted transaction.
+            self.aborted_map().remove(transaction_id)?;
+            // Remove the fee transition, if one exists.
+            if let Some(fee) = transaction.fee_transition() {
+                // Remove the reverse fee ID.
+                self.aborted_fee_map().remove(fee.transition_id())?;
+                // Remove or prune the fee transition.
+                match prune {
+                    true => self.transition_store().prune(fee.transition_id())?,
+                    false => self.transition_store().remove(fee.transition_id())?,
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
         transition_id: &N::TransitionID,
     ) -> Result<Option<N::TransactionID>> {
-        self.execution_store().find_transaction_id_from_transition_id(transition_id)
+        match self.execution_store().find_transaction_id_from_transition_id(transition_id)? {
+            Some(transaction_id) => Ok(Some(transaction_id)),
+            None => Ok(self.aborted_fee_map().get(transition_id)?.map(|id| cow_to_copied!(id))),
+        }
     }
 
     /// Returns the transaction ID that contains the given `program ID`.
@@ -185,6 +295,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             TransactionType::Deploy => self.deployment_store().get_transaction(transaction_id),
             // Return the execution transaction.
             TransactionType::Execute => self.execution_store().get_transaction(transaction_id),
+            // Return the aborted transaction.
+            TransactionType::Aborted => Ok(self.aborted_map().get(transaction_id)?.map(|tx| cow_to_cloned!(tx))),
         }
     }
 }
@@ -194,6 +306,10 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 pub struct TransactionMemory<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: MemoryMap<N::TransactionID, TransactionType>,
+    /// The mapping of `transaction ID` to `aborted transaction`.
+    aborted_map: MemoryMap<N::TransactionID, Transaction<N>>,
+    /// The mapping of `fee transition ID` to `aborted transaction ID`.
+    aborted_fee_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentMemory<N>>,
     /// The execution store.
@@ -203,6 +319,8 @@ pub struct TransactionMemory<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, TransactionType>;
+    type AbortedMap = MemoryMap<N::TransactionID, Transaction<N>>;
+    type AbortedFeeMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type DeploymentStorage = DeploymentMemory<N>;
     type ExecutionStorage = ExecutionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
@@ -214,7 +332,25 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(transition_store)?;
         // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store })
+        Ok(Self {
+            id_map: MemoryMap::default(),
+            aborted_map: MemoryMap::default(),
+            aborted_fee_map: MemoryMap::default(),
+            deployment_store,
+            execution_store,
+        })
+    }
+
+    /// Returns a read-only view of the transaction storage, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    fn read_view(&self, transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        Ok(Self {
+            id_map: self.id_map.read_view()?,
+            aborted_map: self.aborted_map.read_view()?,
+            aborted_fee_map: self.aborted_fee_map.read_view()?,
+            deployment_store: self.deployment_store.read_view(transition_store.clone())?,
+            execution_store: self.execution_store.read_view(transition_store)?,
+        })
     }
 
     /// Returns the ID map.
@@ -222,6 +358,16 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         &self.id_map
     }
 
+    /// Returns the aborted map.
+    fn aborted_map(&self) -> &Self::AbortedMap {
+        &self.aborted_map
+    }
+
+    /// Returns the aborted fee map.
+    fn aborted_fee_map(&self) -> &Self::AbortedFeeMap {
+        &self.aborted_fee_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
@@ -256,16 +402,32 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         Self { transaction_ids: storage.id_map().clone(), storage }
     }
 
+    /// Returns a read-only view of the transaction store, as of the last finished atomic batch.
+    /// The view shares the given view of the transition store.
+    pub fn read_view(&self, transition_store: TransitionStore<N, T::TransitionStorage>) -> Result<Self> {
+        Ok(Self::from(self.storage.read_view(transition_store)?))
+    }
+
     /// Stores the given `transaction` into storage.
     pub fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         self.storage.insert(transaction)
     }
 
+    /// Stores the given aborted `transaction` into storage, keeping only its fee transition.
+    pub fn insert_aborted(&self, transaction: &Transaction<N>) -> Result<()> {
+        self.storage.insert_aborted(transaction)
+    }
+
     /// Removes the transaction for the given `transaction ID`.
     pub fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the transaction for the given `transaction ID`, keeping the serial numbers and commitments.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, T::TransitionStorage> {
         self.storage.transition_store()
@@ -288,7 +450,7 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
@@ -316,6 +478,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Deploy => self.storage.deployment_store().get_deployment(transaction_id),
             // Throw an error.
             TransactionType::Execute => bail!("Tried to get a deployment for execution transaction '{transaction_id}'"),
+            // Throw an error.
+            TransactionType::Aborted => bail!("Tried to get a deployment for aborted transaction '{transaction_id}'"),
         }
     }
 
@@ -332,6 +496,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Deploy => bail!("Tried to get an execution for deployment transaction '{transaction_id}'"),
             // Return the execution.
             TransactionType::Execute => self.storage.execution_store().get_execution(transaction_id),
+            // Throw an error.
+            TransactionType::Aborted => bail!("Tried to get an execution for aborted transaction '{transaction_id}'"),
         }
     }
 
@@ -354,7 +520,7 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
                 }
             }
             // Return the edition.
-            TransactionType::Execute => Ok(None),
+            TransactionType::Execute | TransactionType::Aborted => Ok(None),
         }
     }
 
@@ -466,6 +632,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{snark::Proof, store::helpers::memory_map::test_helpers::FailingMap};
+    use console::program::ProgramOwner;
 
     #[test]
     fn test_insert_get_remove() {
@@ -503,6 +671,350 @@ mod tests {
         }
     }
 
+    /// An in-memory transaction storage, whose maps fail to finish an atomic batch when a failure is injected.
+    #[derive(Clone)]
+    struct FailingTransactionMemory<N: Network> {
+        /// The mapping of `transaction ID` to `transaction type`.
+        id_map: FailingMap<N::TransactionID, TransactionType>,
+        /// The mapping of `transaction ID` to `aborted transaction`.
+        aborted_map: FailingMap<N::TransactionID, Transaction<N>>,
+        /// The mapping of `fee transition ID` to `aborted transaction ID`.
+        aborted_fee_map: FailingMap<N::TransitionID, N::TransactionID>,
+        /// The deployment store.
+        deployment_store: DeploymentStore<N, FailingDeploymentMemory<N>>,
+        /// The execution store.
+        execution_store: ExecutionStore<N, FailingExecutionMemory<N>>,
+    }
+
+    #[rustfmt::skip]
+    impl<N: Network> TransactionStorage<N> for FailingTransactionMemory<N> {
+        type IDMap = FailingMap<N::TransactionID, TransactionType>;
+        type AbortedMap = FailingMap<N::TransactionID, Transaction<N>>;
+        type AbortedFeeMap = FailingMap<N::TransitionID, N::TransactionID>;
+        type DeploymentStorage = FailingDeploymentMemory<N>;
+        type ExecutionStorage = FailingExecutionMemory<N>;
+        type TransitionStorage = TransitionMemory<N>;
+
+        /// Initializes the transaction storage.
+        fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+            // Initialize the deployment store.
+            let deployment_store = DeploymentStore::<N, FailingDeploymentMemory<N>>::open(transition_store.clone())?;
+            // Initialize the execution store.
+            let execution_store = ExecutionStore::<N, FailingExecutionMemory<N>>::open(transition_store)?;
+            // Return the transaction storage.
+            Ok(Self {
+                id_map: FailingMap::default(),
+                aborted_map: FailingMap::default(),
+                aborted_fee_map: FailingMap::default(),
+                deployment_store,
+                execution_store,
+            })
+        }
+
+        /// Returns a read-only view of the transaction storage.
+        /// Note: Read views are not supported by the failing storage.
+        fn read_view(&self, _transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+            bail!("Read views are not supported by the failing storage")
+        }
+
+        /// Returns the ID map.
+        fn id_map(&self) -> &Self::IDMap {
+            &self.id_map
+        }
+
+        /// Returns the aborted map.
+        fn aborted_map(&self) -> &Self::AbortedMap {
+            &self.aborted_map
+        }
+
+        /// Returns the aborted fee map.
+        fn aborted_fee_map(&self) -> &Self::AbortedFeeMap {
+            &self.aborted_fee_map
+        }
+
+        /// Returns the deployment store.
+        fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
+            &self.deployment_store
+        }
+
+        /// Returns the execution store.
+        fn execution_store(&self) -> &ExecutionStore<N, Self::ExecutionStorage> {
+            &self.execution_store
+        }
+    }
+
+    /// An in-memory deployment storage, whose maps fail to finish an atomic batch when a failure is injected.
+    #[derive(Clone)]
+    #[allow(clippy::type_complexity)]
+    struct FailingDeploymentMemory<N: Network> {
+        /// The ID map.
+        id_map: FailingMap<N::TransactionID, ProgramID<N>>,
+        /// The edition map.
+        edition_map: FailingMap<ProgramID<N>, u16>,
+        /// The reverse ID map.
+        reverse_id_map: FailingMap<(ProgramID<N>, u16), N::TransactionID>,
+        /// The owner map.
+        owner_map: FailingMap<(ProgramID<N>, u16), ProgramOwner<N>>,
+        /// The program map.
+        program_map: FailingMap<(ProgramID<N>, u16), Program<N>>,
+        /// The verifying key map.
+        verifying_key_map: FailingMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
+        /// The certificate map.
+        certificate_map: FailingMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+        /// The fee map.
+        fee_map: FailingMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>,
+        /// The reverse fee map.
+        reverse_fee_map: FailingMap<N::TransitionID, N::TransactionID>,
+        /// The transition store.
+        transition_store: TransitionStore<N, TransitionMemory<N>>,
+    }
+
+    #[rustfmt::skip]
+    impl<N: Network> DeploymentStorage<N> for FailingDeploymentMemory<N> {
+        type IDMap = FailingMap<N::TransactionID, ProgramID<N>>;
+        type EditionMap = FailingMap<ProgramID<N>, u16>;
+        type ReverseIDMap = FailingMap<(ProgramID<N>, u16), N::TransactionID>;
+        type OwnerMap = FailingMap<(ProgramID<N>, u16), ProgramOwner<N>>;
+        type ProgramMap = FailingMap<(ProgramID<N>, u16), Program<N>>;
+        type VerifyingKeyMap = FailingMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
+        type CertificateMap = FailingMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+        type FeeMap = FailingMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
+        type ReverseFeeMap = FailingMap<N::TransitionID, N::TransactionID>;
+        type TransitionStorage = TransitionMemory<N>;
+
+        /// Initializes the deployment storage.
+        fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+            Ok(Self {
+                id_map: FailingMap::default(),
+                edition_map: FailingMap::default(),
+                reverse_id_map: FailingMap::default(),
+                owner_map: FailingMap::default(),
+                program_map: FailingMap::default(),
+                verifying_key_map: FailingMap::default(),
+                certificate_map: FailingMap::default(),
+                fee_map: FailingMap::default(),
+                reverse_fee_map: FailingMap::default(),
+                transition_store,
+            })
+        }
+
+        /// Returns a read-only view of the deployment storage.
+        /// Note: Read views are not supported by the failing storage.
+        fn read_view(&self, _transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+            bail!("Read views are not supported by the failing storage")
+        }
+
+        /// Returns the ID map.
+        fn id_map(&self) -> &Self::IDMap {
+            &self.id_map
+        }
+
+        /// Returns the edition map.
+        fn edition_map(&self) -> &Self::EditionMap {
+            &self.edition_map
+        }
+
+        /// Returns the reverse ID map.
+        fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+            &self.reverse_id_map
+        }
+
+        /// Returns the owner map.
+        fn owner_map(&self) -> &Self::OwnerMap {
+            &self.owner_map
+        }
+
+        /// Returns the program map.
+        fn program_map(&self) -> &Self::ProgramMap {
+            &self.program_map
+        }
+
+        /// Returns the verifying key map.
+        fn verifying_key_map(&self) -> &Self::VerifyingKeyMap {
+            &self.verifying_key_map
+        }
+
+        /// Returns the certificate map.
+        fn certificate_map(&self) -> &Self::CertificateMap {
+            &self.certificate_map
+        }
+
+        /// Returns the fee map.
+        fn fee_map(&self) -> &Self::FeeMap {
+            &self.fee_map
+        }
+
+        /// Returns the reverse fee map.
+        fn reverse_fee_map(&self) -> &Self::ReverseFeeMap {
+            &self.reverse_fee_map
+        }
+
+        /// Returns the transition store.
+        fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
+            &self.transition_store
+        }
+    }
+
+    /// An in-memory execution storage, whose maps fail to finish an atomic batch when a failure is injected.
+    #[derive(Clone)]
+    #[allow(clippy::type_complexity)]
+    struct FailingExecutionMemory<N: Network> {
+        /// The ID map.
+        id_map: FailingMap<N::TransactionID, (Vec<N::TransitionID>, Option<N::TransitionID>)>,
+        /// The reverse ID map.
+        reverse_id_map: FailingMap<N::TransitionID, N::TransactionID>,
+        /// The transition store.
+        transition_store: TransitionStore<N, TransitionMemory<N>>,
+        /// The inclusion map.
+        inclusion_map: FailingMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+        /// The fee map.
+        fee_map: FailingMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    }
+
+    #[rustfmt::skip]
+    impl<N: Network> ExecutionStorage<N> for FailingExecutionMemory<N> {
+        type IDMap = FailingMap<N::TransactionID, (Vec<N::TransitionID>, Option<N::TransitionID>)>;
+        type ReverseIDMap = FailingMap<N::TransitionID, N::TransactionID>;
+        type TransitionStorage = TransitionMemory<N>;
+        type InclusionMap = FailingMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+        type FeeMap = FailingMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+
+        /// Initializes the execution storage.
+        fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+            Ok(Self {
+                id_map: FailingMap::default(),
+                reverse_id_map: FailingMap::default(),
+                transition_store,
+                inclusion_map: FailingMap::default(),
+                fee_map: FailingMap::default(),
+            })
+        }
+
+        /// Returns a read-only view of the execution storage.
+        /// Note: Read views are not supported by the failing storage.
+        fn read_view(&self, _transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+            bail!("Read views are not supported by the failing storage")
+        }
+
+        /// Returns the ID map.
+        fn id_map(&self) -> &Self::IDMap {
+            &self.id_map
+        }
+
+        /// Returns the reverse ID map.
+        fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+            &self.reverse_id_map
+        }
+
+        /// Returns the transition store.
+        fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
+            &self.transition_store
+        }
+
+        /// Returns the inclusion map.
+        fn inclusion_map(&self) -> &Self::InclusionMap {
+            &self.inclusion_map
+        }
+
+        /// Returns the fee map.
+        fn fee_map(&self) -> &Self::FeeMap {
+            &self.fee_map
+        }
+    }
+
+    #[test]
+    fn test_finish_atomic_failure() {
+        use crate::store::helpers::memory_map::test_helpers::{fail_each_finish_atomic, fail_finish_atomic_after};
+
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        for transaction in [
+            crate::vm::test_helpers::sample_deployment_transaction(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_fee(rng),
+        ] {
+            let transaction_id = transaction.id();
+
+            // Initialize a new transition store.
+            let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+            // Initialize a new transaction store.
+            let transaction_store = TransactionStore::<_, FailingTransactionMemory<_>>::open(transition_store).unwrap();
+
+            // Fail each map of the insertion in turn, until the transaction is inserted.
+            fail_each_finish_atomic(
+                || transaction_store.insert(&transaction),
+                || {
+                    // Ensure none of the transaction was inserted.
+                    assert!(!transaction_store.is_atomic_in_progress());
+                    assert_eq!(transaction_store.transaction_ids().count(), 0);
+                    assert_eq!(transaction_store.transition_store().transition_ids().count(), 0);
+                },
+            );
+            assert_eq!(Some(transaction.clone()), transaction_store.get_transaction(&transaction_id).unwrap());
+
+            // Fail the removal partway, and ensure the transaction is unchanged.
+            fail_finish_atomic_after(Some(1));
+            assert!(transaction_store.remove(&transaction_id).is_err());
+            fail_finish_atomic_after(None);
+            assert!(!transaction_store.is_atomic_in_progress());
+            assert_eq!(Some(transaction), transaction_store.get_transaction(&transaction_id).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_insert_aborted() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_fee(rng);
+        let transaction_id = transaction.id();
+        let fee = transaction.fee_transition().unwrap().clone();
+        let (execution_serial_numbers, execution_commitments) = match transaction {
+            Transaction::Execute(_, ref execution, _) => (
+                execution.transitions().flat_map(|transition| transition.serial_numbers().copied()).collect::<Vec<_>>(),
+                execution.transitions().flat_map(|transition| transition.commitments().copied()).collect::<Vec<_>>(),
+            ),
+            _ => panic!("Incorrect transaction type"),
+        };
+        assert!(!execution_serial_numbers.is_empty());
+        assert!(!execution_commitments.is_empty());
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Insert the aborted transaction.
+        transaction_store.insert_aborted(&transaction).unwrap();
+        // Ensure the transaction is retrievable in full.
+        assert_eq!(Some(transaction.clone()), transaction_store.get_transaction(&transaction_id).unwrap());
+        assert!(transaction_store.get_execution(&transaction_id).is_err());
+
+        // Ensure the serial numbers and commitments of the execution are not in the ledger.
+        let transition_store = transaction_store.transition_store();
+        for serial_number in &execution_serial_numbers {
+            assert!(!transition_store.contains_serial_number(serial_number).unwrap());
+        }
+        for commitment in &execution_commitments {
+            assert!(!transition_store.contains_commitment(commitment).unwrap());
+        }
+        // Ensure the fee transition is in the ledger.
+        assert_eq!(transition_store.transition_ids().count(), 1);
+        for serial_number in fee.serial_numbers() {
+            assert!(transition_store.contains_serial_number(serial_number).unwrap());
+        }
+        for commitment in fee.commitments() {
+            assert!(transition_store.contains_commitment(commitment).unwrap());
+        }
+        let candidate = transaction_store.find_transaction_id_from_transition_id(fee.transition_id()).unwrap();
+        assert_eq!(Some(transaction_id), candidate);
+
+        // Remove the transaction, and ensure the fee transition is removed.
+        transaction_store.remove(&transaction_id).unwrap();
+        assert_eq!(None, transaction_store.get_transaction(&transaction_id).unwrap());
+        assert_eq!(transaction_store.transition_store().transition_ids().count(), 0);
+    }
+
     #[test]
     fn test_find_transaction_id() {
         let rng = &mut TestRng::default();
@@ -17,7 +17,11 @@
 use crate::{
     atomic_write_batch,
     block::Input,
-    store::helpers::{memory_map::MemoryMap, Map, MapRead},
+    store::helpers::{
+        memory_map::{finish_atomic_journaled, MemoryMap},
+        Map,
+        MapRead,
+    },
 };
 use console::{
     network::prelude::*,
@@ -50,6 +54,9 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     /// Initializes the transition input storage.
     fn open(dev: Option<u16>) -> Result<Self>;
 
+    /// Returns a read-only view of the transition input storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self>;
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
     /// Returns the reverse ID map.
@@ -149,6 +156,19 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the given `(serial number, tag)` pair into storage, without the transition that spent the record.
+    /// Note: This is used to bootstrap the storage from a snapshot of the consensus state.
+    fn insert_serial_number(&self, serial_number: Field<N>, tag: Field<N>) -> Result<()> {
+        atomic_write_batch!(self, {
+            // Store the record tag.
+            self.record_tag_map().insert(tag, serial_number)?;
+            // Store the record.
+            self.record_map().insert(serial_number, tag)
+        });
+
+        Ok(())
+    }
+
     /// Removes the input for the given `transition ID`.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         // Retrieve the input IDs.
@@ -186,6 +206,37 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the input for the given `transition ID`, keeping the serial numbers and tags of its records.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs.
+        let input_ids: Vec<_> = match self.id_map().get(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_write_batch!(self, {
+            // Remove the input IDs.
+            self.id_map().remove(transition_id)?;
+
+            // Remove the inputs, except for the records.
+            for input_id in input_ids {
+                // Remove the reverse input ID.
+                self.reverse_id_map().remove(&input_id)?;
+
+                // Remove the input.
+                self.constant_map().remove(&input_id)?;
+                self.public_map().remove(&input_id)?;
+                self.private_map().remove(&input_id)?;
+                self.external_record_map().remove(&input_id)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get(input_id)? {
@@ -303,6 +354,21 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
         })
     }
 
+    /// Returns a read-only view of the transition input storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self> {
+        Ok(Self {
+            id_map: self.id_map.read_view()?,
+            reverse_id_map: self.reverse_id_map.read_view()?,
+            constant: self.constant.read_view()?,
+            public: self.public.read_view()?,
+            private: self.private.read_view()?,
+            record: self.record.read_view()?,
+            record_tag: self.record_tag.read_view()?,
+            external_record: self.external_record.read_view()?,
+            dev: self.dev,
+        })
+    }
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap {
         &self.id_map
@@ -398,16 +464,31 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         }
     }
 
+    /// Returns a read-only view of the input store, as of the last finished atomic batch.
+    pub fn read_view(&self) -> Result<Self> {
+        Ok(Self::from(self.storage.read_view()?))
+    }
+
     /// Stores the given `(transition ID, input)` pair into storage.
     pub fn insert(&self, transition_id: N::TransitionID, inputs: &[Input<N>]) -> Result<()> {
         self.storage.insert(transition_id, inputs)
     }
 
+    /// Stores the given `(serial number, tag)` pair into storage, without the transition that spent the record.
+    pub fn insert_serial_number(&self, serial_number: Field<N>, tag: Field<N>) -> Result<()> {
+        self.storage.insert_serial_number(serial_number, tag)
+    }
+
     /// Removes the input for the given `transition ID`.
     pub fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input for the given `transition ID`, keeping the serial numbers and tags of its records.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -425,7 +506,7 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
@@ -496,6 +577,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.record.keys()
     }
 
+    /// Returns an iterator over the `(serial number, tag)` pairs, for all transition inputs that are records.
+    pub fn serial_numbers_with_tags(&self) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, Field<N>>)> {
+        self.record.iter()
+    }
+
     /// Returns an iterator over the external record input IDs, for all transition inputs that are external records.
     pub fn external_input_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.external_record.keys()
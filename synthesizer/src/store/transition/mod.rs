@@ -26,7 +26,14 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     snark::Proof,
-    store::helpers::{memory_map::MemoryMap, Map, MapRead},
+    store::{
+        helpers::{
+            memory_map::{finish_atomic_journaled, MemoryMap},
+            Map,
+            MapRead,
+        },
+        IndexPage,
+    },
 };
 use console::{
     network::prelude::*,
@@ -57,10 +64,15 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type TCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
     /// The mapping of `transition commitment` to `transition ID`.
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
+    /// The mapping of `(program ID, function name, transition ID)` to `()`, for each transition of the function.
+    type FunctionIndexMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, N::TransitionID), ()>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self>;
 
+    /// Returns a read-only view of the transition storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self>;
+
     /// Returns the transition program IDs and function names.
     fn locator_map(&self) -> &Self::LocatorMap;
     /// Returns the transition input store.
@@ -79,6 +91,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn tcm_map(&self) -> &Self::TCMMap;
     /// Returns the reverse `tcm` map.
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
+    /// Returns the function index map, if the storage maintains the index.
+    fn function_index_map(&self) -> Option<&Self::FunctionIndexMap> {
+        None
+    }
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
@@ -97,6 +113,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().start_atomic();
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
+        if let Some(function_index_map) = self.function_index_map() {
+            function_index_map.start_atomic();
+        }
     }
 
     /// Checks if an atomic batch is in progress.
@@ -110,6 +129,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.reverse_tpk_map().is_atomic_in_progress()
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
+            || self.function_index_map().is_some_and(|map| map.is_atomic_in_progress())
     }
 
     /// Aborts an atomic batch write operation.
@@ -123,6 +143,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().abort_atomic();
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
+        if let Some(function_index_map) = self.function_index_map() {
+            function_index_map.abort_atomic();
+        }
     }
 
     /// Finishes an atomic batch write operation.
@@ -135,7 +158,11 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tpk_map().finish_atomic()?;
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
-        self.reverse_tcm_map().finish_atomic()
+        self.reverse_tcm_map().finish_atomic()?;
+        match self.function_index_map() {
+            Some(function_index_map) => function_index_map.finish_atomic(),
+            None => Ok(()),
+        }
     }
 
     /// Stores the given `transition` into storage.
@@ -161,8 +188,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().insert(transition_id, *transition.tcm())?;
             // Store the reverse `tcm` entry.
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
-
-            Ok(())
+            // Index the transition by its program ID and function name.
+            self.index_transition(transition_id, (*transition.program_id(), *transition.function_name()))
         });
 
         Ok(())
@@ -180,8 +207,14 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             Some(tcm) => cow_to_copied!(tcm),
             None => return Ok(()),
         };
+        // Retrieve the program ID and function name.
+        let locator = self.locator_map().get(transition_id)?.map(|locator| cow_to_copied!(locator));
 
         atomic_write_batch!(self, {
+            // Remove the transition from the function index.
+            if let Some(locator) = locator {
+                self.unindex_transition(transition_id, locator)?;
+            }
             // Remove the program ID and function name.
             self.locator_map().remove(transition_id)?;
             // Remove the inputs.
@@ -207,6 +240,78 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the transition for the given `transition ID`, keeping its serial numbers, commitments, `tpk`, and `tcm`.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the program ID and function name.
+        let locator = self.locator_map().get(transition_id)?.map(|locator| cow_to_copied!(locator));
+
+        atomic_write_batch!(self, {
+            // Remove the transition from the function index, as it can no longer be retrieved.
+            if let Some(locator) = locator {
+                self.unindex_transition(transition_id, locator)?;
+            }
+            // Remove the program ID and function name.
+            self.locator_map().remove(transition_id)?;
+            // Prune the inputs.
+            self.input_store().prune(transition_id)?;
+            // Prune the outputs.
+            self.output_store().prune(transition_id)?;
+            // Remove the finalize inputs.
+            self.finalize_map().remove(transition_id)?;
+            // Remove the proof.
+            self.proof_map().remove(transition_id)?;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Adds the given `transition ID` to the function index, under the given `(program ID, function name)`.
+    /// If the storage does not maintain the index, this is a no-op.
+    fn index_transition(&self, transition_id: N::TransitionID, locator: (ProgramID<N>, Identifier<N>)) -> Result<()> {
+        match self.function_index_map() {
+            Some(function_index_map) => function_index_map.insert((locator.0, locator.1, transition_id), ()),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes the given `transition ID` from the function index, under the given `(program ID, function name)`.
+    /// If the storage does not maintain the index, this is a no-op.
+    fn unindex_transition(
+        &self,
+        transition_id: &N::TransitionID,
+        locator: (ProgramID<N>, Identifier<N>),
+    ) -> Result<()> {
+        match self.function_index_map() {
+            Some(function_index_map) => function_index_map.remove(&(locator.0, locator.1, *transition_id)),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns up to `limit` transition IDs for the given `program ID` and `function name`, in transition ID order.
+    /// The page starts after the transition ID `cursor`, or at the first transition ID if `cursor` is `None`.
+    fn get_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        cursor: Option<&N::TransitionID>,
+        limit: usize,
+    ) -> Result<IndexPage<N::TransitionID>> {
+        // Ensure the limit is nonzero.
+        ensure!(limit > 0, "The page limit must be greater than zero");
+        let function_index_map = match self.function_index_map() {
+            Some(function_index_map) => function_index_map,
+            None => bail!("The transition storage does not maintain the function index"),
+        };
+        // Retrieve the transition IDs for the program ID and function name, with one more to detect a next page.
+        let after = cursor.map(|cursor| (*program_id, *function_name, *cursor));
+        let entries =
+            function_index_map.get_prefix_range(&(program_id, function_name), after.as_ref(), limit.saturating_add(1))?;
+        let transition_ids = entries.into_iter().map(|(key, _)| key.2).collect();
+        Ok(IndexPage::from_ids(transition_ids, limit))
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -272,6 +377,8 @@ pub struct TransitionMemory<N: Network> {
     tcm_map: MemoryMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
+    /// The transition IDs for each program ID and function name.
+    function_index_map: MemoryMap<(ProgramID<N>, Identifier<N>, N::TransitionID), ()>,
 }
 
 #[rustfmt::skip]
@@ -285,6 +392,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
+    type FunctionIndexMap = MemoryMap<(ProgramID<N>, Identifier<N>, N::TransitionID), ()>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -298,6 +406,23 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             reverse_tpk_map: MemoryMap::default(),
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
+            function_index_map: MemoryMap::default(),
+        })
+    }
+
+    /// Returns a read-only view of the transition storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self> {
+        Ok(Self {
+            locator_map: self.locator_map.read_view()?,
+            input_store: self.input_store.read_view()?,
+            output_store: self.output_store.read_view()?,
+            finalize_map: self.finalize_map.read_view()?,
+            proof_map: self.proof_map.read_view()?,
+            tpk_map: self.tpk_map.read_view()?,
+            reverse_tpk_map: self.reverse_tpk_map.read_view()?,
+            tcm_map: self.tcm_map.read_view()?,
+            reverse_tcm_map: self.reverse_tcm_map.read_view()?,
+            function_index_map: self.function_index_map.read_view()?,
         })
     }
 
@@ -345,6 +470,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> Option<&Self::FunctionIndexMap> {
+        Some(&self.function_index_map)
+    }
 }
 
 /// The transition store.
@@ -408,16 +538,53 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         }
     }
 
+    /// Returns a read-only view of the transition store, as of the last finished atomic batch.
+    pub fn read_view(&self) -> Result<Self> {
+        Ok(Self::from(self.storage.read_view()?))
+    }
+
     /// Stores the given `transition` into storage.
     pub fn insert(&self, transition: &Transition<N>) -> Result<()> {
         self.storage.insert(transition)
     }
 
+    /// Stores the given `(serial number, tag)` pair into storage, without the transition that spent the record.
+    /// Note: This is used to bootstrap the storage from a snapshot of the consensus state.
+    pub fn insert_serial_number(&self, serial_number: Field<N>, tag: Field<N>) -> Result<()> {
+        self.storage.input_store().insert_serial_number(serial_number, tag)
+    }
+
+    /// Stores the given record commitment into storage, without the transition that created the record.
+    /// Note: This is used to bootstrap the storage from a snapshot of the consensus state.
+    pub fn insert_commitment(
+        &self,
+        commitment: Field<N>,
+        checksum: Field<N>,
+        optional_record: Option<Record<N, Ciphertext<N>>>,
+    ) -> Result<()> {
+        self.storage.output_store().insert_commitment(commitment, checksum, optional_record)
+    }
+
+    /// Adds the given `transition ID` to the function index, under the given `(program ID, function name)`.
+    /// Note: This is used to build the index for a storage that predates it.
+    pub fn index_transition(
+        &self,
+        transition_id: N::TransitionID,
+        locator: (ProgramID<N>, Identifier<N>),
+    ) -> Result<()> {
+        self.storage.index_transition(transition_id, locator)
+    }
+
     /// Removes the input for the given `transition ID`.
     pub fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the transition for the given `transition ID`, keeping its serial numbers, commitments, `tpk`, and `tcm`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -435,7 +602,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
@@ -518,6 +685,19 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     pub fn get_record(&self, commitment: &Field<N>) -> Result<Option<Record<N, Ciphertext<N>>>> {
         self.outputs.get_record(commitment)
     }
+
+    /// Returns up to `limit` transition IDs for the given `program ID` and `function name`, in transition ID order.
+    /// The page starts after the transition ID `cursor`, or at the first transition ID if `cursor` is `None`.
+    /// To retrieve the next page, pass in the `next_cursor` of the returned page.
+    pub fn get_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        cursor: Option<&N::TransitionID>,
+        limit: usize,
+    ) -> Result<IndexPage<N::TransitionID>> {
+        self.storage.get_transition_ids_for_function(program_id, function_name, cursor, limit)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
@@ -611,6 +791,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.inputs.serial_numbers()
     }
 
+    /// Returns an iterator over the `(serial number, tag)` pairs, for all transition inputs that are records.
+    pub fn serial_numbers_with_tags(&self) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, Field<N>>)> {
+        self.inputs.serial_numbers_with_tags()
+    }
+
     /// Returns an iterator over the external record input IDs, for all transition inputs that are external records.
     pub fn external_input_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.inputs.external_input_ids()
@@ -643,6 +828,14 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.outputs.commitments()
     }
 
+    /// Returns an iterator over the `(commitment, (checksum, (optional) record))` entries, for all record outputs.
+    #[allow(clippy::type_complexity)]
+    pub fn commitments_with_records(
+        &self,
+    ) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, (Field<N>, Option<Record<N, Ciphertext<N>>>)>)> {
+        self.outputs.commitments_with_records()
+    }
+
     /// Returns an iterator over the external record output IDs, for all transition outputs that are external records.
     pub fn external_output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.outputs.external_output_ids()
@@ -809,4 +1002,66 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_function_index() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_fee(rng);
+        let transitions = transaction
+            .transitions()
+            .chain([crate::process::test_helpers::sample_transition()].iter())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+
+        // Returns every transition ID for the given transition's program ID and function name, one page at a time.
+        let get_transition_ids = |transition: &Transition<_>| {
+            let mut transition_ids = Vec::new();
+            let mut cursor = None;
+            loop {
+                let (program_id, function_name) = (transition.program_id(), transition.function_name());
+                let page = transition_store
+                    .get_transition_ids_for_function(program_id, function_name, cursor.as_ref(), 1)
+                    .unwrap();
+                transition_ids.extend_from_slice(page.items());
+                match page.next_cursor() {
+                    Some(next_cursor) => cursor = Some(next_cursor),
+                    None => return transition_ids,
+                }
+            }
+        };
+
+        // Insert every transition.
+        for transition in transitions.iter() {
+            transition_store.insert(transition).unwrap();
+        }
+        // Ensure every transition is indexed by its program ID and function name, exactly once.
+        for transition in transitions.iter() {
+            let locator = (transition.program_id(), transition.function_name());
+            let expected = transitions
+                .iter()
+                .filter(|t| (t.program_id(), t.function_name()) == locator)
+                .map(|t| *t.id())
+                .collect::<Vec<_>>();
+            let candidate = get_transition_ids(transition);
+            assert_eq!(expected.len(), candidate.len());
+            assert!(expected.iter().all(|transition_id| candidate.contains(transition_id)));
+        }
+
+        // Prune the first transition, and ensure it is no longer indexed.
+        transition_store.prune(transitions[0].id()).unwrap();
+        assert!(!get_transition_ids(&transitions[0]).contains(transitions[0].id()));
+
+        // Remove every transition, and ensure the index is empty.
+        for transition in transitions.iter() {
+            transition_store.remove(transition.id()).unwrap();
+        }
+        for transition in transitions.iter() {
+            assert!(get_transition_ids(transition).is_empty());
+        }
+    }
 }
@@ -17,7 +17,11 @@
 use crate::{
     atomic_write_batch,
     block::Output,
-    store::helpers::{memory_map::MemoryMap, Map, MapRead},
+    store::helpers::{
+        memory_map::{finish_atomic_journaled, MemoryMap},
+        Map,
+        MapRead,
+    },
 };
 use console::{
     network::prelude::*,
@@ -50,6 +54,9 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     /// Initializes the transition output storage.
     fn open(dev: Option<u16>) -> Result<Self>;
 
+    /// Returns a read-only view of the transition output storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self>;
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
     /// Returns the reverse ID map.
@@ -151,6 +158,26 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the given record commitment into storage, without the transition that created the record.
+    /// Note: This is used to bootstrap the storage from a snapshot of the consensus state.
+    fn insert_commitment(
+        &self,
+        commitment: Field<N>,
+        checksum: Field<N>,
+        optional_record: Option<Record<N, Ciphertext<N>>>,
+    ) -> Result<()> {
+        atomic_write_batch!(self, {
+            // If the optional record exists, insert the record nonce.
+            if let Some(record) = &optional_record {
+                self.record_nonce_map().insert(*record.nonce(), commitment)?;
+            }
+            // Insert the record entry.
+            self.record_map().insert(commitment, (checksum, optional_record))
+        });
+
+        Ok(())
+    }
+
     /// Removes the output for the given `transition ID`.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         // Retrieve the output IDs.
@@ -190,6 +217,44 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the output for the given `transition ID`, keeping the commitments, checksums, and nonces of its records.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids: Vec<_> = match self.id_map().get(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_write_batch!(self, {
+            // Remove the output IDs.
+            self.id_map().remove(transition_id)?;
+
+            // Remove the outputs, except for the record commitments.
+            for output_id in output_ids {
+                // Remove the reverse output ID.
+                self.reverse_id_map().remove(&output_id)?;
+
+                // If the output is a record, remove the record ciphertext.
+                if let Some(record) = self.record_map().get(&output_id)? {
+                    if record.1.is_some() {
+                        self.record_map().insert(output_id, (record.0, None))?;
+                    }
+                }
+
+                // Remove the output.
+                self.constant_map().remove(&output_id)?;
+                self.public_map().remove(&output_id)?;
+                self.private_map().remove(&output_id)?;
+                self.external_record_map().remove(&output_id)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get(output_id)? {
@@ -308,6 +373,21 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
         })
     }
 
+    /// Returns a read-only view of the transition output storage, as of the last finished atomic batch.
+    fn read_view(&self) -> Result<Self> {
+        Ok(Self {
+            id_map: self.id_map.read_view()?,
+            reverse_id_map: self.reverse_id_map.read_view()?,
+            constant: self.constant.read_view()?,
+            public: self.public.read_view()?,
+            private: self.private.read_view()?,
+            record: self.record.read_view()?,
+            record_nonce: self.record_nonce.read_view()?,
+            external_record: self.external_record.read_view()?,
+            dev: self.dev,
+        })
+    }
+
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap {
         &self.id_map
@@ -403,16 +483,36 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         }
     }
 
+    /// Returns a read-only view of the output store, as of the last finished atomic batch.
+    pub fn read_view(&self) -> Result<Self> {
+        Ok(Self::from(self.storage.read_view()?))
+    }
+
     /// Stores the given `(transition ID, output)` pair into storage.
     pub fn insert(&self, transition_id: N::TransitionID, outputs: &[Output<N>]) -> Result<()> {
         self.storage.insert(transition_id, outputs)
     }
 
+    /// Stores the given record commitment into storage, without the transition that created the record.
+    pub fn insert_commitment(
+        &self,
+        commitment: Field<N>,
+        checksum: Field<N>,
+        optional_record: Option<Record<N, Ciphertext<N>>>,
+    ) -> Result<()> {
+        self.storage.insert_commitment(commitment, checksum, optional_record)
+    }
+
     /// Removes the output for the given `transition ID`.
     pub fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the output for the given `transition ID`, keeping the commitments, checksums, and nonces of its records.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -430,7 +530,7 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        finish_atomic_journaled(|| self.storage.finish_atomic())
     }
 
     /// Returns the optional development ID.
@@ -522,6 +622,14 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.record.keys()
     }
 
+    /// Returns an iterator over the `(commitment, (checksum, (optional) record))` entries, for all record outputs.
+    #[allow(clippy::type_complexity)]
+    pub fn commitments_with_records(
+        &self,
+    ) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, (Field<N>, Option<Record<N, Ciphertext<N>>>)>)> {
+        self.record.iter()
+    }
+
     /// Returns an iterator over the external record output IDs, for all transition outputs that are external records.
     pub fn external_output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.external_record.keys()
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the minimum fee (in microcredits) for the given deployment.
+    /// The cost is the size of the deployment (in bytes) times `N::DEPLOYMENT_FEE_MULTIPLIER`,
+    /// plus the number of constraints of each function times `N::SYNTHESIS_FEE_MULTIPLIER`.
+    /// Note: This method synthesizes the circuit of each function. To verify a deployment transaction,
+    /// the cost is computed from the number of constraints synthesized by `check_deployment` instead.
+    pub fn deployment_cost(&self, deployment: &Deployment<N>) -> Result<u64> {
+        let timer = timer!("VM::deployment_cost");
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                let task = || {
+                    // Prepare the deployment.
+                    let deployment = cast_ref!(&deployment as Deployment<$network>);
+                    // Initialize an RNG.
+                    let rng = &mut rand::thread_rng();
+                    // Synthesize the circuits of the deployment.
+                    let num_constraints = $process.deployment_num_constraints::<$aleo, _>(&deployment, rng)?;
+                    Ok(num_constraints.into_iter().map(|(_, num_constraints)| num_constraints).collect::<Vec<_>>())
+                };
+                task()
+            }};
+        }
+
+        // Synthesize the circuits of the deployment.
+        let num_constraints = process!(self, logic)?;
+        lap!(timer, "Synthesize the circuits");
+
+        // Compute the cost.
+        let cost = Self::to_deployment_cost(deployment, &num_constraints);
+        finish!(timer);
+
+        cost
+    }
+
+    /// Returns the minimum fee (in microcredits) for the given deployment,
+    /// given the number of constraints of each function in the deployment.
+    pub(crate) fn to_deployment_cost(deployment: &Deployment<N>, num_constraints: &[u64]) -> Result<u64> {
+        // Compute the storage cost.
+        let num_bytes = u64::try_from(deployment.to_bytes_le()?.len())?;
+        let storage_cost = match num_bytes.checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER) {
+            Some(storage_cost) => storage_cost,
+            None => bail!("The storage cost of the deployment overflowed"),
+        };
+
+        // Compute the synthesis cost.
+        let mut synthesis_cost = 0u64;
+        for num_constraints in num_constraints {
+            synthesis_cost = match num_constraints
+                .checked_mul(N::SYNTHESIS_FEE_MULTIPLIER)
+                .and_then(|cost| synthesis_cost.checked_add(cost))
+            {
+                Some(synthesis_cost) => synthesis_cost,
+                None => bail!("The synthesis cost of the deployment overflowed"),
+            };
+        }
+
+        match storage_cost.checked_add(synthesis_cost) {
+            Some(cost) => Ok(cost),
+            None => bail!("The cost of the deployment overflowed"),
+        }
+    }
+
+    /// Returns the minimum fee (in microcredits) for the given execution.
+    /// The cost is the size of the execution (in bytes) times `N::EXECUTION_FEE_MULTIPLIER`,
+    /// plus the number of commands in the finalize scope of each transition times `N::FINALIZE_FEE_MULTIPLIER`.
+    /// Note: The finalize cost is an upper bound, as every command in the finalize scope is counted,
+    /// including the commands that are skipped by a branch (e.g. `branch.eq`) when the scope is finalized.
+    pub fn execution_cost(&self, execution: &Execution<N>) -> Result<u64> {
+        // Compute the storage cost.
+        let num_bytes = u64::try_from(execution.to_bytes_le()?.len())?;
+        let storage_cost = match num_bytes.checked_mul(N::EXECUTION_FEE_MULTIPLIER) {
+            Some(storage_cost) => storage_cost,
+            None => bail!("The storage cost of the execution overflowed"),
+        };
+
+        // Count the number of finalize commands.
+        let process = self.process.read();
+        let mut num_commands = 0u64;
+        for transition in execution.transitions() {
+            // Retrieve the function.
+            let function = process.get_stack(transition.program_id())?.get_function(transition.function_name())?;
+            // Add the number of commands in the finalize scope, if it exists.
+            if let Some(finalize) = function.finalize_logic() {
+                num_commands = num_commands.saturating_add(u64::try_from(finalize.commands().len())?);
+            }
+        }
+
+        // Compute the finalize cost.
+        let finalize_cost = match num_commands.checked_mul(N::FINALIZE_FEE_MULTIPLIER) {
+            Some(finalize_cost) => finalize_cost,
+            None => bail!("The finalize cost of the execution overflowed"),
+        };
+
+        match storage_cost.checked_add(finalize_cost) {
+            Some(cost) => Ok(cost),
+            None => bail!("The cost of the execution overflowed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_deployment_cost() {
+        let rng = &mut TestRng::default();
+        let vm = test_helpers::sample_vm();
+
+        // Deploy the program.
+        let program = test_helpers::sample_program();
+        let deployment = vm.deploy(&program, rng).unwrap();
+
+        // Ensure the cost covers the size of the deployment, and is deterministic.
+        let cost = vm.deployment_cost(&deployment).unwrap();
+        let num_bytes = deployment.to_bytes_le().unwrap().len() as u64;
+        assert!(cost > num_bytes * test_helpers::CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER);
+        assert_eq!(cost, vm.deployment_cost(&deployment).unwrap());
+    }
+
+    #[test]
+    fn test_execution_cost() {
+        let rng = &mut TestRng::default();
+        let vm = test_helpers::sample_vm();
+
+        // Fetch an execution transaction, which calls a function without a finalize scope.
+        let transaction = test_helpers::sample_execution_transaction_with_fee(rng);
+        let execution = match transaction {
+            Transaction::Execute(_, execution, _) => execution,
+            _ => panic!("Expected an execution transaction"),
+        };
+
+        // Ensure the cost is the size of the execution.
+        let num_bytes = execution.to_bytes_le().unwrap().len() as u64;
+        let expected = num_bytes * test_helpers::CurrentNetwork::EXECUTION_FEE_MULTIPLIER;
+        assert_eq!(expected, vm.execution_cost(&execution).unwrap());
+    }
+}
@@ -17,34 +17,144 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Finalizes the given transactions into the VM.
+    /// Finalizes the given transactions into the VM, given the global state of the block being finalized,
+    /// and returns the status of each transaction.
+    /// A transaction that fails to finalize is aborted: its changes to the program state are reverted,
+    /// while the changes of the other transactions are kept.
     /// This method assumes the given transactions **are valid**.
     #[inline]
-    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<()> {
+    pub fn finalize(
+        &self,
+        state: FinalizeGlobalState<N>,
+        transactions: &Transactions<N>,
+    ) -> Result<Vec<TransactionStatus>> {
         let timer = timer!("VM::finalize");
-        atomic_write_batch!(self, {
-            // Acquire the write lock on the process.
-            let mut process = self.process.write();
-
-            for transaction in transactions.values() {
-                // Finalize the transaction.
-                match transaction {
-                    Transaction::Deploy(_, _, deployment, _) => {
-                        process.finalize_deployment(self.program_store(), deployment)?;
-                        lap!(timer, "Finalize deployment");
-                    }
-                    Transaction::Execute(_, execution, _) => {
-                        process.finalize_execution(self.program_store(), execution)?;
-                        lap!(timer, "Finalize execution");
-                    }
-                }
-            }
-            Ok(())
+
+        // Acquire the batch lock, and the write lock on the process.
+        let _atomic_lock = self.atomic_lock.lock();
+        let mut process = self.process.write();
+        let (next_process, statuses) = atomic_write_batch!(self, {
+            // Finalize the transactions on a copy of the process, so that the process is only updated on success.
+            let mut next_process = process.clone();
+            let statuses = self.finalize_transactions(&mut next_process, state, transactions)?;
+            Ok((next_process, statuses))
         });
+        // Update the process.
+        *process = next_process;
 
         finish!(timer);
 
-        Ok(())
+        Ok(statuses)
+    }
+
+    /// Returns the finalize root for the given transactions, given the global state of the block being finalized.
+    /// The finalize root commits to the checksum of the program state and to the ledger digest
+    /// once the transactions are finalized, and to the status of each transaction.
+    /// Note: The transactions are finalized speculatively, so the VM is left unchanged.
+    pub fn compute_finalize_root(
+        &self,
+        state: FinalizeGlobalState<N>,
+        transactions: &Transactions<N>,
+    ) -> Result<Field<N>> {
+        // Finalize the transactions in a speculative batch, and compute the finalize root.
+        self.speculative_batch(|process| {
+            let statuses = self.finalize_transactions(process, state, transactions)?;
+            self.to_next_finalize_root(transactions, &statuses)
+        })
+    }
+
+    /// Finalizes the given transactions with the given process, given the global state of the block being finalized,
+    /// and returns the status of each transaction.
+    /// Note: This method must be called within an atomic batch.
+    pub(super) fn finalize_transactions(
+        &self,
+        process: &mut Process<N>,
+        state: FinalizeGlobalState<N>,
+        transactions: &Transactions<N>,
+    ) -> Result<Vec<TransactionStatus>> {
+        // Record the mapping changes of this block, so that they can be queried or reverted later.
+        self.program_store().start_change_set(state.block_height())?;
+
+        // Finalize each transaction.
+        let statuses = transactions
+            .values()
+            .map(|transaction| self.finalize_transaction(process, state, transaction))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Store the recorded mapping changes.
+        self.program_store().finish_change_set()?;
+        Ok(statuses)
+    }
+
+    /// Finalizes the given transaction with the given process, given the global state of the block being finalized,
+    /// and returns the status of the transaction. If the transaction fails to finalize, its changes are reverted.
+    /// Note: This method must be called within an atomic batch, while the mapping changes are being recorded.
+    pub(super) fn finalize_transaction(
+        &self,
+        process: &mut Process<N>,
+        state: FinalizeGlobalState<N>,
+        transaction: &Transaction<N>,
+    ) -> Result<TransactionStatus> {
+        // Retrieve the number of mapping changes, before the transaction is finalized.
+        let num_changes = match self.program_store().num_recorded_changes() {
+            Some(num_changes) => num_changes,
+            None => bail!("The mapping changes of block {} are not being recorded", state.block_height()),
+        };
+        // Finalize the transaction.
+        match self.execute_finalize(process, state, transaction) {
+            Ok(()) => Ok(TransactionStatus::Accepted),
+            Err(error) => {
+                // Ensure the atomic batch is still in progress, as a failed write to storage aborts the batch.
+                ensure!(
+                    self.program_store().is_atomic_in_progress(),
+                    "Failed to finalize transaction '{}': {error}",
+                    transaction.id()
+                );
+                // Revert the mapping changes of the transaction, and abort the transaction.
+                self.program_store().rewind_change_set(num_changes)?;
+                Ok(TransactionStatus::Aborted)
+            }
+        }
+    }
+
+    /// Executes the finalize logic of the given transaction with the given process,
+    /// given the global state of the block being finalized. On failure, the changes are **not** reverted.
+    pub(super) fn execute_finalize(
+        &self,
+        process: &mut Process<N>,
+        state: FinalizeGlobalState<N>,
+        transaction: &Transaction<N>,
+    ) -> Result<()> {
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => process.finalize_deployment(self.program_store(), deployment),
+            Transaction::Execute(_, execution, _) => process.finalize_execution(state, self.program_store(), execution),
+        }
+    }
+
+    /// Returns the finalize root of the next block, once the given transactions are finalized with the given statuses.
+    /// Note: This method must be called after the transactions are finalized, within the same atomic batch.
+    pub(super) fn to_next_finalize_root(
+        &self,
+        transactions: &Transactions<N>,
+        statuses: &[TransactionStatus],
+    ) -> Result<Field<N>> {
+        // Compute the ledger digest, once the transactions are added to the ledger.
+        let ledger_digest = self.block_store().get_ledger_digest()? + to_ledger_digest(transactions, statuses)?;
+        // Compute the finalize root.
+        Self::to_finalize_root(self.program_store().get_checksum()?, ledger_digest, statuses)
+    }
+
+    /// Returns the finalize root, as `Hash(checksum || ledger digest || statuses)`,
+    /// where each aborted transaction is a set bit.
+    pub(super) fn to_finalize_root(
+        checksum: Field<N>,
+        ledger_digest: Group<N>,
+        statuses: &[TransactionStatus],
+    ) -> Result<Field<N>> {
+        let mut preimage = checksum.to_bits_le();
+        preimage.extend(ledger_digest.to_bits_le());
+        preimage.extend(statuses.iter().map(|status| status.is_aborted()));
+        N::hash_bhp1024(&preimage)
     }
 }
 
@@ -63,9 +173,16 @@ mod tests {
         let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
 
         // Finalize the transaction.
-        vm.finalize(&Transactions::from(&[deployment_transaction.clone()])).unwrap();
+        let statuses = vm
+            .finalize(FinalizeGlobalState::new_genesis(), &Transactions::from(&[deployment_transaction.clone()]))
+            .unwrap();
+        assert_eq!(vec![TransactionStatus::Accepted], statuses);
+        let checksum = vm.program_store().get_checksum().unwrap();
 
-        // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(&Transactions::from(&[deployment_transaction])).is_err());
+        // Ensure the VM can't redeploy the same transaction, and that the redeployment is aborted.
+        let state = FinalizeGlobalState::new(1, Default::default());
+        let statuses = vm.finalize(state, &Transactions::from(&[deployment_transaction])).unwrap();
+        assert_eq!(vec![TransactionStatus::Aborted], statuses);
+        assert_eq!(checksum, vm.program_store().get_checksum().unwrap());
     }
 }
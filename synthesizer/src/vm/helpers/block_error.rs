@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::{network::prelude::*, types::Field};
+
+/// The reason a block was rejected by `VM::check_next_block`.
+/// Note: This error can be detected with `error.downcast_ref::<BlockError<N>>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockError<N: Network> {
+    /// The block is for a different network.
+    InvalidNetwork { expected: u16, found: u16 },
+    /// The ledger is empty, and the block is not a valid genesis block.
+    InvalidGenesis,
+    /// The block does not build on the latest block.
+    InvalidPreviousHash { expected: N::BlockHash, found: N::BlockHash },
+    /// The block height does not follow the latest block height.
+    InvalidHeight { expected: u32, found: u32 },
+    /// The block round is not after the latest block round.
+    InvalidRound { previous: u64, found: u64 },
+    /// The block timestamp is not after the latest block timestamp.
+    InvalidTimestamp { previous: i64, found: i64 },
+    /// The block timestamp is too far ahead of the local clock.
+    FutureTimestamp { max: i64, found: i64 },
+    /// The block hash does not correspond to the previous hash and the header.
+    InvalidBlockHash { expected: N::BlockHash, found: N::BlockHash },
+    /// The block signature is invalid for the block hash.
+    InvalidSignature,
+    /// The previous state root in the header is not the latest state root.
+    InvalidPreviousStateRoot { expected: Field<N>, found: Field<N> },
+    /// The transactions root in the header does not correspond to the transactions.
+    InvalidTransactionsRoot { expected: Field<N>, found: Field<N> },
+    /// The finalize root in the header does not correspond to finalizing the transactions.
+    InvalidFinalizeRoot { expected: Field<N>, found: Field<N> },
+    /// The coinbase accumulator point in the header does not correspond to the coinbase solution.
+    InvalidCoinbaseAccumulatorPoint { expected: Field<N>, found: Field<N> },
+    /// The coinbase solution is invalid.
+    InvalidCoinbaseSolution { reason: String },
+    /// The transaction is already in the ledger.
+    DuplicateTransaction { transaction_id: N::TransactionID },
+    /// The transaction is invalid.
+    InvalidTransaction { transaction_id: N::TransactionID, reason: String },
+    /// The serial number is spent more than once in the block.
+    DuplicateSerialNumber { serial_number: Field<N> },
+    /// The serial number is already spent in the ledger.
+    SpentSerialNumber { serial_number: Field<N> },
+}
+
+impl<N: Network> Display for BlockError<N> {
+    /// Displays the block error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidNetwork { expected, found } => {
+                write!(f, "The block is for network {found}, instead of network {expected}")
+            }
+            Self::InvalidGenesis => write!(f, "The ledger is empty, and the block is not a valid genesis block"),
+            Self::InvalidPreviousHash { expected, found } => {
+                write!(f, "The previous block hash is '{found}', instead of '{expected}'")
+            }
+            Self::InvalidHeight { expected, found } => {
+                write!(f, "The block height is {found}, instead of {expected}")
+            }
+            Self::InvalidRound { previous, found } => {
+                write!(f, "The block round {found} is not after the previous round {previous}")
+            }
+            Self::InvalidTimestamp { previous, found } => {
+                write!(f, "The block timestamp {found} is not after the previous timestamp {previous}")
+            }
+            Self::FutureTimestamp { max, found } => {
+                write!(f, "The block timestamp {found} is after the maximum timestamp {max}, given the local clock")
+            }
+            Self::InvalidBlockHash { expected, found } => {
+                write!(f, "The block hash is '{found}', instead of '{expected}'")
+            }
+            Self::InvalidSignature => write!(f, "The block signature is invalid"),
+            Self::InvalidPreviousStateRoot { expected, found } => {
+                write!(f, "The previous state root is '{found}', instead of '{expected}'")
+            }
+            Self::InvalidTransactionsRoot { expected, found } => {
+                write!(f, "The transactions root is '{found}', instead of '{expected}'")
+            }
+            Self::InvalidFinalizeRoot { expected, found } => {
+                write!(f, "The finalize root is '{found}', instead of '{expected}'")
+            }
+            Self::InvalidCoinbaseAccumulatorPoint { expected, found } => {
+                write!(f, "The coinbase accumulator point is '{found}', instead of '{expected}'")
+            }
+            Self::InvalidCoinbaseSolution { reason } => write!(f, "The coinbase solution is invalid: {reason}"),
+            Self::DuplicateTransaction { transaction_id } => {
+                write!(f, "Transaction '{transaction_id}' is already in the ledger")
+            }
+            Self::InvalidTransaction { transaction_id, reason } => {
+                write!(f, "Transaction '{transaction_id}' is invalid: {reason}")
+            }
+            Self::DuplicateSerialNumber { serial_number } => {
+                write!(f, "Serial number '{serial_number}' is spent more than once in the block")
+            }
+            Self::SpentSerialNumber { serial_number } => {
+                write!(f, "Serial number '{serial_number}' is already spent in the ledger")
+            }
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for BlockError<N> {}
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+/// The `(program ID, function name, inputs)` of a finalize scope that is called by a transaction.
+pub type FinalizeInputs<N> = (ProgramID<N>, Identifier<N>, Vec<Value<N>>);
+/// The `(program ID, mapping name, key, old value, new value)` of a write to a mapping,
+/// where the old value is `None` if the key is inserted, and the new value is `None` if the key is removed.
+pub type MappingWrite<N> = (ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>, Option<Value<N>>);
+
+/// The outcome of speculatively finalizing a transaction with `VM::speculate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Speculation<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The inputs of each finalize scope called by the transaction, in order.
+    finalize_inputs: Vec<FinalizeInputs<N>>,
+    /// The writes to the mappings, in order.
+    writes: Vec<MappingWrite<N>>,
+    /// The error, if the transaction failed to finalize.
+    error: Option<String>,
+}
+
+impl<N: Network> Speculation<N> {
+    /// Initializes a new speculation with the given outcome.
+    pub const fn new(
+        transaction_id: N::TransactionID,
+        finalize_inputs: Vec<FinalizeInputs<N>>,
+        writes: Vec<MappingWrite<N>>,
+        error: Option<String>,
+    ) -> Self {
+        Self { transaction_id, finalize_inputs, writes, error }
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the inputs of each finalize scope called by the transaction.
+    pub fn finalize_inputs(&self) -> &[FinalizeInputs<N>] {
+        &self.finalize_inputs
+    }
+
+    /// Returns the writes to the mappings.
+    /// Note: If the transaction failed to finalize, these are the writes before the failure, which are reverted.
+    pub fn writes(&self) -> &[MappingWrite<N>] {
+        &self.writes
+    }
+
+    /// Returns the error, if the transaction failed to finalize.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns `true` if the transaction is finalized successfully.
+    pub const fn is_accepted(&self) -> bool {
+        self.error.is_none()
+    }
+}
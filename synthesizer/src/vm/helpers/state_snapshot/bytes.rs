@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for StateSnapshot<N> {
+    /// Reads the snapshot from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid snapshot version"));
+        }
+
+        // Read the block.
+        let block = FromBytes::read_le(&mut reader)?;
+
+        // Read the transaction statuses.
+        let num_statuses = u32::read_le(&mut reader)?;
+        let statuses = (0..num_statuses)
+            .map(|_| match u8::read_le(&mut reader)? {
+                0 => Ok(TransactionStatus::Accepted),
+                1 => Ok(TransactionStatus::Aborted),
+                _ => Err(error("Invalid transaction status")),
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+
+        // Read the block hashes.
+        let num_block_hashes = u32::read_le(&mut reader)?;
+        let block_hashes =
+            (0..num_block_hashes).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the deployments.
+        let num_deployments = u32::read_le(&mut reader)?;
+        let deployments =
+            (0..num_deployments).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the mappings.
+        let num_mappings = u32::read_le(&mut reader)?;
+        let mut mappings = Vec::with_capacity(num_mappings as usize);
+        for _ in 0..num_mappings {
+            // Read the program ID and mapping name.
+            let program_id = FromBytes::read_le(&mut reader)?;
+            let mapping_name = FromBytes::read_le(&mut reader)?;
+            // Read the key-value pairs.
+            let num_entries = u32::read_le(&mut reader)?;
+            let entries = (0..num_entries)
+                .map(|_| Ok((FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?)))
+                .collect::<IoResult<Vec<_>>>()?;
+            mappings.push((program_id, mapping_name, entries));
+        }
+
+        // Read the serial numbers.
+        let num_serial_numbers = u32::read_le(&mut reader)?;
+        let serial_numbers = (0..num_serial_numbers)
+            .map(|_| Ok((FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+
+        // Read the commitments.
+        let num_commitments = u32::read_le(&mut reader)?;
+        let mut commitments = Vec::with_capacity(num_commitments as usize);
+        for _ in 0..num_commitments {
+            // Read the commitment and checksum.
+            let commitment = FromBytes::read_le(&mut reader)?;
+            let checksum = FromBytes::read_le(&mut reader)?;
+            // Read the record.
+            let record = match u8::read_le(&mut reader)? {
+                0 => None,
+                1 => Some(FromBytes::read_le(&mut reader)?),
+                _ => return Err(error("Invalid record variant")),
+            };
+            commitments.push((commitment, checksum, record));
+        }
+
+        // Read the checksum.
+        let checksum = FromBytes::read_le(&mut reader)?;
+
+        // Construct the snapshot.
+        Self::from(block, statuses, block_hashes, deployments, mappings, serial_numbers, commitments, checksum)
+            .map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for StateSnapshot<N> {
+    /// Writes the snapshot to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the contents.
+        self.write_contents_le(&mut writer)?;
+        // Write the checksum.
+        self.checksum.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> StateSnapshot<N> {
+    /// Writes the contents of the snapshot, excluding the version and checksum, to the buffer.
+    pub(super) fn write_contents_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the block.
+        self.block.write_le(&mut writer)?;
+
+        // Write the transaction statuses.
+        (u32::try_from(self.statuses.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        for status in &self.statuses {
+            match status {
+                TransactionStatus::Accepted => 0u8.write_le(&mut writer)?,
+                TransactionStatus::Aborted => 1u8.write_le(&mut writer)?,
+            }
+        }
+
+        // Write the block hashes.
+        (u32::try_from(self.block_hashes.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        self.block_hashes.iter().try_for_each(|block_hash| block_hash.write_le(&mut writer))?;
+
+        // Write the deployments.
+        (u32::try_from(self.deployments.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        self.deployments.iter().try_for_each(|transaction| transaction.write_le(&mut writer))?;
+
+        // Write the mappings.
+        (u32::try_from(self.mappings.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        for (program_id, mapping_name, entries) in &self.mappings {
+            // Write the program ID and mapping name.
+            program_id.write_le(&mut writer)?;
+            mapping_name.write_le(&mut writer)?;
+            // Write the key-value pairs.
+            (u32::try_from(entries.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+            for (key, value) in entries {
+                key.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+
+        // Write the serial numbers.
+        (u32::try_from(self.serial_numbers.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        for (serial_number, tag) in &self.serial_numbers {
+            serial_number.write_le(&mut writer)?;
+            tag.write_le(&mut writer)?;
+        }
+
+        // Write the commitments.
+        (u32::try_from(self.commitments.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        for (commitment, checksum, record) in &self.commitments {
+            // Write the commitment and checksum.
+            commitment.write_le(&mut writer)?;
+            checksum.write_le(&mut writer)?;
+            // Write the record.
+            match record {
+                None => 0u8.write_le(&mut writer)?,
+                Some(record) => {
+                    1u8.write_le(&mut writer)?;
+                    record.write_le(&mut writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
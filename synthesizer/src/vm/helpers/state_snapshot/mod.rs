@@ -0,0 +1,262 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+
+use crate::{
+    block::{Block, Transaction},
+    store::{to_commitment_digest, to_deployment_digest, to_serial_number_digest, TransactionStatus},
+};
+use console::{
+    network::prelude::*,
+    program::{BlockTree, Ciphertext, Identifier, Plaintext, ProgramID, Record, Value},
+    types::{Field, Group},
+};
+
+/// The `(program ID, mapping name, key-value pairs)` of a mapping, with the key-value pairs in mapping order.
+pub type SnapshotMapping<N> = (ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>);
+/// The `(commitment, checksum, (optional) record ciphertext)` of a record output.
+pub type SnapshotRecord<N> = (Field<N>, Field<N>, Option<Record<N, Ciphertext<N>>>);
+
+/// A snapshot of the consensus state at a given block, which allows a node to bootstrap without replaying every block.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StateSnapshot<N: Network> {
+    /// The block at the height of the snapshot.
+    block: Block<N>,
+    /// The status of each transaction in the block.
+    statuses: Vec<TransactionStatus>,
+    /// The block hashes, from the genesis block up to and including the block of the snapshot.
+    block_hashes: Vec<N::BlockHash>,
+    /// The deployment transactions, which contain the deployed programs and their verifying keys.
+    deployments: Vec<Transaction<N>>,
+    /// The program state, as the key-value pairs of every mapping.
+    mappings: Vec<SnapshotMapping<N>>,
+    /// The `(serial number, tag)` pairs of the spent records.
+    serial_numbers: Vec<(Field<N>, Field<N>)>,
+    /// The commitments of the created records.
+    commitments: Vec<SnapshotRecord<N>>,
+    /// The checksum of the snapshot, which is bound to the previous state root in the block header.
+    checksum: Field<N>,
+}
+
+impl<N: Network> StateSnapshot<N> {
+    /// Initializes a new snapshot with the given contents, and computes its checksum.
+    pub fn new(
+        block: Block<N>,
+        statuses: Vec<TransactionStatus>,
+        block_hashes: Vec<N::BlockHash>,
+        deployments: Vec<Transaction<N>>,
+        mappings: Vec<SnapshotMapping<N>>,
+        serial_numbers: Vec<(Field<N>, Field<N>)>,
+        commitments: Vec<SnapshotRecord<N>>,
+    ) -> Result<Self> {
+        // Construct the snapshot, without its checksum.
+        let mut snapshot = Self {
+            block,
+            statuses,
+            block_hashes,
+            deployments,
+            mappings,
+            serial_numbers,
+            commitments,
+            checksum: Field::zero(),
+        };
+        // Compute the checksum.
+        snapshot.checksum = snapshot.compute_checksum()?;
+        Ok(snapshot)
+    }
+
+    /// Initializes a snapshot with the given contents and checksum.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from(
+        block: Block<N>,
+        statuses: Vec<TransactionStatus>,
+        block_hashes: Vec<N::BlockHash>,
+        deployments: Vec<Transaction<N>>,
+        mappings: Vec<SnapshotMapping<N>>,
+        serial_numbers: Vec<(Field<N>, Field<N>)>,
+        commitments: Vec<SnapshotRecord<N>>,
+        checksum: Field<N>,
+    ) -> Result<Self> {
+        // Construct the snapshot.
+        let snapshot =
+            Self { block, statuses, block_hashes, deployments, mappings, serial_numbers, commitments, checksum };
+        // Ensure the checksum matches the contents.
+        ensure!(
+            snapshot.compute_checksum()? == checksum,
+            "Mismatching checksum for the snapshot at block {}, possible data corruption",
+            snapshot.height()
+        );
+        Ok(snapshot)
+    }
+
+    /// Ensures the block hashes of the snapshot correspond to the previous state root in the block header,
+    /// that the transactions of the block correspond to the transactions root in the block header,
+    /// and that the deployments and records of the snapshot are well-formed.
+    /// Note: The block header is only trusted once the block hash is checked against a trusted block hash.
+    /// Note: The program state and the ledger digest of the snapshot are verified against the finalize root
+    /// in the block header once the program state is stored, in `VM::from_snapshot`.
+    pub fn verify(&self) -> Result<()> {
+        // Retrieve the block height.
+        let height = self.height() as usize;
+
+        // Ensure the snapshot is not at the genesis block, as the finalize root of a genesis block is zero.
+        ensure!(height > 0, "Cannot verify a snapshot at the genesis block, as the genesis block has no finalize root");
+
+        // Ensure the previous block hash matches the block.
+        ensure!(
+            self.block_hashes[height - 1] == self.block.previous_hash(),
+            "The previous block hash in the snapshot does not match block {height}"
+        );
+        // Compute the state root, using the block hashes preceding the block.
+        let leaves = self.block_hashes[..height].iter().map(|hash| hash.to_bits_le()).collect::<Vec<_>>();
+        let block_tree: BlockTree<N> = N::merkle_tree_bhp(&leaves)?;
+        // Ensure the state root matches the block header.
+        ensure!(
+            *block_tree.root() == self.block.previous_state_root(),
+            "The block hashes in the snapshot do not match the state root in block {height}"
+        );
+        // Ensure the transactions of the block match the transactions root in the block header.
+        ensure!(
+            self.block.transactions().to_root()? == self.block.transactions_root(),
+            "The transactions in the snapshot do not match the transactions root in block {height}"
+        );
+
+        // Ensure each deployment matches its transaction ID.
+        for transaction in &self.deployments {
+            if let Transaction::Deploy(transaction_id, owner, deployment, fee) = transaction {
+                let candidate = Transaction::from_deployment(*owner, *deployment.clone(), fee.clone())?;
+                ensure!(candidate.id() == *transaction_id, "Mismatching ID for deployment '{transaction_id}'");
+            }
+        }
+        // Ensure each record matches the checksum of its commitment.
+        for (commitment, checksum, record) in &self.commitments {
+            if let Some(record) = record {
+                ensure!(
+                    N::hash_bhp1024(&record.to_bits_le())? == *checksum,
+                    "Mismatching checksum for the record of commitment '{commitment}'"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the ledger digest of the snapshot, over its serial numbers, commitments, and deployments.
+    pub fn ledger_digest(&self) -> Result<Group<N>> {
+        let mut ledger_digest = Group::zero();
+        // Add the spent records.
+        for (serial_number, tag) in &self.serial_numbers {
+            ledger_digest += to_serial_number_digest(serial_number, tag)?;
+        }
+        // Add the created records.
+        for (commitment, checksum, _) in &self.commitments {
+            ledger_digest += to_commitment_digest(commitment, checksum)?;
+        }
+        // Add the deployments.
+        for transaction in &self.deployments {
+            ledger_digest += to_deployment_digest::<N>(&transaction.id())?;
+        }
+        Ok(ledger_digest)
+    }
+}
+
+impl<N: Network> StateSnapshot<N> {
+    /// Returns the block height of the snapshot.
+    pub const fn height(&self) -> u32 {
+        self.block.height()
+    }
+
+    /// Returns the block at the height of the snapshot.
+    pub const fn block(&self) -> &Block<N> {
+        &self.block
+    }
+
+    /// Returns the status of each transaction in the block.
+    pub fn statuses(&self) -> &[TransactionStatus] {
+        &self.statuses
+    }
+
+    /// Returns the block hashes, from the genesis block up to and including the block of the snapshot.
+    pub fn block_hashes(&self) -> &[N::BlockHash] {
+        &self.block_hashes
+    }
+
+    /// Returns the deployment transactions.
+    pub fn deployments(&self) -> &[Transaction<N>] {
+        &self.deployments
+    }
+
+    /// Returns the key-value pairs of every mapping.
+    pub fn mappings(&self) -> &[SnapshotMapping<N>] {
+        &self.mappings
+    }
+
+    /// Returns the `(serial number, tag)` pairs of the spent records.
+    pub fn serial_numbers(&self) -> &[(Field<N>, Field<N>)] {
+        &self.serial_numbers
+    }
+
+    /// Returns the commitments of the created records.
+    pub fn commitments(&self) -> &[SnapshotRecord<N>] {
+        &self.commitments
+    }
+
+    /// Returns the checksum of the snapshot.
+    pub const fn checksum(&self) -> Field<N> {
+        self.checksum
+    }
+}
+
+impl<N: Network> StateSnapshot<N> {
+    /// Returns the checksum of the snapshot, as `Hash(previous state root || contents)`.
+    fn compute_checksum(&self) -> Result<Field<N>> {
+        // Ensure the block hashes end with the block of the snapshot.
+        ensure!(
+            self.block_hashes.len() == self.height() as usize + 1,
+            "The snapshot must contain {} block hashes, found {}",
+            self.height() as usize + 1,
+            self.block_hashes.len()
+        );
+        ensure!(
+            self.block_hashes.last() == Some(&self.block.hash()),
+            "The last block hash in the snapshot does not match block {}",
+            self.height()
+        );
+        // Ensure there is a status for each transaction in the block.
+        ensure!(
+            self.statuses.len() == self.block.transactions().len(),
+            "The snapshot must contain {} transaction statuses, found {}",
+            self.block.transactions().len(),
+            self.statuses.len()
+        );
+        // Ensure the deployments are deployment transactions.
+        for transaction in &self.deployments {
+            ensure!(
+                matches!(transaction, Transaction::Deploy(..)),
+                "Transaction '{}' in the snapshot is not a deployment",
+                transaction.id()
+            );
+        }
+
+        // Serialize the contents of the snapshot.
+        let mut contents = Vec::new();
+        self.write_contents_le(&mut contents)?;
+        // Compute the checksum, which is bound to the previous state root in the block header.
+        let mut preimage = self.block.previous_state_root().to_bits_le();
+        preimage.extend(contents.iter().flat_map(|byte| byte.to_bits_le()));
+        N::hash_bhp1024(&preimage)
+    }
+}
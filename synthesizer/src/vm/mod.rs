@@ -15,33 +15,61 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod helpers;
+pub use helpers::*;
 
 mod authorize;
+mod cost;
 mod deploy;
 mod execute;
 mod finalize;
+mod propose;
+mod snapshot;
+mod speculate;
 mod verify;
 
 use crate::{
     atomic_write_batch,
     block::{Block, Transaction, Transactions, Transition},
     cast_ref,
+    coinbase_puzzle::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, PuzzleConfig},
+    cow_to_cloned,
+    cow_to_copied,
     process,
-    process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process, Query},
+    process::{
+        Authorization,
+        Deployment,
+        Execution,
+        Fee,
+        FinalizeGlobalState,
+        Inclusion,
+        InclusionAssignment,
+        Process,
+        Query,
+    },
     program::Program,
-    store::{BlockStore, ConsensusStorage, ConsensusStore, ProgramStore, TransactionStore, TransitionStore},
+    store::{
+        to_ledger_digest,
+        BlockStore,
+        ConsensusStorage,
+        ConsensusStore,
+        ProgramStore,
+        TransactionStatus,
+        TransactionStore,
+        TransitionStore,
+    },
     CallMetrics,
 };
 use console::{
     account::PrivateKey,
     network::prelude::*,
     program::{Identifier, Plaintext, ProgramID, Record, Response, Value},
-    types::Field,
+    types::{Field, Group},
 };
 
 use aleo_std::prelude::{finish, lap, timer};
-use parking_lot::RwLock;
-use std::sync::Arc;
+use once_cell::sync::OnceCell;
+use parking_lot::{Mutex, RwLock};
+use std::{collections::HashSet, sync::Arc};
 
 #[derive(Clone)]
 pub struct VM<N: Network, C: ConsensusStorage<N>> {
@@ -49,6 +77,11 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The coinbase puzzle, which is initialized on first use.
+    coinbase_puzzle: Arc<OnceCell<CoinbasePuzzle<N>>>,
+    /// The lock that serializes the atomic batches of the VM, so that a speculative batch,
+    /// which is aborted, does not interleave with a batch that is committed.
+    atomic_lock: Arc<Mutex<()>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -71,7 +104,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
 
         // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(Self {
+            process: Arc::new(RwLock::new(process)),
+            store,
+            coinbase_puzzle: Default::default(),
+            atomic_lock: Default::default(),
+        })
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -83,18 +121,90 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Adds the given block into the VM.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
-        // First, insert the block.
-        self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(block.transactions()) {
-            Ok(_) => Ok(()),
-            Err(error) => {
-                // Rollback the block.
-                self.block_store().remove_last_n(1)?;
-                // Return the error.
-                Err(error)
+        // Acquire the batch lock.
+        let _atomic_lock = self.atomic_lock.lock();
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+        let next_process = atomic_write_batch!(self, {
+            // Finalize the transactions on a copy of the process, so that the process is only updated on success.
+            let mut next_process = process.clone();
+            let state = FinalizeGlobalState::from(block);
+            let statuses = self.finalize_transactions(&mut next_process, state, block.transactions())?;
+            // Insert the block, along with the status of each transaction.
+            self.block_store().insert_with_statuses(block, &statuses)?;
+            Ok(next_process)
+        });
+        // Update the process.
+        *process = next_process;
+        Ok(())
+    }
+
+    /// Removes the last 'n' blocks from the VM, reverting the program state they finalized,
+    /// and removing the programs they deployed from the process.
+    #[inline]
+    pub fn remove_last_n(&self, n: u32) -> Result<()> {
+        // Ensure 'n' is non-zero.
+        ensure!(n > 0, "Cannot remove zero blocks");
+
+        // Acquire the batch lock.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Determine the block heights to remove.
+        let heights = match self.block_store().heights().max() {
+            Some(height) => {
+                // Determine the end block height to remove.
+                let end_height = cow_to_copied!(height);
+                // Determine the start block height to remove.
+                let start_height = end_height
+                    .checked_sub(n - 1)
+                    .ok_or_else(|| anyhow!("Failed to remove last '{n}' blocks: block height underflow"))?;
+                // Output the block heights.
+                start_height..=end_height
+            }
+            None => bail!("Failed to remove last '{n}' blocks: no blocks in storage"),
+        };
+        // Ensure the change sets of the blocks are not pruned.
+        if self.program_store().is_change_set_pruned(*heights.start())? {
+            bail!("Failed to remove last '{n}' blocks: the change sets of the blocks are pruned")
+        }
+
+        // Determine the program IDs deployed in the blocks, in order of deployment.
+        let mut program_ids = Vec::new();
+        for height in heights.clone() {
+            // Retrieve the block hash.
+            let block_hash = match self.block_store().get_block_hash(height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Failed to remove last '{n}' blocks: missing block hash for block {height}"),
+            };
+            // Retrieve the block transactions.
+            let transactions = match self.block_store().get_block_transactions(&block_hash)? {
+                Some(transactions) => transactions,
+                None => bail!("Failed to remove last '{n}' blocks: missing transactions for block {height}"),
+            };
+            program_ids.extend(transactions.deployments().map(|deployment| *deployment.program_id()));
+        }
+
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+        atomic_write_batch!(self, {
+            // Revert the mapping changes, starting from the latest block.
+            for height in heights.rev() {
+                if self.program_store().get_change_set(height)?.is_some() {
+                    self.program_store().revert_change_set(height)?;
+                }
+            }
+            // Remove the blocks.
+            self.block_store().remove_last_n(n)
+        });
+
+        // Remove the deployed programs from the process, starting from the latest deployment.
+        // Note: A program is kept if it is still in storage, as its deployment in the blocks was rejected.
+        for program_id in program_ids.iter().rev() {
+            if process.contains_program(program_id) && self.transaction_store().get_program(program_id)?.is_none() {
+                process.remove_program(program_id)?;
             }
         }
+        Ok(())
     }
 
     /// Returns the process.
@@ -103,6 +213,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.process.clone()
     }
 
+    /// Returns the coinbase puzzle, which is trimmed from the universal SRS of the process on first use.
+    pub fn coinbase_puzzle(&self) -> Result<&CoinbasePuzzle<N>> {
+        self.coinbase_puzzle.get_or_try_init(|| {
+            // Retrieve the universal SRS.
+            let universal_srs = self.process.read().universal_srs().clone();
+            // Trim the universal SRS to the coinbase puzzle degree.
+            CoinbasePuzzle::trim(&universal_srs, PuzzleConfig { degree: N::COINBASE_PUZZLE_DEGREE })
+        })
+    }
+
     /// Returns the program store.
     #[inline]
     pub fn program_store(&self) -> &ProgramStore<N, C::ProgramStorage> {
@@ -146,6 +266,27 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn finish_atomic(&self) -> Result<()> {
         self.store.finish_atomic()
     }
+
+    /// Runs the given closure on a copy of the process, in an atomic batch that is aborted once the closure returns,
+    /// so that the VM is left unchanged.
+    /// Note: The batch lock is held throughout, so that a block that is being added does not join the batch.
+    fn speculative_batch<T>(&self, f: impl FnOnce(&mut Process<N>) -> Result<T>) -> Result<T> {
+        // Acquire the batch lock.
+        let _atomic_lock = self.atomic_lock.lock();
+        // Ensure an atomic batch is not in progress, as the batch is aborted below.
+        ensure!(!self.is_atomic_in_progress(), "Cannot start a speculative batch during an atomic batch");
+
+        // Clone the process, so that any deployed programs are not added to the VM.
+        let mut process = self.process.read().clone();
+
+        // Run the closure in an atomic batch.
+        self.start_atomic();
+        let result = f(&mut process);
+        // Abort the atomic batch, to discard the program state changes.
+        self.abort_atomic();
+
+        result
+    }
 }
 
 #[cfg(test)]
@@ -261,7 +402,7 @@ function compute:
 
                 // Prepare the fee.
                 let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-                let fee = (credits, 10);
+                let fee = (credits, 1_000_000_000);
 
                 // Initialize the VM.
                 let vm = sample_vm();
@@ -362,7 +503,7 @@ function compute:
                 assert_eq!(authorization.len(), 1);
 
                 // Execute the fee.
-                let fee = Transaction::execute_fee(&vm, &caller_private_key, record, 100, None, rng).unwrap();
+                let fee = Transaction::execute_fee(&vm, &caller_private_key, record, 1_000_000_000, None, rng).unwrap();
 
                 // Execute.
                 let transaction = Transaction::execute_authorization(&vm, authorization, Some(fee), None, rng).unwrap();
@@ -411,3 +552,48 @@ function compute:
             .clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    use console::account::ViewKey;
+
+    #[test]
+    fn test_remove_last_n() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let genesis = test_helpers::sample_genesis_block(rng);
+        let vm = test_helpers::sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+        let checksum = vm.program_store().get_checksum().unwrap();
+
+        // Prepare the fee.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Add a block that deploys a program.
+        let program = test_helpers::sample_program();
+        let deployment_transaction =
+            Transaction::deploy(&vm, &caller_private_key, &program, (credits, 1_000_000_000), None, rng).unwrap();
+        let block = vm.propose_next_block(&caller_private_key, &[deployment_transaction], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(program.id()));
+
+        // Remove the block, and ensure the program is removed from the process and from storage.
+        vm.remove_last_n(1).unwrap();
+        assert!(!vm.contains_program(program.id()));
+        assert!(vm.transaction_store().get_program(program.id()).unwrap().is_none());
+        assert_eq!(checksum, vm.program_store().get_checksum().unwrap());
+
+        // Ensure the block can be added again.
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(program.id()));
+    }
+}
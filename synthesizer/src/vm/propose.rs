@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::block::{Header, Metadata};
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a new block on top of the latest block, signed by the given private key.
+    /// The candidate transactions are speculatively finalized in order, and any candidate that is invalid,
+    /// already in the ledger, conflicts with a previous candidate, or fails to finalize, is dropped.
+    /// Note: The VM is left unchanged, and the block can be added with `add_next_block`.
+    pub fn propose_next_block<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        candidate_transactions: &[Transaction<N>],
+        coinbase_solution: Option<CoinbaseSolution<N>>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        let timer = timer!("VM::propose_next_block");
+
+        // Retrieve the latest block header.
+        let latest_height = match self.block_store().heights().max() {
+            Some(latest_height) => cow_to_copied!(latest_height),
+            None => bail!("Cannot propose a block without a genesis block"),
+        };
+        let latest_hash = match self.block_store().get_block_hash(latest_height)? {
+            Some(latest_hash) => latest_hash,
+            None => bail!("Missing the block hash for the latest block {latest_height}"),
+        };
+        let latest_header = match self.block_store().get_block_header(&latest_hash)? {
+            Some(latest_header) => latest_header,
+            None => bail!("Missing the block header for the latest block {latest_height}"),
+        };
+        lap!(timer, "Retrieve the latest block header");
+
+        // Select the transactions, and compute the finalize root.
+        let next_height = latest_height.saturating_add(1);
+        let state = FinalizeGlobalState::new(next_height, latest_hash);
+        let (transactions, finalize_root) = self.select_transactions(state, candidate_transactions)?;
+        lap!(timer, "Select the transactions");
+
+        // Compute the timestamp, ensuring it is after the latest block timestamp.
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let timestamp = now.max(latest_header.timestamp().saturating_add(1));
+
+        // Compute the cumulative proof target, and the last coinbase target and timestamp.
+        let mut cumulative_proof_target = latest_header.cumulative_proof_target();
+        let mut last_coinbase_target = latest_header.last_coinbase_target();
+        let mut last_coinbase_timestamp = latest_header.last_coinbase_timestamp();
+        if let Some(coinbase_solution) = &coinbase_solution {
+            cumulative_proof_target =
+                cumulative_proof_target.saturating_add(coinbase_solution.to_cumulative_proof_target()?);
+            // If the coinbase target is reached, reset the cumulative proof target.
+            if cumulative_proof_target >= latest_header.coinbase_target() as u128 {
+                cumulative_proof_target = 0;
+                last_coinbase_target = latest_header.coinbase_target();
+                last_coinbase_timestamp = timestamp;
+            }
+        }
+
+        // Prepare the metadata.
+        // Note: The total supply, coinbase target, and proof target are carried over from the latest block.
+        let metadata = Metadata::new(
+            N::ID,
+            latest_header.round().saturating_add(1),
+            next_height,
+            latest_header.total_supply_in_microcredits(),
+            cumulative_proof_target,
+            latest_header.coinbase_target(),
+            latest_header.proof_target(),
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        )?;
+
+        // Prepare the coinbase accumulator point.
+        let coinbase_accumulator_point = match &coinbase_solution {
+            Some(coinbase_solution) => coinbase_solution.to_accumulator_point()?,
+            None => Field::zero(),
+        };
+
+        // Prepare the block header.
+        let header = Header::from(
+            *self.block_store().current_state_root(),
+            transactions.to_root()?,
+            finalize_root,
+            coinbase_accumulator_point,
+            metadata,
+        )?;
+
+        // Construct the block.
+        let block = Block::new(private_key, latest_hash, header, transactions, coinbase_solution, rng)?;
+
+        finish!(timer);
+
+        Ok(block)
+    }
+
+    /// Returns the candidate transactions that can be included in the next block, and their finalize root,
+    /// given the global state of the next block.
+    /// Note: The transactions are finalized speculatively, so the VM is left unchanged.
+    fn select_transactions(
+        &self,
+        state: FinalizeGlobalState<N>,
+        candidate_transactions: &[Transaction<N>],
+    ) -> Result<(Transactions<N>, Field<N>)> {
+        // Filter out the candidate transactions that are duplicates, spend a spent record, or are invalid.
+        // Note: This is done before the speculative batch, so the batch lock is not held while verifying.
+        let mut transaction_ids = HashSet::new();
+        let mut serial_numbers = HashSet::new();
+        let mut candidates = Vec::with_capacity(candidate_transactions.len());
+        for transaction in candidate_transactions {
+            let transaction_id = transaction.id();
+            // Skip the transaction if it is a duplicate, or already exists in the ledger.
+            if transaction_ids.contains(&transaction_id)
+                || self.block_store().find_block_hash(&transaction_id)?.is_some()
+            {
+                warn!("Skipping transaction '{transaction_id}', as it already exists");
+                continue;
+            }
+            // Skip the transaction if it spends a record that is already spent.
+            let mut is_spent = false;
+            for serial_number in transaction.serial_numbers() {
+                if serial_numbers.contains(serial_number)
+                    || self.transition_store().contains_serial_number(serial_number)?
+                {
+                    is_spent = true;
+                    break;
+                }
+            }
+            if is_spent {
+                warn!("Skipping transaction '{transaction_id}', as it spends a record that is already spent");
+                continue;
+            }
+            // Skip the transaction if it is invalid.
+            if let Err(error) = self.check_transaction(transaction) {
+                warn!("Skipping transaction '{transaction_id}', as it is invalid: {error}");
+                continue;
+            }
+            // Add the transaction to the candidates.
+            transaction_ids.insert(transaction_id);
+            serial_numbers.extend(transaction.serial_numbers().copied());
+            candidates.push(transaction);
+        }
+
+        // Finalize the remaining candidate transactions in a speculative batch.
+        let result = self.speculative_batch(|process| {
+            self.program_store().start_change_set(state.block_height())?;
+
+            let mut transactions = Vec::with_capacity(candidates.len());
+            for transaction in candidates {
+                // Skip the transaction if it fails to finalize.
+                if self.finalize_transaction(process, state, transaction)?.is_aborted() {
+                    warn!("Skipping transaction '{}', as it fails to finalize", transaction.id());
+                    continue;
+                }
+                // Select the transaction.
+                transactions.push(transaction.clone());
+            }
+
+            // Compute the finalize root, where every selected transaction is accepted.
+            let transactions = Transactions::from(&transactions);
+            let statuses = vec![TransactionStatus::Accepted; transactions.len()];
+            let finalize_root = self.to_next_finalize_root(&transactions, &statuses)?;
+            Ok((transactions, finalize_root))
+        });
+
+        // Ensure at least one transaction is selected.
+        let (transactions, finalize_root) = result?;
+        ensure!(!transactions.is_empty(), "Cannot propose a block, as none of the candidate transactions are valid");
+        Ok((transactions, finalize_root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    use console::account::{Address, ViewKey};
+
+    #[test]
+    fn test_propose_next_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let genesis = test_helpers::sample_genesis_block(rng);
+        let vm = test_helpers::sample_vm();
+
+        // Ensure a block cannot be proposed without a genesis block.
+        assert!(vm.propose_next_block(&caller_private_key, &[], None, rng).is_err());
+        vm.add_next_block(&genesis).unwrap();
+
+        // Prepare the fee.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Deploy.
+        let program = test_helpers::sample_program();
+        let deployment_transaction =
+            Transaction::deploy(&vm, &caller_private_key, &program, (credits.clone(), 1_000_000_000), None, rng)
+                .unwrap();
+        // Execute, spending the same record as the deployment for the fee.
+        let address = Address::try_from(&caller_private_key).unwrap();
+        let inputs = [address.to_string(), "1u64".to_string()];
+        let conflicting_transaction = Transaction::execute(
+            &vm,
+            &caller_private_key,
+            ("credits.aleo", "mint"),
+            inputs.iter(),
+            Some((credits, 1_000_000_000)),
+            None,
+            rng,
+        )
+        .unwrap();
+
+        // Ensure a block cannot be proposed without a new candidate.
+        assert!(vm.propose_next_block(&caller_private_key, &[], None, rng).is_err());
+        let genesis_transactions = genesis.transactions().values().cloned().collect::<Vec<_>>();
+        assert!(vm.propose_next_block(&caller_private_key, &genesis_transactions, None, rng).is_err());
+
+        // Propose a block, with a conflicting and a duplicate candidate.
+        let candidates = [deployment_transaction.clone(), conflicting_transaction, deployment_transaction.clone()];
+        let block = vm.propose_next_block(&caller_private_key, &candidates, None, rng).unwrap();
+        assert_eq!(block.height(), 1);
+        assert_eq!(block.previous_hash(), genesis.hash());
+        assert_eq!(block.transaction_ids().collect::<Vec<_>>(), vec![&deployment_transaction.id()]);
+        // Ensure the VM is left unchanged.
+        assert!(!vm.contains_program(program.id()));
+
+        // Ensure the block is a valid next block, and can be added.
+        vm.check_next_block(&block).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(program.id()));
+    }
+}
@@ -0,0 +1,302 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a snapshot of the consensus state at the latest block.
+    /// Note: This method assumes the VM is not concurrently adding a block.
+    #[inline]
+    pub fn export_snapshot(&self) -> Result<StateSnapshot<N>> {
+        let timer = timer!("VM::export_snapshot");
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(height) => cow_to_copied!(height),
+            None => bail!("Cannot export a snapshot without any blocks in storage"),
+        };
+        // Retrieve the block hashes, from the genesis block up to the latest block.
+        let block_hashes = (0..=latest_height)
+            .map(|height| match self.block_store().get_block_hash(height)? {
+                Some(block_hash) => Ok(block_hash),
+                None => bail!("Missing block hash for block {height}"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Retrieve the latest block.
+        let block = match self.block_store().get_block(&block_hashes[latest_height as usize])? {
+            Some(block) => block,
+            None => bail!("Missing block {latest_height}"),
+        };
+        // Retrieve the status of each transaction in the latest block.
+        let statuses = block
+            .transaction_ids()
+            .map(|transaction_id| match self.block_store().get_transaction_status(transaction_id)? {
+                Some(status) => Ok(status),
+                None => bail!("Missing status for transaction '{transaction_id}'"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Retrieve the blocks");
+
+        // Retrieve the deployment transactions.
+        // Note: Aborted deployments are not in the deployment store, as only their fee is stored.
+        let mut deployments = Vec::new();
+        for transaction_id in self.transaction_store().deployment_transaction_ids() {
+            match self.transaction_store().get_transaction(&transaction_id)? {
+                Some(transaction) => deployments.push(transaction),
+                None => bail!("Deployment transaction '{transaction_id}' is not found in storage"),
+            }
+        }
+        lap!(timer, "Retrieve the deployments");
+
+        // Retrieve the key-value pairs of every mapping.
+        let mut mappings = Vec::new();
+        for program_id in self.program_store().program_ids() {
+            let mapping_names = match self.program_store().get_mapping_names(&program_id)? {
+                Some(mapping_names) => mapping_names,
+                None => bail!("Missing mapping names for program '{program_id}'"),
+            };
+            for mapping_name in mapping_names {
+                let entries = self.program_store().get_mapping(&program_id, &mapping_name)?.collect::<Result<_>>()?;
+                mappings.push((cow_to_copied!(program_id), mapping_name, entries));
+            }
+        }
+        lap!(timer, "Retrieve the program state");
+
+        // Retrieve the serial numbers and commitments.
+        let serial_numbers = self
+            .transition_store()
+            .serial_numbers_with_tags()
+            .map(|(serial_number, tag)| (cow_to_copied!(serial_number), cow_to_copied!(tag)))
+            .collect();
+        let commitments = self
+            .transition_store()
+            .commitments_with_records()
+            .map(|(commitment, output)| {
+                let (checksum, record) = cow_to_cloned!(output);
+                (cow_to_copied!(commitment), checksum, record)
+            })
+            .collect();
+        lap!(timer, "Retrieve the serial numbers and commitments");
+
+        // Construct the snapshot.
+        let snapshot =
+            StateSnapshot::new(block, statuses, block_hashes, deployments, mappings, serial_numbers, commitments);
+        finish!(timer, "Compute the checksum");
+        snapshot
+    }
+
+    /// Initializes the VM from the given snapshot of the consensus state, into an empty store.
+    /// The block of the snapshot is verified against the given trusted block hash, which is the hash of the block
+    /// at the snapshot height, as obtained from a trusted source (e.g. a checkpoint) rather than from the snapshot.
+    /// The block hashes of the snapshot are verified against the previous state root in its block header,
+    /// while the program state, serial numbers, commitments, and deployments are verified against the finalize root
+    /// in its block header. The snapshot is only stored if it is verified.
+    #[inline]
+    pub fn from_snapshot(
+        store: ConsensusStore<N, C>,
+        snapshot: &StateSnapshot<N>,
+        trusted_block_hash: N::BlockHash,
+    ) -> Result<Self> {
+        let timer = timer!("VM::from_snapshot");
+
+        // Ensure the store is empty.
+        ensure!(store.block_store().heights().next().is_none(), "Cannot import a snapshot into a store with blocks");
+        ensure!(
+            store.program_store().program_ids().next().is_none(),
+            "Cannot import a snapshot into a store with program state"
+        );
+        // Ensure the block of the snapshot is the trusted block.
+        // Note: The block hash commits to the block header, which the rest of the snapshot is verified against.
+        ensure!(
+            snapshot.block().hash() == trusted_block_hash,
+            "The snapshot block {} does not match the trusted block hash '{trusted_block_hash}'",
+            snapshot.height()
+        );
+        // Verify the snapshot against the block header.
+        snapshot.verify()?;
+        // Compute the ledger digest of the snapshot.
+        let ledger_digest = snapshot.ledger_digest()?;
+        lap!(timer, "Verify the snapshot");
+
+        atomic_write_batch!(store, {
+            // Store the deployment transactions.
+            for transaction in snapshot.deployments() {
+                store.transaction_store().insert(transaction)?;
+            }
+            // Store the program state.
+            for (program_id, mapping_name, entries) in snapshot.mappings() {
+                store.program_store().initialize_mapping(program_id, mapping_name)?;
+                for (key, value) in entries {
+                    store.program_store().insert_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+                }
+            }
+            // Ensure the program state and the ledger digest match the finalize root in the block header.
+            let checksum = store.program_store().get_checksum()?;
+            let finalize_root = Self::to_finalize_root(checksum, ledger_digest, snapshot.statuses())?;
+            ensure!(
+                finalize_root == snapshot.block().finalize_root(),
+                "The snapshot does not match the finalize root in block {}",
+                snapshot.height()
+            );
+            // Store the serial numbers and commitments.
+            for (serial_number, tag) in snapshot.serial_numbers() {
+                store.transition_store().insert_serial_number(*serial_number, *tag)?;
+            }
+            for (commitment, checksum, record) in snapshot.commitments() {
+                store.transition_store().insert_commitment(*commitment, *checksum, record.clone())?;
+            }
+            // Store the block, along with the block hashes of all preceding blocks.
+            let (block_hash, previous_hashes) = match snapshot.block_hashes().split_last() {
+                Some(block_hashes) => block_hashes,
+                None => bail!("Missing block hashes in the snapshot"),
+            };
+            ensure!(*block_hash == snapshot.block().hash(), "Mismatching block hash in the snapshot");
+            store.block_store().bootstrap(previous_hashes, snapshot.block(), snapshot.statuses(), ledger_digest)
+        });
+        lap!(timer, "Store the snapshot");
+
+        // Initialize the VM, which loads the deployed programs from storage.
+        let vm = Self::from(store);
+        finish!(timer, "Load the deployments");
+        vm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ConsensusMemory;
+    use console::account::ViewKey;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = test_helpers::CurrentNetwork;
+
+    /// Returns a copy of the given snapshot, with the given components replaced.
+    fn tamper(
+        snapshot: &StateSnapshot<CurrentNetwork>,
+        block_hashes: Option<Vec<<CurrentNetwork as Network>::BlockHash>>,
+        mappings: Option<Vec<SnapshotMapping<CurrentNetwork>>>,
+        serial_numbers: Option<Vec<(Field<CurrentNetwork>, Field<CurrentNetwork>)>>,
+    ) -> StateSnapshot<CurrentNetwork> {
+        StateSnapshot::new(
+            snapshot.block().clone(),
+            snapshot.statuses().to_vec(),
+            block_hashes.unwrap_or_else(|| snapshot.block_hashes().to_vec()),
+            snapshot.deployments().to_vec(),
+            mappings.unwrap_or_else(|| snapshot.mappings().to_vec()),
+            serial_numbers.unwrap_or_else(|| snapshot.serial_numbers().to_vec()),
+            snapshot.commitments().to_vec(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_export_import_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM with the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Add program state to the VM.
+        // Note: Programs with mappings cannot be deployed yet, so the program state is inserted directly.
+        let program = crate::vm::test_helpers::sample_program();
+        let program_id = *program.id();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        vm.program_store().initialize_mapping(&program_id, &mapping_name).unwrap();
+        for i in 0..3 {
+            let key = Plaintext::from_str(&format!("{i}field")).unwrap();
+            let value = Value::from_str(&format!("{i}u64")).unwrap();
+            vm.program_store().insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+        }
+
+        // Deploy the program in the next block, which commits to the program state in its finalize root.
+        let credits = genesis.records().next().unwrap().1.decrypt(&caller_view_key).unwrap();
+        let transaction =
+            Transaction::deploy(&vm, &caller_private_key, &program, (credits, 1_000_000_000), None, rng).unwrap();
+        let block = vm.propose_next_block(&caller_private_key, &[transaction], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Export the snapshot, and ensure it serializes correctly.
+        let snapshot = vm.export_snapshot().unwrap();
+        assert_eq!(1, snapshot.height());
+        let snapshot = StateSnapshot::read_le(&snapshot.to_bytes_le().unwrap()[..]).unwrap();
+
+        // Retrieve the trusted block hash at the snapshot height.
+        let trusted_hash = vm.block_store().get_block_hash(snapshot.height()).unwrap().unwrap();
+        // Imports the given snapshot into a new VM, with the trusted block hash.
+        let import = |snapshot: &StateSnapshot<CurrentNetwork>| {
+            VM::from_snapshot(ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap(), snapshot, trusted_hash)
+        };
+
+        // Import the snapshot into a new VM.
+        let candidate = import(&snapshot).unwrap();
+
+        // Ensure the new VM matches the consensus state of the original VM.
+        assert_eq!(vm.block_store().current_state_root(), candidate.block_store().current_state_root());
+        assert_eq!(vm.block_store().get_state_root(0).unwrap(), candidate.block_store().get_state_root(0).unwrap());
+        assert_eq!(Some(block), candidate.block_store().get_block(&snapshot.block().hash()).unwrap());
+        assert!(candidate.contains_program(&program_id));
+        assert_eq!(vm.program_store().get_checksum().unwrap(), candidate.program_store().get_checksum().unwrap());
+        assert_eq!(vm.block_store().get_ledger_digest().unwrap(), candidate.block_store().get_ledger_digest().unwrap());
+        for serial_number in vm.transition_store().serial_numbers() {
+            assert!(candidate.transition_store().contains_serial_number(&serial_number).unwrap());
+        }
+        for commitment in vm.transition_store().commitments() {
+            assert!(candidate.transition_store().contains_commitment(&commitment).unwrap());
+        }
+
+        // Ensure the snapshot cannot be imported into a store that is not empty.
+        assert!(VM::from_snapshot(candidate.store.clone(), &snapshot, trusted_hash).is_err());
+
+        // Ensure the snapshot is rejected if it does not match the trusted block hash.
+        let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+        assert!(VM::from_snapshot(store.clone(), &snapshot, genesis.hash()).is_err());
+        assert!(store.block_store().heights().next().is_none());
+
+        // Ensure a snapshot with tampered block hashes is rejected.
+        let mut block_hashes = snapshot.block_hashes().to_vec();
+        block_hashes[0] = Default::default();
+        let tampered = tamper(&snapshot, Some(block_hashes), None, None);
+        assert!(tampered.verify().is_err());
+        assert!(import(&tampered).is_err());
+
+        // Ensure a snapshot with a tampered mapping value is rejected.
+        let mut mappings = snapshot.mappings().to_vec();
+        let index = mappings.iter().position(|(id, name, _)| *id == program_id && *name == mapping_name).unwrap();
+        mappings[index].2[0].1 = Value::from_str("100u64").unwrap();
+        let tampered = tamper(&snapshot, None, Some(mappings), None);
+        assert!(import(&tampered).is_err());
+
+        // Ensure a snapshot with a missing serial number is rejected.
+        let mut serial_numbers = snapshot.serial_numbers().to_vec();
+        assert!(serial_numbers.pop().is_some());
+        let tampered = tamper(&snapshot, None, None, Some(serial_numbers));
+        assert!(import(&tampered).is_err());
+
+        // Ensure a snapshot with a mismatching checksum is rejected.
+        let mut bytes = snapshot.to_bytes_le().unwrap();
+        let num_bytes = bytes.len();
+        bytes[num_bytes - 1] ^= 1;
+        assert!(StateSnapshot::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::store::MappingChange;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Speculatively finalizes the given transactions in order, on top of the latest block,
+    /// and returns the finalize inputs, the mapping writes, and the error (if any) of each transaction.
+    /// A transaction that fails to finalize is reverted, so it does not affect the subsequent transactions.
+    /// Note: The transactions are not verified, and the VM is left unchanged.
+    pub fn speculate(&self, transactions: &[Transaction<N>]) -> Result<Vec<Speculation<N>>> {
+        let timer = timer!("VM::speculate");
+
+        // Finalize the transactions in a speculative batch.
+        let speculations = self.speculative_batch(|process| {
+            // Retrieve the global state of the next block.
+            let latest_height = match self.block_store().heights().max() {
+                Some(latest_height) => cow_to_copied!(latest_height),
+                None => bail!("Cannot speculate without a genesis block"),
+            };
+            let latest_hash = match self.block_store().get_block_hash(latest_height)? {
+                Some(latest_hash) => latest_hash,
+                None => bail!("Missing the block hash for the latest block {latest_height}"),
+            };
+            let state = FinalizeGlobalState::new(latest_height.saturating_add(1), latest_hash);
+
+            // Finalize each transaction, while recording the mapping changes.
+            self.program_store().start_change_set(state.block_height())?;
+            transactions.iter().map(|transaction| self.speculate_transaction(process, state, transaction)).collect()
+        });
+
+        finish!(timer);
+
+        speculations
+    }
+
+    /// Speculatively finalizes the given transaction with the given process, given the global state of the next block.
+    /// Note: This method must be called within an atomic batch, while the mapping changes are being recorded.
+    fn speculate_transaction(
+        &self,
+        process: &mut Process<N>,
+        state: FinalizeGlobalState<N>,
+        transaction: &Transaction<N>,
+    ) -> Result<Speculation<N>> {
+        // Retrieve the inputs of each finalize scope called by the transaction.
+        let finalize_inputs = match transaction {
+            Transaction::Deploy(..) => Vec::new(),
+            Transaction::Execute(_, execution, _) => execution
+                .transitions()
+                .filter_map(|transition| {
+                    let inputs = transition.finalize()?.clone();
+                    Some((*transition.program_id(), *transition.function_name(), inputs))
+                })
+                .collect(),
+        };
+
+        // Retrieve the number of mapping changes, before the transaction is finalized.
+        let num_changes = match self.program_store().num_recorded_changes() {
+            Some(num_changes) => num_changes,
+            None => bail!("The mapping changes of block {} are not being recorded", state.block_height()),
+        };
+        // Finalize the transaction.
+        let result = self.execute_finalize(process, state, transaction);
+        // Ensure the atomic batch is still in progress, as a failed write to storage aborts the batch.
+        if let Err(error) = &result {
+            ensure!(
+                self.program_store().is_atomic_in_progress(),
+                "Failed to speculate transaction '{}': {error}",
+                transaction.id()
+            );
+        }
+
+        // Retrieve the mapping writes of the transaction.
+        let changes = match self.program_store().get_recorded_changes(num_changes) {
+            Some(changes) => changes,
+            None => bail!("The mapping changes of block {} are not being recorded", state.block_height()),
+        };
+        let writes = self.to_mapping_writes(&changes)?;
+
+        // If the transaction failed to finalize, revert its mapping changes.
+        let error = match result {
+            Ok(()) => None,
+            Err(error) => {
+                self.program_store().rewind_change_set(num_changes)?;
+                Some(error.to_string())
+            }
+        };
+        Ok(Speculation::new(transaction.id(), finalize_inputs, writes, error))
+    }
+
+    /// Returns the mapping writes for the given changes, which are the latest changes to the program state.
+    /// The new value of each write is the old value of the next change to the same key,
+    /// or the current value if there is no such change.
+    fn to_mapping_writes(&self, changes: &[MappingChange<N>]) -> Result<Vec<MappingWrite<N>>> {
+        let mut writes = Vec::with_capacity(changes.len());
+        for (index, change) in changes.iter().enumerate() {
+            // Skip the change if it is not a write to a key.
+            let (program_id, mapping_name, key, previous) = match change {
+                MappingChange::InitializeMapping(..) => continue,
+                MappingChange::KeyValue(program_id, mapping_name, key, previous) => {
+                    (program_id, mapping_name, key, previous)
+                }
+            };
+            // Retrieve the old value of the next change to the same key.
+            let next_previous = changes[index + 1..].iter().find_map(|next_change| match next_change {
+                MappingChange::KeyValue(next_program_id, next_mapping_name, next_key, next_previous)
+                    if next_program_id == program_id && next_mapping_name == mapping_name && next_key == key =>
+                {
+                    Some(next_previous)
+                }
+                _ => None,
+            });
+            // Determine the new value.
+            let new_value = match next_previous {
+                Some(next_previous) => next_previous.as_ref().map(|(_, value)| (**value).clone()),
+                None => self.program_store().get_value(program_id, mapping_name, key)?,
+            };
+            let old_value = previous.as_ref().map(|(_, value)| (**value).clone());
+            writes.push((*program_id, *mapping_name, (**key).clone(), old_value, new_value));
+        }
+        Ok(writes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    use console::account::{Address, ViewKey};
+
+    type CurrentNetwork = test_helpers::CurrentNetwork;
+
+    #[test]
+    fn test_speculate() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let genesis = test_helpers::sample_genesis_block(rng);
+        let vm = test_helpers::sample_vm();
+
+        // Ensure the VM cannot speculate without a genesis block.
+        assert!(vm.speculate(&[]).is_err());
+        vm.add_next_block(&genesis).unwrap();
+
+        // Initialize a program with a finalize scope.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program speculate.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];",
+        )
+        .unwrap();
+
+        // Deploy the program.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+        let deployment_transaction =
+            Transaction::deploy(&vm, &caller_private_key, &program, (credits, 10), None, rng).unwrap();
+
+        // Execute the program, using a separate VM where the program is deployed.
+        let other_vm = test_helpers::sample_vm();
+        other_vm.add_next_block(&genesis).unwrap();
+        let transactions = Transactions::from(std::slice::from_ref(&deployment_transaction));
+        other_vm.finalize(FinalizeGlobalState::new(1, genesis.hash()), &transactions).unwrap();
+        let inputs = [address.to_string(), "5u64".to_string()];
+        let execution_transaction = Transaction::execute(
+            &other_vm,
+            &caller_private_key,
+            ("speculate.aleo", "mint_public"),
+            inputs.iter(),
+            None,
+            None,
+            rng,
+        )
+        .unwrap();
+
+        // Speculate the deployment, the execution twice, and a redeployment.
+        let checksum = vm.program_store().get_checksum().unwrap();
+        let speculations = vm
+            .speculate(&[
+                deployment_transaction.clone(),
+                execution_transaction.clone(),
+                execution_transaction.clone(),
+                deployment_transaction,
+            ])
+            .unwrap();
+        assert_eq!(speculations.len(), 4);
+
+        // Ensure the deployment is accepted, without any writes.
+        assert!(speculations[0].is_accepted());
+        assert!(speculations[0].finalize_inputs().is_empty());
+        assert!(speculations[0].writes().is_empty());
+
+        // Ensure each execution is accepted, with its finalize inputs and writes.
+        let program_id = *program.id();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let function_name = Identifier::from_str("mint_public").unwrap();
+        let key = Plaintext::from_str(&address.to_string()).unwrap();
+        let value = |amount: &str| Some(Value::from_str(amount).unwrap());
+        let finalize_inputs = vec![(program_id, function_name, vec![
+            Value::from_str(&address.to_string()).unwrap(),
+            Value::from_str("5u64").unwrap(),
+        ])];
+        assert_eq!(speculations[1].transaction_id(), &execution_transaction.id());
+        assert!(speculations[1].is_accepted());
+        assert_eq!(speculations[1].finalize_inputs(), finalize_inputs);
+        assert_eq!(speculations[1].writes(), [
+            (program_id, mapping_name, key.clone(), None, value("0u64")),
+            (program_id, mapping_name, key.clone(), value("0u64"), value("5u64")),
+        ]);
+        assert!(speculations[2].is_accepted());
+        assert_eq!(speculations[2].finalize_inputs(), finalize_inputs);
+        assert_eq!(speculations[2].writes(), [(program_id, mapping_name, key, value("5u64"), value("10u64"))]);
+
+        // Ensure the redeployment fails.
+        assert!(speculations[3].error().is_some());
+        assert!(speculations[3].writes().is_empty());
+
+        // Ensure the VM is left unchanged.
+        assert!(!vm.contains_program(&program_id));
+        assert_eq!(checksum, vm.program_store().get_checksum().unwrap());
+        assert_eq!(vm.program_store().num_recorded_changes(), None);
+    }
+
+    #[test]
+    fn test_speculate_concurrently_with_add_next_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let genesis = test_helpers::sample_genesis_block(rng);
+        let vm = test_helpers::sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Prepare a block that deploys a program.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+        let program = test_helpers::sample_program();
+        let deployment_transaction =
+            Transaction::deploy(&vm, &caller_private_key, &program, (credits, 1_000_000_000), None, rng).unwrap();
+        let block = vm.propose_next_block(&caller_private_key, &[deployment_transaction.clone()], None, rng).unwrap();
+
+        // Speculate repeatedly on another thread, while the block is added.
+        let is_added = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let handle = {
+            let (vm, is_added, transactions) = (vm.clone(), is_added.clone(), [deployment_transaction.clone()]);
+            std::thread::spawn(move || {
+                while !is_added.load(std::sync::atomic::Ordering::SeqCst) {
+                    vm.speculate(&transactions).unwrap();
+                }
+            })
+        };
+        vm.add_next_block(&block).unwrap();
+        is_added.store(true, std::sync::atomic::Ordering::SeqCst);
+        handle.join().unwrap();
+
+        // Ensure the block is added in full, and the speculative batches are discarded.
+        assert!(!vm.is_atomic_in_progress());
+        assert_eq!(vm.block_store().get_block(&block.hash()).unwrap(), Some(block));
+        assert!(vm.contains_program(program.id()));
+        assert!(vm.transaction_store().get_program(program.id()).unwrap().is_some());
+        assert_eq!(vm.program_store().num_recorded_changes(), None);
+
+        // Ensure a redeployment now fails to speculate.
+        assert!(vm.speculate(&[deployment_transaction]).unwrap()[0].error().is_some());
+    }
+}
@@ -16,7 +16,12 @@
 
 use super::*;
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// The maximum number of seconds that a block timestamp can be ahead of the local clock.
+    pub const MAX_FUTURE_TIMESTAMP_DRIFT: i64 = 10;
+
     /// Returns `true` if the transaction is valid.
     pub fn verify_transaction(&self, transaction: &Transaction<N>) -> bool {
         match self.check_transaction(transaction) {
@@ -127,23 +132,38 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 }
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(*id), "Invalid signature for the deployment transaction '{id}'");
+                // Verify the deployment, which synthesizes the circuit of each function.
+                let num_constraints = self.check_deployment(deployment)?;
+                // Ensure the fee covers the cost of the deployment, given the synthesized circuits.
+                let cost = Self::to_deployment_cost(deployment, &num_constraints)?;
+                let fee_in_microcredits = *fee.amount()?;
+                if fee_in_microcredits < cost {
+                    bail!("Insufficient fee for the deployment (expected at least {cost}, found {fee_in_microcredits})")
+                }
                 // Verify the fee.
                 self.check_fee(fee)?;
-                // Verify the deployment.
-                self.check_deployment(deployment)?;
             }
             Transaction::Execute(_, execution, fee) => {
                 // Check the execution size.
                 if let Err(error) = Transaction::check_execution_size(execution) {
                     bail!("Invalid transaction size (execution): {error}");
                 }
+                // Compute the cost of the execution.
+                let cost = self.execution_cost(execution)?;
                 // TODO (raychu86): Remove `is_split` check once batch executions are supported.
-                // Ensure the fee is present, if the transaction is not a coinbase or split.
-                if !transaction.is_coinbase() && !transaction.is_split() && fee.is_none() {
+                // Ensure the fee is present if the execution has a cost, unless the transaction is a coinbase or split.
+                if cost > 0 && !transaction.is_coinbase() && !transaction.is_split() && fee.is_none() {
                     bail!("Transaction is missing a fee (execution)");
                 }
                 // Verify the fee.
                 if let Some(fee) = fee {
+                    // Ensure the fee covers the cost of the execution.
+                    let fee_in_microcredits = *fee.amount()?;
+                    if fee_in_microcredits < cost {
+                        bail!(
+                            "Insufficient fee for the execution (expected at least {cost}, found {fee_in_microcredits})"
+                        )
+                    }
                     self.check_fee(fee)?;
                 }
                 // Verify the execution.
@@ -158,9 +178,189 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(())
     }
 
+    /// Checks that the given block is valid as the next block of the ledger. On failure, returns an error.
+    /// If the block is rejected, the error is a `BlockError`, which can be detected with
+    /// `error.downcast_ref::<BlockError<N>>()`; any other error is a failure to perform the check.
+    pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        let timer = timer!("VM::check_next_block");
+
+        // Ensure the block is for this network.
+        ensure!(block.network() == N::ID, BlockError::<N>::InvalidNetwork { expected: N::ID, found: block.network() });
+
+        // Ensure the block hash corresponds to the previous block hash and the block header.
+        let block_hash =
+            N::hash_bhp1024(&[block.previous_hash().to_bits_le(), block.header().to_root()?.to_bits_le()].concat())?;
+        ensure!(
+            block.hash() == block_hash.into(),
+            BlockError::<N>::InvalidBlockHash { expected: block_hash.into(), found: block.hash() }
+        );
+        // Ensure the signature is valid for the block hash.
+        ensure!(
+            block.signature().verify(&block.signature().to_address(), &[block_hash]),
+            BlockError::<N>::InvalidSignature
+        );
+        lap!(timer, "Check the block hash and signature");
+
+        // Ensure the block builds on the latest block, or is a genesis block if the ledger is empty.
+        match self.block_store().heights().max() {
+            Some(latest_height) => {
+                // Retrieve the latest block header.
+                let latest_height = cow_to_copied!(latest_height);
+                let latest_hash = match self.block_store().get_block_hash(latest_height)? {
+                    Some(latest_hash) => latest_hash,
+                    None => bail!("Missing the block hash for the latest block {latest_height}"),
+                };
+                let latest_header = match self.block_store().get_block_header(&latest_hash)? {
+                    Some(latest_header) => latest_header,
+                    None => bail!("Missing the block header for the latest block {latest_height}"),
+                };
+
+                // Ensure the previous block hash is the latest block hash.
+                ensure!(
+                    block.previous_hash() == latest_hash,
+                    BlockError::<N>::InvalidPreviousHash { expected: latest_hash, found: block.previous_hash() }
+                );
+                // Ensure the block height is the next block height.
+                let expected_height = latest_height.saturating_add(1);
+                ensure!(
+                    block.height() == expected_height,
+                    BlockError::<N>::InvalidHeight { expected: expected_height, found: block.height() }
+                );
+                // Ensure the block round is after the latest block round.
+                ensure!(
+                    block.round() > latest_header.round(),
+                    BlockError::<N>::InvalidRound { previous: latest_header.round(), found: block.round() }
+                );
+                // Ensure the block timestamp is after the latest block timestamp.
+                ensure!(
+                    block.timestamp() > latest_header.timestamp(),
+                    BlockError::<N>::InvalidTimestamp { previous: latest_header.timestamp(), found: block.timestamp() }
+                );
+                // Ensure the previous state root is the latest state root.
+                let state_root = *self.block_store().current_state_root();
+                ensure!(
+                    block.previous_state_root() == state_root,
+                    BlockError::<N>::InvalidPreviousStateRoot {
+                        expected: state_root,
+                        found: block.previous_state_root()
+                    }
+                );
+            }
+            None => ensure!(block.is_genesis(), BlockError::<N>::InvalidGenesis),
+        }
+        // Ensure the block timestamp is not too far ahead of the local clock.
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let max_timestamp = now.saturating_add(Self::MAX_FUTURE_TIMESTAMP_DRIFT);
+        ensure!(
+            block.timestamp() <= max_timestamp,
+            BlockError::<N>::FutureTimestamp { max: max_timestamp, found: block.timestamp() }
+        );
+        lap!(timer, "Check the block against the latest block");
+
+        // Ensure the transactions root corresponds to the transactions.
+        let transactions_root = block.transactions().to_root()?;
+        ensure!(
+            block.transactions_root() == transactions_root,
+            BlockError::<N>::InvalidTransactionsRoot { expected: transactions_root, found: block.transactions_root() }
+        );
+
+        // Ensure the coinbase accumulator point corresponds to the coinbase solution.
+        let accumulator_point = match block.coinbase() {
+            Some(coinbase_solution) => coinbase_solution.to_accumulator_point()?,
+            None => Field::zero(),
+        };
+        ensure!(
+            block.header().coinbase_accumulator_point() == accumulator_point,
+            BlockError::<N>::InvalidCoinbaseAccumulatorPoint {
+                expected: accumulator_point,
+                found: block.header().coinbase_accumulator_point(),
+            }
+        );
+
+        // Ensure the coinbase solution is valid.
+        if let Some(coinbase_solution) = block.coinbase() {
+            self.check_coinbase_solution(block, coinbase_solution)
+                .map_err(|error| BlockError::<N>::InvalidCoinbaseSolution { reason: error.to_string() })?;
+        }
+        lap!(timer, "Check the block header");
+
+        // Ensure the serial numbers are unique in the block, and are not already spent in the ledger.
+        let mut serial_numbers = HashSet::new();
+        for serial_number in block.serial_numbers() {
+            ensure!(
+                serial_numbers.insert(serial_number),
+                BlockError::<N>::DuplicateSerialNumber { serial_number: *serial_number }
+            );
+            ensure!(
+                !self.transition_store().contains_serial_number(serial_number)?,
+                BlockError::<N>::SpentSerialNumber { serial_number: *serial_number }
+            );
+        }
+
+        // Ensure each transaction is new to the ledger, and is valid.
+        for transaction in block.transactions().values() {
+            let transaction_id = transaction.id();
+            ensure!(
+                self.block_store().find_block_hash(&transaction_id)?.is_none(),
+                BlockError::<N>::DuplicateTransaction { transaction_id }
+            );
+            self.check_transaction(transaction)
+                .map_err(|error| BlockError::<N>::InvalidTransaction { transaction_id, reason: error.to_string() })?;
+        }
+        lap!(timer, "Check the transactions");
+
+        // Ensure the finalize root corresponds to finalizing the transactions.
+        // Note: The finalize root of a genesis block is zero, which is checked above.
+        if !block.is_genesis() {
+            let finalize_root = self.compute_finalize_root(FinalizeGlobalState::from(block), block.transactions())?;
+            ensure!(
+                block.finalize_root() == finalize_root,
+                BlockError::<N>::InvalidFinalizeRoot { expected: finalize_root, found: block.finalize_root() }
+            );
+        }
+
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Verifies the given coinbase solution of the given block. On failure, returns an error.
+    fn check_coinbase_solution(&self, block: &Block<N>, coinbase_solution: &CoinbaseSolution<N>) -> Result<()> {
+        // Ensure the puzzle commitments are new to the ledger.
+        for puzzle_commitment in coinbase_solution.puzzle_commitments() {
+            if self.block_store().contains_puzzle_commitment(&puzzle_commitment)? {
+                bail!("Puzzle commitment '{puzzle_commitment}' already exists in the ledger")
+            }
+        }
+
+        // Retrieve the epoch block hash, which is the block hash right before the epoch of the block.
+        let epoch_starting_height = block.epoch_number().saturating_mul(N::NUM_BLOCKS_PER_EPOCH);
+        let epoch_block_hash = match epoch_starting_height.checked_sub(1) {
+            Some(height) => match self.block_store().get_block_hash(height)? {
+                Some(epoch_block_hash) => epoch_block_hash,
+                None => bail!("Missing the block hash for block {height}"),
+            },
+            None => N::BlockHash::default(),
+        };
+        // Construct the epoch challenge.
+        let epoch_challenge = EpochChallenge::new(block.epoch_number(), epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)?;
+
+        // Verify the coinbase solution.
+        match self.coinbase_puzzle()?.verify(
+            coinbase_solution,
+            &epoch_challenge,
+            block.coinbase_target(),
+            block.proof_target(),
+        )? {
+            true => Ok(()),
+            false => bail!("The coinbase solution does not verify"),
+        }
+    }
+
     /// Verifies the given deployment. On failure, returns an error.
+    /// On success, returns the number of constraints of each function, as synthesized for the verification.
     #[inline]
-    fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
+    fn check_deployment(&self, deployment: &Deployment<N>) -> Result<Vec<u64>> {
         let timer = timer!("VM::verify_deployment");
 
         // Compute the core logic.
@@ -172,7 +372,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Initialize an RNG.
                     let rng = &mut rand::thread_rng();
                     // Verify the deployment.
-                    $process.verify_deployment::<$aleo, _>(&deployment, rng)
+                    let num_constraints = $process.verify_deployment::<$aleo, _>(&deployment, rng)?;
+                    Ok(num_constraints.into_iter().map(|(_, num_constraints)| num_constraints).collect::<Vec<_>>())
                 };
                 task()
             }};
@@ -180,9 +381,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Process the logic.
         match process!(self, logic) {
-            Ok(()) => {
+            Ok(num_constraints) => {
                 finish!(timer);
-                Ok(())
+                Ok(num_constraints)
             }
             Err(error) => {
                 finish!(timer);
@@ -202,7 +403,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
+            Ok(()) => match self.contains_state_root(&execution.global_state_root()) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Execution verification failed: global state root not found"),
                 Err(error) => bail!("Execution verification failed: {error}"),
@@ -222,7 +423,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
+            Ok(()) => match self.contains_state_root(&fee.global_state_root()) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Fee verification failed: global state root not found"),
                 Err(error) => bail!("Fee verification failed: {error}"),
@@ -230,13 +431,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Err(error) => bail!("Fee verification failed: {error}"),
         }
     }
+
+    /// Returns `true` if the given state root is the state root of a block in the ledger, or the current state root.
+    /// Note: The current state root of an empty ledger is the root of the empty block tree,
+    /// which is the global state root of the transactions in a genesis block.
+    fn contains_state_root(&self, state_root: &N::StateRoot) -> Result<bool> {
+        Ok(*state_root == self.block_store().current_state_root()
+            || self.block_store().contains_state_root(state_root)?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::{Block, Header, Inclusion, Metadata, Transaction, Transactions};
+    use crate::{Block, ConsensusMemory, Header, Inclusion, Metadata, Transaction, Transactions};
     use console::{
         account::{Address, ViewKey},
         types::Field,
@@ -277,6 +486,12 @@ mod tests {
         assert!(vm.check_deployment(&deployment).is_ok());
         assert!(vm.verify_deployment(&deployment));
 
+        // Ensure the constraints synthesized by the verification give the deployment cost.
+        let num_constraints = vm.check_deployment(&deployment).unwrap();
+        assert_eq!(num_constraints.len(), program.functions().len());
+        let cost = VM::<_, ConsensusMemory<_>>::to_deployment_cost(&deployment, &num_constraints).unwrap();
+        assert_eq!(vm.deployment_cost(&deployment).unwrap(), cost);
+
         // Ensure that deserialization doesn't break the transaction verification.
         let serialized_deployment = deployment.to_string();
         let deployment_transaction: Deployment<CurrentNetwork> = serde_json::from_str(&serialized_deployment).unwrap();
@@ -383,7 +598,7 @@ mod tests {
 
         // Prepare the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let fee = (credits, 10);
+        let fee = (credits, 1_000_000_000);
 
         // Initialize the VM.
         let vm = crate::vm::test_helpers::sample_vm();
@@ -432,7 +647,7 @@ mod tests {
 
         // Prepare the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let fee_in_microcredits = 10;
+        let fee_in_microcredits = 1_000_000_000;
 
         // Execute the fee.
         let fee = Transaction::execute_fee(&vm, &caller_private_key, credits, fee_in_microcredits, None, rng).unwrap();
@@ -455,4 +670,117 @@ mod tests {
         assert!(vm.check_transaction(&transaction).is_ok());
         assert!(vm.verify_transaction(&transaction));
     }
+
+    #[test]
+    fn test_check_next_block() {
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Ensure the genesis block is a valid next block for an empty ledger.
+        assert!(vm.check_next_block(&genesis).is_ok());
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure the genesis block is no longer a valid next block.
+        let error = vm.check_next_block(&genesis).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(BlockError::InvalidPreviousHash { .. })
+        ));
+
+        // Prepare the fee.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Deploy.
+        let program = crate::vm::test_helpers::sample_program();
+        let deployment_transaction =
+            Transaction::deploy(&vm, &caller_private_key, &program, (credits, 1_000_000_000), None, rng).unwrap();
+        let transactions = Transactions::from(&[deployment_transaction]);
+
+        // Compute the finalize root, and ensure the VM is left untouched.
+        let checksum = vm.program_store().get_checksum().unwrap();
+        let finalize_root =
+            vm.compute_finalize_root(FinalizeGlobalState::new(1, genesis.hash()), &transactions).unwrap();
+        assert_eq!(checksum, vm.program_store().get_checksum().unwrap());
+        assert!(!vm.contains_program(program.id()));
+
+        // Constructs a block on top of the genesis block.
+        let sample_block = |height: u32,
+                            timestamp: i64,
+                            finalize_root: Field<CurrentNetwork>,
+                            transactions: &Transactions<_>| {
+            let metadata = Metadata::new(
+                CurrentNetwork::ID,
+                1,
+                height,
+                CurrentNetwork::STARTING_SUPPLY,
+                0,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::GENESIS_PROOF_TARGET,
+                genesis.last_coinbase_target(),
+                genesis.last_coinbase_timestamp(),
+                timestamp,
+            )
+            .unwrap();
+            let header = Header::from(
+                *vm.block_store().current_state_root(),
+                transactions.to_root().unwrap(),
+                finalize_root,
+                Field::zero(),
+                metadata,
+            )
+            .unwrap();
+            Block::new(&caller_private_key, genesis.hash(), header, transactions.clone(), None, &mut TestRng::default())
+                .unwrap()
+        };
+
+        let timestamp = CurrentNetwork::GENESIS_TIMESTAMP + 1;
+
+        // Ensure a block with the wrong height is rejected.
+        let error = vm.check_next_block(&sample_block(2, timestamp, finalize_root, &transactions)).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(&BlockError::InvalidHeight { expected: 1, found: 2 })
+        );
+
+        // Ensure a block with the wrong finalize root is rejected.
+        let error = vm.check_next_block(&sample_block(1, timestamp, Field::zero(), &transactions)).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(&BlockError::InvalidFinalizeRoot { expected: finalize_root, found: Field::zero() })
+        );
+
+        // Ensure a block containing an existing transaction is rejected.
+        let genesis_transactions = genesis.transactions().clone();
+        let error = vm.check_next_block(&sample_block(1, timestamp, finalize_root, &genesis_transactions)).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(BlockError::DuplicateTransaction { .. })
+        ));
+
+        // Ensure a block with a timestamp too far ahead of the local clock is rejected.
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+        let future_timestamp = now + VM::<CurrentNetwork, ConsensusMemory<_>>::MAX_FUTURE_TIMESTAMP_DRIFT + 60;
+        let error = vm.check_next_block(&sample_block(1, future_timestamp, finalize_root, &transactions)).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(BlockError::FutureTimestamp { found, .. }) if *found == future_timestamp
+        ));
+
+        // Ensure the valid block is accepted, and can be added.
+        let block = sample_block(1, timestamp, finalize_root, &transactions);
+        assert!(vm.check_next_block(&block).is_ok());
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(program.id()));
+    }
 }